@@ -7,6 +7,8 @@ pub const MINIMUM_WINDOW: u64 = 86400;
 pub const BPS_DENOMINATOR: u64 = 10000;
 pub const DEFAULT_INIT_EARLY_UNLOCK_PENALTY: &str = "0.7";
 pub const DEFAULT_LAST_EARLY_UNLOCK_PENALTY: &str = "0.5";
+pub const DEFAULT_PAGE_LIMIT: u32 = 30;
+pub const MAX_PAGE_LIMIT: u32 = 100;
 
 pub const DEFAULT_LOCK_CONFIGS: &[LockConfig] = &[
     LockConfig {
@@ -5,27 +5,35 @@ use cosmwasm_std::{
     StdResult, Uint128,
 };
 use cw2::{get_contract_version, set_contract_version};
-use equinox_msg::lockdrop::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UserAdjustRewards};
+use equinox_msg::{
+    lockdrop::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UserAdjustRewards},
+    ownership::{
+        claim_ownership, drop_ownership_proposal, propose_new_owner, query_ownership_proposal,
+    },
+};
 use semver::Version;
 
 use crate::{
     entry::{
         execute::{
-            _handle_callback, receive_cw20, try_claim_all_rewards, try_claim_blacklist_rewards,
-            try_claim_rewards, try_extend_lockup, try_increase_incentives, try_increase_lockup,
-            try_stake_to_vaults, try_unbond, try_unlock, try_update_config,
-            try_update_lockdrop_periods, try_update_owner, try_update_reward_distribution_config,
+            _handle_callback, receive_cw20, try_add_to_blacklist, try_claim_all_rewards,
+            try_claim_blacklist_rewards, try_claim_referral_rewards, try_claim_rewards,
+            try_extend_lockup, try_increase_incentives, try_increase_lockup, try_migrate_to_vault,
+            try_remove_from_blacklist, try_stake_to_vaults, try_unbond, try_unlock,
+            try_update_config, try_update_lockdrop_periods, try_update_reward_distribution_config,
         },
         instantiate::try_instantiate,
         query::{
             query_blacklist, query_blacklist_rewards, query_calculate_penalty_amount, query_config,
             query_incentives, query_lp_lockup_info, query_lp_lockup_state, query_owner,
-            query_reward_config, query_single_lockup_info, query_single_lockup_state,
-            query_user_lp_lockup_info, query_user_single_lockup_info,
+            query_referral_info, query_referrer, query_reward_config, query_single_lockup_info,
+            query_single_lockup_state, query_user_lp_lockup_info, query_user_single_lockup_info,
         },
     },
     error::ContractError,
-    state::{ADJUST_REWARDS, CONFIG, CONTRACT_NAME, CONTRACT_VERSION, SINGLE_USER_LOCKUP_INFO},
+    state::{
+        ADJUST_REWARDS, CONFIG, CONTRACT_NAME, CONTRACT_VERSION, OWNER, SINGLE_USER_LOCKUP_INFO,
+    },
 };
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -53,7 +61,9 @@ pub fn execute(
         ExecuteMsg::IncreaseLockup {
             stake_type,
             duration,
-        } => try_increase_lockup(deps, env, info, stake_type, duration),
+            recipient,
+            referrer,
+        } => try_increase_lockup(deps, env, info, stake_type, duration, recipient, referrer),
         ExecuteMsg::ExtendLock {
             stake_type,
             from,
@@ -65,6 +75,10 @@ pub fn execute(
             duration,
             amount,
         } => try_unlock(deps, env, info, stake_type, duration, amount),
+        ExecuteMsg::MigrateToVault {
+            stake_type,
+            duration,
+        } => try_migrate_to_vault(deps, env, info, stake_type, duration),
         ExecuteMsg::StakeToVaults {} => try_stake_to_vaults(deps, env, info),
         ExecuteMsg::ClaimRewards {
             stake_type,
@@ -80,8 +94,17 @@ pub fn execute(
         ExecuteMsg::IncreaseIncentives { rewards } => {
             try_increase_incentives(deps, env, info, rewards)
         }
-        ExecuteMsg::UpdateOwner { new_owner } => try_update_owner(deps, env, info, new_owner),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => Ok(propose_new_owner(
+            deps, env, info, &OWNER, owner, expires_in,
+        )?),
+        ExecuteMsg::DropOwnershipProposal {} => Ok(drop_ownership_proposal(deps, info, &OWNER)?),
+        ExecuteMsg::ClaimOwnership {} => Ok(claim_ownership(deps, env, info, &OWNER)?),
         ExecuteMsg::ClaimBlacklistRewards {} => try_claim_blacklist_rewards(deps, env),
+        ExecuteMsg::ClaimReferralRewards {} => try_claim_referral_rewards(deps, env, info),
+        ExecuteMsg::AddToBlacklist { user } => try_add_to_blacklist(deps, env, info, user),
+        ExecuteMsg::RemoveFromBlacklist { user } => {
+            try_remove_from_blacklist(deps, env, info, user)
+        }
         ExecuteMsg::UpdateLockdropPeriods { deposit, withdraw } => {
             try_update_lockdrop_periods(deps, env, info, deposit, withdraw)
         }
@@ -96,6 +119,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => Ok(to_json_binary(&query_config(deps, env)?)?),
         QueryMsg::RewardConfig {} => Ok(to_json_binary(&query_reward_config(deps, env)?)?),
         QueryMsg::Owner {} => Ok(to_json_binary(&query_owner(deps, env)?)?),
+        QueryMsg::OwnershipProposal {} => Ok(to_json_binary(&query_ownership_proposal(deps)?)?),
         QueryMsg::SingleLockupInfo {} => Ok(to_json_binary(&query_single_lockup_info(deps, env)?)?),
         QueryMsg::LpLockupInfo {} => Ok(to_json_binary(&query_lp_lockup_info(deps, env)?)?),
         QueryMsg::SingleLockupState {} => {
@@ -111,11 +135,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Incentives { stake_type } => {
             Ok(to_json_binary(&query_incentives(deps, stake_type)?)?)
         }
-        QueryMsg::Blacklist {} => Ok(to_json_binary(&query_blacklist(deps)?)?),
+        QueryMsg::Blacklist { start_after, limit } => {
+            Ok(to_json_binary(&query_blacklist(deps, start_after, limit)?)?)
+        }
         QueryMsg::BlacklistRewards {} => Ok(to_json_binary(&query_blacklist_rewards(deps, env)?)?),
         QueryMsg::CalculatePenaltyAmount { amount, duration } => Ok(to_json_binary(
             &query_calculate_penalty_amount(deps, env, amount, duration)?,
         )?),
+        QueryMsg::Referrer { user } => Ok(to_json_binary(&query_referrer(deps, user)?)?),
+        QueryMsg::ReferralInfo { referrer } => {
+            Ok(to_json_binary(&query_referral_info(deps, env, referrer)?)?)
+        }
     }
 }
 
@@ -1,11 +1,15 @@
 use astroport::{
     asset::{Asset, AssetInfo, AssetInfoExt, PairInfo},
+    astro_converter::{
+        Config as AstroConverterConfig, Cw20HookMsg as AstroConverterCw20HookMsg,
+        ExecuteMsg as AstroConverterExecuteMsg, QueryMsg as AstroConverterQueryMsg,
+    },
     pair::{ExecuteMsg as PairExecuteMsg, QueryMsg as AstroportPairQueryMsg},
     staking::ExecuteMsg as AstroStakingExecuteMsg,
 };
 use cosmwasm_std::{
     attr, coin, coins, ensure, ensure_eq, from_json, to_json_binary, Addr, BankMsg, Coin,
-    CosmosMsg, DepsMut, Env, MessageInfo, Order, QuerierWrapper, Response, StdError, StdResult,
+    CosmosMsg, DepsMut, Env, MessageInfo, Order, QuerierWrapper, Response, StdResult, Storage,
     Uint128, Uint256, WasmMsg,
 };
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
@@ -15,31 +19,37 @@ use eclipse_base::{
 };
 use equinox_msg::{
     lockdrop::{
-        CallbackMsg, Cw20HookMsg, IncentiveRewards, RewardDistributionConfig, StakeType,
+        CallbackMsg, Config, Cw20HookMsg, IncentiveRewards, RewardDistributionConfig, StakeType,
         UpdateConfigMsg,
     },
-    lp_staking::{Cw20HookMsg as LpStakingCw20HookMsg, ExecuteMsg as LpExecuteMsg},
-    single_sided_staking::{ExecuteMsg as SingleSidedExecuteMsg, UserReward},
-    utils::has_unique_elements,
+    lp_staking::{
+        Cw20HookMsg as LpStakingCw20HookMsg, ExecuteMsg as LpExecuteMsg, QueryMsg as LpQueryMsg,
+    },
+    single_sided_staking::{
+        ExecuteMsg as SingleSidedExecuteMsg, RewardAmount, TimeLockConfig, UserReward,
+    },
+    utils::{add_asset, has_unique_elements},
 };
 
 use crate::{
-    config::MINIMUM_WINDOW,
+    config::{BPS_DENOMINATOR, MINIMUM_WINDOW},
     entry::query::{
         calculate_lp_staking_user_rewards, calculate_lp_total_rewards,
         calculate_pending_lockdrop_incentives, calculate_updated_lp_reward_weights,
         check_deposit_window, check_lock_ended, check_lockdrop_ended,
-        get_user_lp_lockdrop_incentives, get_user_single_lockdrop_incentives,
-        query_astro_staking_total_deposit, query_astro_staking_total_shares, query_lp_pool_assets,
-        query_user_single_rewards,
+        get_referrer_lockdrop_incentives, get_user_lp_lockdrop_incentives,
+        get_user_single_lockdrop_incentives, query_astro_staking_total_deposit,
+        query_astro_staking_total_shares, query_lp_pool_assets, query_user_single_rewards,
     },
     error::ContractError,
-    math::{calculate_max_withdrawal_amount_allowed, calculate_weight},
+    math::{calculate_max_withdrawal_amount_allowed, calculate_weight, calculate_weighted_xastro},
     state::{
         ADJUST_REWARDS, BLACK_LIST, BLACK_LIST_REWARDS, CONFIG, LP_LOCKDROP_INCENTIVES,
-        LP_LOCKUP_INFO, LP_LOCKUP_STATE, LP_STAKING_REWARD_WEIGHTS, LP_USER_LOCKUP_INFO, OWNER,
+        LP_LOCKUP_INFO, LP_LOCKUP_STATE, LP_REFERRAL_INFO, LP_STAKING_REWARD_WEIGHTS,
+        LP_TOTAL_REFERRAL_WEIGHT, LP_USER_LOCKUP_INFO, OWNER, REFERRERS,
         REWARD_DISTRIBUTION_CONFIG, SINGLE_LOCKDROP_INCENTIVES, SINGLE_LOCKUP_INFO,
-        SINGLE_LOCKUP_STATE, SINGLE_USER_LOCKUP_INFO,
+        SINGLE_LOCKUP_STATE, SINGLE_REFERRAL_INFO, SINGLE_TOTAL_REFERRAL_WEIGHT,
+        SINGLE_USER_LOCKUP_INFO,
     },
 };
 
@@ -108,6 +118,68 @@ pub fn try_update_config(
             init_early_unlock_penalty.to_string(),
         ));
     };
+
+    if let Some(referral_bonus) = new_cfg.referral_bonus {
+        // incentives are allocated with fixed weights once claims are allowed
+        ensure!(!cfg.claims_allowed, ContractError::LockdropEnded {});
+        ensure!(
+            referral_bonus <= BPS_DENOMINATOR,
+            ContractError::InvalidReferralBonus(referral_bonus)
+        );
+        cfg.referral_bonus = referral_bonus;
+        attributes.push(attr("new_referral_bonus", referral_bonus.to_string()));
+    };
+
+    if let Some(astro_converter) = new_cfg.astro_converter {
+        cfg.astro_converter = Some(deps.api.addr_validate(&astro_converter)?);
+        attributes.push(attr("new_astro_converter", &astro_converter));
+    };
+
+    if let Some(accepted_astro_assets) = new_cfg.accepted_astro_assets {
+        let native_assets =
+            [&cfg.astro_token, &cfg.xastro_token].map(|denom| AssetInfo::NativeToken {
+                denom: denom.to_string(),
+            });
+        for asset in &accepted_astro_assets {
+            asset.check(deps.api)?;
+            ensure!(
+                !native_assets.iter().any(|a| a.equal(asset)),
+                ContractError::InvalidAsset {}
+            );
+        }
+        ensure!(
+            has_unique_elements(accepted_astro_assets.clone()),
+            ContractError::DuplicatedAssets {}
+        );
+        attributes.push(attr(
+            "new_accepted_astro_assets",
+            accepted_astro_assets
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+        cfg.accepted_astro_assets = accepted_astro_assets;
+    };
+
+    // ASTRO converter converts only its single old ASTRO asset to native ASTRO
+    if !cfg.accepted_astro_assets.is_empty() {
+        let astro_converter = cfg
+            .astro_converter
+            .clone()
+            .ok_or(ContractError::AstroConverterNotSet {})?;
+        let converter_cfg: AstroConverterConfig = deps
+            .querier
+            .query_wasm_smart(astro_converter, &AstroConverterQueryMsg::Config {})?;
+        ensure!(
+            converter_cfg.new_astro_denom == cfg.astro_token
+                && cfg
+                    .accepted_astro_assets
+                    .iter()
+                    .all(|a| a.equal(&converter_cfg.old_astro_asset_info)),
+            ContractError::AstroConverterMismatch {}
+        );
+    }
     CONFIG.save(deps.storage, &cfg)?;
     Ok(Response::new().add_attributes(attributes))
 }
@@ -127,6 +199,10 @@ pub fn try_update_reward_distribution_config(
         !check_lockdrop_ended(deps.as_ref(), env.block.time.seconds()).unwrap(),
         ContractError::LockdropEnded {}
     );
+    ensure!(
+        new_cfg.instant <= BPS_DENOMINATOR,
+        ContractError::InvalidInstantBps(new_cfg.instant)
+    );
 
     let attributes = vec![
         attr("action", "update_reward_distribution_config"),
@@ -138,35 +214,61 @@ pub fn try_update_reward_distribution_config(
     Ok(Response::new().add_attributes(attributes))
 }
 
-/// Update owner
-/// Only owner
-pub fn try_update_owner(
-    mut deps: DepsMut,
-    _env: Env,
-    info: MessageInfo,
-    new_owner: Addr,
-) -> Result<Response, ContractError> {
-    // only owner can update owner
-    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
-    OWNER.set(deps.branch(), Some(new_owner.clone()))?;
-    Ok(Response::new()
-        .add_attribute("action", "update owner")
-        .add_attribute("to", new_owner.to_string()))
-}
-
 /// Deposit assets to Lockdrop
 /// Only during deposit window
+/// Lockup is credited to recipient, referrer is linked to recipient on the first deposit with it
 pub fn try_increase_lockup(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     stake_type: StakeType,
     duration: u64,
+    recipient: Option<String>,
+    referrer: Option<String>,
 ) -> Result<Response, ContractError> {
-    let cfg = CONFIG.load(deps.storage)?;
     let received_token = one_coin(&info)?;
-    let sender = info.sender.to_string();
+    let asset = Asset {
+        info: AssetInfo::NativeToken {
+            denom: received_token.denom,
+        },
+        amount: received_token.amount,
+    };
+    _increase_lockup(
+        deps,
+        env,
+        asset,
+        stake_type,
+        duration,
+        info.sender,
+        recipient,
+        referrer,
+    )
+}
+
+/// ASTRO in native or accepted forms is converted to xASTRO before lockup is recorded
+/// Referrer can be set only by recipient itself
+#[allow(clippy::too_many_arguments)]
+fn _increase_lockup(
+    mut deps: DepsMut,
+    env: Env,
+    asset: Asset,
+    stake_type: StakeType,
+    duration: u64,
+    depositor: Addr,
+    recipient: Option<String>,
+    referrer: Option<String>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
     let block_time = env.block.time.seconds();
+    let sender = recipient
+        .map(|r| deps.api.addr_validate(&r))
+        .transpose()?
+        .unwrap_or(depositor.clone());
+    ensure!(
+        referrer.is_none() || sender == depositor,
+        ContractError::ReferrerNotAllowed {}
+    );
+    let sender = sender.to_string();
 
     // only deposit window
     ensure!(
@@ -178,18 +280,25 @@ pub fn try_increase_lockup(
         cfg.lock_configs.iter().any(|c| c.duration == duration),
         ContractError::InvalidDuration(duration)
     );
-    // only ASTRO / xASTRO tokens are allowed
+    // only ASTRO / xASTRO tokens and accepted ASTRO assets are allowed
+    let is_xastro = asset.info.to_string() == cfg.xastro_token && asset.is_native_token();
     ensure!(
-        received_token.denom == cfg.astro_token || received_token.denom == cfg.xastro_token,
+        is_xastro || is_astro_asset(&cfg, &asset.info),
         ContractError::InvalidAsset {}
     );
 
-    if received_token.denom == cfg.astro_token {
+    let mut attributes = vec![];
+    if let Some(referrer) = referrer {
+        let referrer = _set_referrer(deps.branch(), &cfg, &sender, referrer)?;
+        attributes.push(attr("referrer", referrer));
+    }
+
+    if !is_xastro {
         let xastro_balance = deps
             .querier
-            .query_balance(&env.contract.address, cfg.xastro_token)?;
-        let msgs = vec![
-            astro_convert_msg(cfg.astro_staking.to_string(), &received_token)?,
+            .query_balance(&env.contract.address, &cfg.xastro_token)?;
+        let mut msgs = astro_deposit_msgs(&cfg, &asset)?;
+        msgs.push(
             CallbackMsg::IncreaseLockup {
                 prev_xastro_balance: xastro_balance.amount,
                 stake_type,
@@ -197,19 +306,113 @@ pub fn try_increase_lockup(
                 sender,
             }
             .to_cosmos_msg(&env)?,
-        ];
+        );
         return Ok(Response::new()
             .add_attribute("action", "convert ASTRO to xASTRO")
-            .add_attribute("amount", received_token.amount.to_string())
+            .add_attribute("asset", asset.info.to_string())
+            .add_attribute("amount", asset.amount.to_string())
+            .add_attributes(attributes)
             .add_messages(msgs));
     }
 
-    match stake_type {
-        StakeType::SingleStaking => {
-            _increase_single_lockup(deps, duration, sender, received_token.amount)
-        }
-        StakeType::LpStaking => _increase_lp_lockup(deps, duration, sender, received_token.amount),
+    let response = match stake_type {
+        StakeType::SingleStaking => _increase_single_lockup(deps, duration, sender, asset.amount),
+        StakeType::LpStaking => _increase_lp_lockup(deps, duration, sender, asset.amount),
+    }?;
+    Ok(response.add_attributes(attributes))
+}
+
+/// Link user to referrer, referrer is credited with weight of existing lockups of user
+/// Setting the same referrer again is a no-op
+fn _set_referrer(
+    deps: DepsMut,
+    cfg: &Config,
+    user: &String,
+    referrer: String,
+) -> Result<String, ContractError> {
+    let referrer = deps.api.addr_validate(&referrer)?.to_string();
+    ensure!(&referrer != user, ContractError::InvalidReferrer {});
+    if let Some(current_referrer) = REFERRERS.may_load(deps.storage, user)? {
+        ensure!(
+            current_referrer == referrer,
+            ContractError::ReferrerAlreadySet(current_referrer)
+        );
+        return Ok(referrer);
     }
+    REFERRERS.save(deps.storage, user, &referrer)?;
+
+    let single_weight = SINGLE_USER_LOCKUP_INFO
+        .prefix(user)
+        .range(deps.storage, None, None, Order::Ascending)
+        .try_fold(Uint128::zero(), |acc, cur| -> StdResult<Uint128> {
+            let (duration, info) = cur?;
+            Ok(acc
+                + calculate_weighted_xastro(
+                    &cfg.lock_configs,
+                    duration,
+                    info.xastro_amount_in_lockups,
+                ))
+        })?;
+    let lp_weight = LP_USER_LOCKUP_INFO
+        .prefix(user)
+        .range(deps.storage, None, None, Order::Ascending)
+        .try_fold(Uint128::zero(), |acc, cur| -> StdResult<Uint128> {
+            let (duration, info) = cur?;
+            Ok(acc
+                + calculate_weighted_xastro(
+                    &cfg.lock_configs,
+                    duration,
+                    info.xastro_amount_in_lockups,
+                ))
+        })?;
+    update_referral_weight(
+        deps.storage,
+        &StakeType::SingleStaking,
+        user,
+        Uint128::zero(),
+        single_weight,
+    )?;
+    update_referral_weight(
+        deps.storage,
+        &StakeType::LpStaking,
+        user,
+        Uint128::zero(),
+        lp_weight,
+    )?;
+    Ok(referrer)
+}
+
+/// Replace prev_weight of referred user lockup with weight in referrer info
+/// Weights only change during lockdrop, they're fixed when lockups are staked to vaults
+fn update_referral_weight(
+    storage: &mut dyn Storage,
+    stake_type: &StakeType,
+    user: &String,
+    prev_weight: Uint128,
+    weight: Uint128,
+) -> StdResult<()> {
+    let referrer = match REFERRERS.may_load(storage, user)? {
+        Some(referrer) => referrer,
+        None => return Ok(()),
+    };
+    if prev_weight == weight {
+        return Ok(());
+    }
+    let (referral_info, total_referral_weight) = match stake_type {
+        StakeType::SingleStaking => (SINGLE_REFERRAL_INFO, SINGLE_TOTAL_REFERRAL_WEIGHT),
+        StakeType::LpStaking => (LP_REFERRAL_INFO, LP_TOTAL_REFERRAL_WEIGHT),
+    };
+    let mut info = referral_info
+        .may_load(storage, &referrer)?
+        .unwrap_or_default();
+    info.weighted_xastro = info.weighted_xastro.saturating_sub(prev_weight) + weight;
+    referral_info.save(storage, &referrer, &info)?;
+    let total = total_referral_weight
+        .may_load(storage)?
+        .unwrap_or_default()
+        .saturating_sub(prev_weight)
+        + weight;
+    total_referral_weight.save(storage, &total)
 }
 
 /// Extend duration
@@ -249,17 +452,23 @@ pub fn try_extend_lockup(
         let mut add_amount = Uint128::zero();
         if deposit_existing {
             let received_token = &received_tokens[0];
-            // if user deposits more asset, check asset is ASTRO or xASTRO
+            let asset = Asset {
+                info: AssetInfo::NativeToken {
+                    denom: received_token.denom.clone(),
+                },
+                amount: received_token.amount,
+            };
+            // if user deposits more asset, check asset is ASTRO or xASTRO or accepted ASTRO asset
             ensure!(
-                received_token.denom == cfg.astro_token || received_token.denom == cfg.xastro_token,
+                received_token.denom == cfg.xastro_token || is_astro_asset(&cfg, &asset.info),
                 ContractError::InvalidAsset {}
             );
-            if received_token.denom == cfg.astro_token {
+            if received_token.denom != cfg.xastro_token {
                 let xastro_balance = deps
                     .querier
-                    .query_balance(&env.contract.address, cfg.xastro_token)?;
-                let msgs = vec![
-                    astro_convert_msg(cfg.astro_staking.to_string(), received_token)?,
+                    .query_balance(&env.contract.address, &cfg.xastro_token)?;
+                let mut msgs = astro_deposit_msgs(&cfg, &asset)?;
+                msgs.push(
                     CallbackMsg::ExtendLockup {
                         prev_xastro_balance: xastro_balance.amount,
                         stake_type,
@@ -268,7 +477,7 @@ pub fn try_extend_lockup(
                         sender,
                     }
                     .to_cosmos_msg(&env)?,
-                ];
+                );
                 return Ok(Response::new()
                     .add_attribute("action", "convert ASTRO to xASTRO")
                     .add_attribute("amount", received_token.amount.to_string())
@@ -330,9 +539,24 @@ pub fn try_extend_lockup(
                 );
             }
             StakeType::LpStaking => {
-                return Err(ContractError::Std(StdError::generic_err(
-                    "Extend Lockup after Lockdrop is not allowed for lp staking",
-                )));
+                let mut add_amount = Uint128::zero();
+                if deposit_existing {
+                    let received_token = &received_tokens[0];
+                    // if user deposits more asset, check asset is lp token
+                    ensure!(
+                        received_token.denom == cfg.lp_token.clone().unwrap().to_string(),
+                        ContractError::InvalidAsset {}
+                    );
+                    add_amount = received_token.amount;
+                }
+                return _extend_lp_lockup_after_lockdrop(
+                    deps,
+                    env,
+                    from_duration,
+                    to_duration,
+                    sender,
+                    add_amount,
+                );
             }
         }
     }
@@ -484,6 +708,13 @@ pub fn _increase_single_lockup(
         .unwrap();
     SINGLE_LOCKUP_INFO.save(deps.storage, duration, &lockup_info)?;
     SINGLE_USER_LOCKUP_INFO.save(deps.storage, (&sender, duration), &user_lockup_info)?;
+    update_referral_weight(
+        deps.storage,
+        &StakeType::SingleStaking,
+        &sender,
+        Uint128::zero(),
+        calculate_weighted_xastro(&cfg.lock_configs, duration, amount),
+    )?;
 
     Ok(Response::new().add_attributes(vec![
         attr("action", "increase_lockup_position"),
@@ -518,6 +749,13 @@ pub fn _increase_lp_lockup(
         .unwrap();
     LP_LOCKUP_INFO.save(deps.storage, duration, &lockup_info)?;
     LP_USER_LOCKUP_INFO.save(deps.storage, (&sender, duration), &user_lockup_info)?;
+    update_referral_weight(
+        deps.storage,
+        &StakeType::LpStaking,
+        &sender,
+        Uint128::zero(),
+        calculate_weighted_xastro(&cfg.lock_configs, duration, amount),
+    )?;
 
     Ok(Response::new().add_attributes(vec![
         attr("action", "increase_lockup_position"),
@@ -563,6 +801,19 @@ pub fn _extend_single_lockup(
     SINGLE_LOCKUP_INFO.save(deps.storage, to_duration, &lockup_info_to)?;
     SINGLE_USER_LOCKUP_INFO.save(deps.storage, (&sender, to_duration), &user_lockup_info_to)?;
 
+    let cfg = CONFIG.load(deps.storage)?;
+    update_referral_weight(
+        deps.storage,
+        &StakeType::SingleStaking,
+        &sender,
+        calculate_weighted_xastro(&cfg.lock_configs, from_duration, existing_xastro_amount),
+        calculate_weighted_xastro(
+            &cfg.lock_configs,
+            to_duration,
+            existing_xastro_amount + add_amount,
+        ),
+    )?;
+
     Ok(Response::new().add_attributes(vec![
         attr("action", "extend_duration"),
         attr("type", "single staking"),
@@ -607,6 +858,19 @@ pub fn _extend_lp_lockup(
     LP_LOCKUP_INFO.save(deps.storage, to_duration, &lockup_info_to)?;
     LP_USER_LOCKUP_INFO.save(deps.storage, (&sender, to_duration), &user_lockup_info_to)?;
 
+    let cfg = CONFIG.load(deps.storage)?;
+    update_referral_weight(
+        deps.storage,
+        &StakeType::LpStaking,
+        &sender,
+        calculate_weighted_xastro(&cfg.lock_configs, from_duration, existing_xastro_amount),
+        calculate_weighted_xastro(
+            &cfg.lock_configs,
+            to_duration,
+            existing_xastro_amount + add_amount,
+        ),
+    )?;
+
     Ok(Response::new().add_attributes(vec![
         attr("action", "extend_duration"),
         attr("type", "single staking"),
@@ -696,6 +960,84 @@ pub fn _extend_single_lockup_after_lockdrop(
         .add_messages(msgs))
 }
 
+/// lockdrop lp is staked flexibly, extended position is moved to a timelocked lp vault position of user
+pub fn _extend_lp_lockup_after_lockdrop(
+    mut deps: DepsMut,
+    env: Env,
+    from_duration: u64,
+    to_duration: u64,
+    sender: String,
+    add_amount: Uint128,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let lp_staking = cfg.lp_staking.unwrap().to_string();
+    // position is restaked to lp vault timelock, so the duration should be a timelock of lp vault
+    let timelock_config: Vec<TimeLockConfig> = deps
+        .querier
+        .query_wasm_smart(&lp_staking, &LpQueryMsg::TimelockConfig {})?;
+    ensure!(
+        timelock_config.iter().any(|c| c.duration == to_duration),
+        ContractError::InvalidVaultDuration(to_duration)
+    );
+
+    let response = _claim_lp_rewards(deps.branch(), env, sender.clone(), from_duration, None)?;
+    let state = LP_LOCKUP_STATE.load(deps.storage)?;
+    let mut lockup_info_from = LP_LOCKUP_INFO.load(deps.storage, from_duration)?;
+    let mut user_lockup_info_from =
+        LP_USER_LOCKUP_INFO.load(deps.storage, (&sender, from_duration))?;
+    if user_lockup_info_from.total_lp_staked.is_zero() {
+        user_lockup_info_from.total_lp_staked = user_lockup_info_from
+            .xastro_amount_in_lockups
+            .multiply_ratio(state.total_lp_lockdrop, state.total_xastro);
+    }
+    let existing_lp_amount =
+        user_lockup_info_from.total_lp_staked - user_lockup_info_from.total_lp_withdrawed;
+    ensure!(!existing_lp_amount.is_zero(), ContractError::NotStaked {});
+    lockup_info_from.total_withdrawed += existing_lp_amount;
+    user_lockup_info_from.total_lp_withdrawed += existing_lp_amount;
+    LP_LOCKUP_INFO.save(deps.storage, from_duration, &lockup_info_from)?;
+    LP_USER_LOCKUP_INFO.save(
+        deps.storage,
+        (&sender, from_duration),
+        &user_lockup_info_from,
+    )?;
+
+    // unstaked lp tokens are received by lockdrop before they are locked for user
+    let msgs = vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_staking.clone(),
+            msg: to_json_binary(&LpExecuteMsg::Unstake {
+                amount: existing_lp_amount,
+                recipient: None,
+            })?,
+            funds: vec![],
+        }),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_staking,
+            msg: to_json_binary(&LpExecuteMsg::Stake {
+                duration: Some(to_duration),
+                recipient: Some(sender.clone()),
+            })?,
+            funds: coins(
+                (existing_lp_amount + add_amount).u128(),
+                cfg.lp_token.unwrap().to_string(),
+            ),
+        }),
+    ];
+
+    Ok(response
+        .add_attributes(vec![
+            attr("action", "extend_duration after lockdrop"),
+            attr("type", "lp staking"),
+            attr("from", sender),
+            attr("from", from_duration.to_string()),
+            attr("amount", existing_lp_amount),
+            attr("to", to_duration.to_string()),
+            attr("amount", existing_lp_amount + add_amount),
+        ])
+        .add_messages(msgs))
+}
+
 pub fn handle_increase_lockup_callback(
     deps: DepsMut,
     env: Env,
@@ -836,6 +1178,22 @@ pub fn receive_cw20(
 
             Ok(Response::new().add_attribute("action", "increase Lockdrop incentives"))
         }
+        Cw20HookMsg::IncreaseLockup {
+            stake_type,
+            duration,
+            recipient,
+            referrer,
+        } => {
+            let asset = Asset {
+                info: AssetInfo::Token {
+                    contract_addr: info.sender,
+                },
+                amount,
+            };
+            _increase_lockup(
+                deps, env, asset, stake_type, duration, sender, recipient, referrer,
+            )
+        }
     }
 }
 
@@ -1354,7 +1712,10 @@ fn handle_stake_lp_token(
     let msg = match lp_token.clone() {
         AssetInfo::NativeToken { denom } => CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: cfg.lp_staking.unwrap().to_string(),
-            msg: to_json_binary(&LpExecuteMsg::Stake { recipient: None })?,
+            msg: to_json_binary(&LpExecuteMsg::Stake {
+                duration: None,
+                recipient: None,
+            })?,
             funds: vec![coin(lp_token_to_stake.u128(), denom)],
         }),
         AssetInfo::Token { contract_addr } => CosmosMsg::Wasm(WasmMsg::Execute {
@@ -1436,6 +1797,12 @@ pub fn _claim_single_sided_rewards(
         blacklist_rewards.eclipastro += rewards.eclipastro;
         blacklist_rewards.beclip += rewards.beclip;
         blacklist_rewards.eclip += rewards.eclip;
+        for reward in rewards.assets.into_iter().filter(|r| !r.amount.is_zero()) {
+            add_asset(
+                &mut blacklist_rewards.assets,
+                reward.info.with_balance(reward.amount),
+            );
+        }
         BLACK_LIST_REWARDS.save(deps.storage, &blacklist_rewards)?;
     } else {
         if !rewards.eclipastro.is_zero() {
@@ -1463,6 +1830,15 @@ pub fn _claim_single_sided_rewards(
                     .into_msg(sender.clone())?,
             );
         }
+
+        for reward in rewards.assets {
+            msgs.push(
+                reward
+                    .info
+                    .with_balance(reward.amount)
+                    .into_msg(sender.clone())?,
+            );
+        }
     }
 
     Ok(Response::new().add_messages(msgs))
@@ -1645,6 +2021,7 @@ pub fn _claim_all_single_sided_rewards(
     let mut eclipastro_rewards = Uint128::zero();
     let mut beclip_rewards = Uint128::zero();
     let mut eclip_rewards = Uint128::zero();
+    let mut asset_rewards = vec![];
 
     for lock_cfg in cfg.lock_configs.iter() {
         let duration = lock_cfg.duration;
@@ -1673,12 +2050,18 @@ pub fn _claim_all_single_sided_rewards(
         eclipastro_rewards += rewards.eclipastro;
         beclip_rewards += rewards.beclip;
         eclip_rewards += rewards.eclip;
+        for reward in rewards.assets.into_iter().filter(|r| !r.amount.is_zero()) {
+            add_asset(&mut asset_rewards, reward.info.with_balance(reward.amount));
+        }
     }
 
     if blacklist.contains(&sender) {
         blacklist_rewards.eclipastro += eclipastro_rewards;
         blacklist_rewards.eclip += eclip_rewards;
         blacklist_rewards.beclip += beclip_rewards;
+        for reward in asset_rewards {
+            add_asset(&mut blacklist_rewards.assets, reward);
+        }
         BLACK_LIST_REWARDS.save(deps.storage, &blacklist_rewards)?;
     } else {
         // add message to claim rewards and incentives
@@ -1699,7 +2082,14 @@ pub fn _claim_all_single_sided_rewards(
             }));
         }
         if !eclip_rewards.is_zero() {
-            msgs.push(cfg.eclip.with_balance(eclip_rewards).into_msg(sender)?);
+            msgs.push(
+                cfg.eclip
+                    .with_balance(eclip_rewards)
+                    .into_msg(sender.clone())?,
+            );
+        }
+        for reward in asset_rewards {
+            msgs.push(reward.into_msg(sender.clone())?);
         }
     }
 
@@ -1877,6 +2267,135 @@ pub fn try_unlock(
     }
 }
 
+/// Move user position to vault after the lockdrop, position keeps lock time
+/// Rewards are claimed before migration, lockdrop incentives are still claimed from lockdrop
+pub fn try_migrate_to_vault(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    stake_type: StakeType,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    let sender = info.sender.to_string();
+
+    ensure!(cfg.claims_allowed, ContractError::ClaimRewardNotAllowed {});
+    ensure!(!blacklist.contains(&sender), ContractError::Blacklisted {});
+
+    match stake_type {
+        StakeType::SingleStaking => _migrate_single_lockup_to_vault(deps, env, sender, duration),
+        StakeType::LpStaking => _migrate_lp_lockup_to_vault(deps, env, sender, duration),
+    }
+}
+
+fn _migrate_single_lockup_to_vault(
+    mut deps: DepsMut,
+    env: Env,
+    sender: String,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let response = _claim_single_sided_rewards(deps.branch(), env, sender.clone(), duration, None)?;
+    let cfg = CONFIG.load(deps.storage)?;
+    let state = SINGLE_LOCKUP_STATE.load(deps.storage)?;
+    let mut lockup_info = SINGLE_LOCKUP_INFO.load(deps.storage, duration)?;
+    let mut user_lockup_info = SINGLE_USER_LOCKUP_INFO.load(deps.storage, (&sender, duration))?;
+    if user_lockup_info.total_eclipastro_staked.is_zero() {
+        user_lockup_info.total_eclipastro_staked = user_lockup_info
+            .xastro_amount_in_lockups
+            .multiply_ratio(state.total_eclipastro_lockup, state.total_xastro);
+    }
+    let migrate_amount =
+        user_lockup_info.total_eclipastro_staked - user_lockup_info.total_eclipastro_withdrawed;
+    ensure!(!migrate_amount.is_zero(), ContractError::NotStaked {});
+
+    user_lockup_info.total_eclipastro_withdrawed += migrate_amount;
+    lockup_info.total_withdrawed += migrate_amount;
+    SINGLE_LOCKUP_INFO.save(deps.storage, duration, &lockup_info)?;
+    SINGLE_USER_LOCKUP_INFO.save(deps.storage, (&sender, duration), &user_lockup_info)?;
+
+    let locked_at = if duration == 0u64 {
+        0u64
+    } else {
+        cfg.countdown_start_at
+    };
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: cfg.single_sided_staking.unwrap().to_string(),
+        msg: to_json_binary(&SingleSidedExecuteMsg::TransferPosition {
+            duration,
+            locked_at,
+            amount: Some(migrate_amount),
+            recipient: sender.clone(),
+        })?,
+        funds: vec![],
+    });
+
+    Ok(response
+        .add_message(msg)
+        .add_attribute("action", "migrate_to_vault")
+        .add_attribute("type", "single staking")
+        .add_attribute("user", sender)
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("amount", migrate_amount))
+}
+
+/// timelocked position is migrated to lp vault timelock of the same duration,
+/// it is migrated to flexible staking after lock end
+fn _migrate_lp_lockup_to_vault(
+    mut deps: DepsMut,
+    env: Env,
+    sender: String,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    let response = _claim_lp_rewards(deps.branch(), env, sender.clone(), duration, None)?;
+    let cfg = CONFIG.load(deps.storage)?;
+    let state = LP_LOCKUP_STATE.load(deps.storage)?;
+    let mut lockup_info = LP_LOCKUP_INFO.load(deps.storage, duration)?;
+    let mut user_lockup_info = LP_USER_LOCKUP_INFO.load(deps.storage, (&sender, duration))?;
+    if user_lockup_info.total_lp_staked.is_zero() {
+        user_lockup_info.total_lp_staked = user_lockup_info
+            .xastro_amount_in_lockups
+            .multiply_ratio(state.total_lp_lockdrop, state.total_xastro);
+    }
+    let migrate_amount = user_lockup_info.total_lp_staked - user_lockup_info.total_lp_withdrawed;
+    ensure!(!migrate_amount.is_zero(), ContractError::NotStaked {});
+
+    user_lockup_info.total_lp_withdrawed += migrate_amount;
+    lockup_info.total_withdrawed += migrate_amount;
+    LP_LOCKUP_INFO.save(deps.storage, duration, &lockup_info)?;
+    LP_USER_LOCKUP_INFO.save(deps.storage, (&sender, duration), &user_lockup_info)?;
+
+    // unstaked lp tokens are received by lockdrop before they are staked for user
+    let lp_staking = cfg.lp_staking.unwrap().to_string();
+    let msgs = vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_staking.clone(),
+            msg: to_json_binary(&LpExecuteMsg::Unstake {
+                amount: migrate_amount,
+                recipient: None,
+            })?,
+            funds: vec![],
+        }),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_staking,
+            msg: to_json_binary(&LpExecuteMsg::StakeLockdropPosition {
+                duration,
+                locked_at: cfg.countdown_start_at,
+                recipient: sender.clone(),
+            })?,
+            funds: coins(migrate_amount.u128(), cfg.lp_token.unwrap().to_string()),
+        }),
+    ];
+
+    Ok(response
+        .add_messages(msgs)
+        .add_attribute("action", "migrate_to_vault")
+        .add_attribute("type", "lp staking")
+        .add_attribute("user", sender)
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("amount", migrate_amount))
+}
+
 pub fn try_claim_blacklist_rewards(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
     let treasury = cfg.dao_treasury_address.unwrap().to_string();
@@ -1921,12 +2440,157 @@ pub fn try_claim_blacklist_rewards(mut deps: DepsMut, env: Env) -> Result<Respon
         msgs.push(
             cfg.eclip
                 .with_balance(blacklist_rewards.eclip + blacklist_rewards.beclip)
-                .into_msg(treasury)?,
+                .into_msg(treasury.clone())?,
         );
     }
+    for reward in blacklist_rewards.assets {
+        msgs.push(reward.into_msg(treasury.clone())?);
+    }
     Ok(Response::new().add_messages(msgs))
 }
 
+/// Claim vested lockdrop incentives earned by referrals
+/// Only after claims are allowed
+pub fn try_claim_referral_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    let referrer = info.sender.to_string();
+    let block_time = env.block.time.seconds();
+
+    ensure!(cfg.claims_allowed, ContractError::ClaimRewardNotAllowed {});
+    ensure!(
+        !blacklist.contains(&referrer),
+        ContractError::Blacklisted {}
+    );
+
+    let mut eclip_incentives = Uint128::zero();
+    let mut beclip_incentives = Uint128::zero();
+    for stake_type in [StakeType::SingleStaking, StakeType::LpStaking] {
+        let referral_infos = match stake_type {
+            StakeType::SingleStaking => SINGLE_REFERRAL_INFO,
+            StakeType::LpStaking => LP_REFERRAL_INFO,
+        };
+        let mut referral_info = match referral_infos.may_load(deps.storage, &referrer)? {
+            Some(referral_info) => referral_info,
+            None => continue,
+        };
+        referral_info.lockdrop_incentives =
+            get_referrer_lockdrop_incentives(deps.as_ref(), &stake_type, &referral_info)?;
+        let pending_lockdrop_incentives = calculate_pending_lockdrop_incentives(
+            deps.as_ref(),
+            block_time,
+            referral_info.lockdrop_incentives.clone(),
+        )?;
+        referral_info.lockdrop_incentives.eclip.claimed += pending_lockdrop_incentives.eclip;
+        referral_info.lockdrop_incentives.beclip.claimed += pending_lockdrop_incentives.beclip;
+        referral_infos.save(deps.storage, &referrer, &referral_info)?;
+        eclip_incentives += pending_lockdrop_incentives.eclip;
+        beclip_incentives += pending_lockdrop_incentives.beclip;
+    }
+
+    let mut msgs = vec![];
+    if !beclip_incentives.is_zero() {
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: cfg.eclip_staking.unwrap().to_string(),
+            msg: to_json_binary(&EclipStakingExecuteMsg::BondFor {
+                address_and_amount_list: vec![(referrer.clone(), beclip_incentives)],
+            })?,
+            funds: coins(beclip_incentives.u128(), cfg.eclip.to_string()),
+        }));
+    }
+    if !eclip_incentives.is_zero() {
+        msgs.push(
+            cfg.eclip
+                .with_balance(eclip_incentives)
+                .into_msg(referrer.clone())?,
+        );
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "claim_referral_rewards")
+        .add_attribute("referrer", referrer)
+        .add_attribute("eclip", eclip_incentives)
+        .add_attribute("beclip", beclip_incentives)
+        .add_messages(msgs))
+}
+
+/// rewards accrued before blacklisting are sent to the user, rewards after go to blacklist rewards
+pub fn try_add_to_blacklist(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    user: String,
+) -> Result<Response, ContractError> {
+    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
+    deps.api.addr_validate(&user)?;
+    let mut blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        !blacklist.contains(&user),
+        ContractError::AlreadyBlacklisted(user)
+    );
+
+    let response = _settle_user_rewards(deps.branch(), env, user.clone())?;
+    blacklist.push(user.clone());
+    BLACK_LIST.save(deps.storage, &blacklist)?;
+
+    Ok(response
+        .add_attribute("action", "add_to_blacklist")
+        .add_attribute("user", user))
+}
+
+/// rewards accrued while user was blacklisted go to blacklist rewards
+pub fn try_remove_from_blacklist(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    user: String,
+) -> Result<Response, ContractError> {
+    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
+    deps.api.addr_validate(&user)?;
+    let mut blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        blacklist.contains(&user),
+        ContractError::NotBlacklisted(user)
+    );
+
+    let response = _settle_user_rewards(deps.branch(), env, user.clone())?;
+    blacklist.retain(|x| x != &user);
+    BLACK_LIST.save(deps.storage, &blacklist)?;
+
+    Ok(response
+        .add_attribute("action", "remove_from_blacklist")
+        .add_attribute("user", user))
+}
+
+/// claim all rewards of user with current blacklist status
+/// rewards aren't accrued until assets are staked to vaults
+fn _settle_user_rewards(
+    mut deps: DepsMut,
+    env: Env,
+    user: String,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    if !cfg.claims_allowed {
+        return Ok(Response::new());
+    }
+    let single_response = _claim_all_single_sided_rewards(
+        deps.branch(),
+        env.clone(),
+        user.clone(),
+        None,
+        true,
+        None,
+    )?;
+    let lp_response = _claim_all_lp_rewards(deps.branch(), env, user, None, true, None)?;
+    Ok(Response::new()
+        .add_submessages(single_response.messages)
+        .add_submessages(lp_response.messages))
+}
+
 pub fn _unlock_single_lockup(
     mut deps: DepsMut,
     env: Env,
@@ -1971,6 +2635,13 @@ pub fn _unlock_single_lockup(
 
         SINGLE_LOCKUP_INFO.save(deps.storage, duration, &lockup_info)?;
         SINGLE_USER_LOCKUP_INFO.save(deps.storage, (&sender, duration), &user_lockup_info)?;
+        update_referral_weight(
+            deps.storage,
+            &StakeType::SingleStaking,
+            &sender,
+            calculate_weighted_xastro(&cfg.lock_configs, duration, withdraw_amount),
+            Uint128::zero(),
+        )?;
 
         Ok(Response::new()
             .add_message(msg)
@@ -2079,6 +2750,13 @@ pub fn _unlock_lp_lockup(
 
         LP_LOCKUP_INFO.save(deps.storage, duration, &lockup_info)?;
         LP_USER_LOCKUP_INFO.save(deps.storage, (&sender, duration), &user_lockup_info)?;
+        update_referral_weight(
+            deps.storage,
+            &StakeType::LpStaking,
+            &sender,
+            calculate_weighted_xastro(&cfg.lock_configs, duration, withdraw_amount),
+            Uint128::zero(),
+        )?;
 
         Ok(Response::new()
             .add_message(msg)
@@ -2138,6 +2816,48 @@ pub fn astro_convert_msg(astro_staking: String, coin: &Coin) -> StdResult<Cosmos
     }))
 }
 
+/// native ASTRO or ASTRO asset accepted by config
+fn is_astro_asset(cfg: &Config, info: &AssetInfo) -> bool {
+    let astro = AssetInfo::NativeToken {
+        denom: cfg.astro_token.clone(),
+    };
+    astro.equal(info) || cfg.accepted_astro_assets.iter().any(|a| a.equal(info))
+}
+
+/// Messages converting ASTRO deposit to xASTRO
+/// Accepted ASTRO assets are converted 1:1 to native ASTRO by ASTRO converter first
+fn astro_deposit_msgs(cfg: &Config, asset: &Asset) -> Result<Vec<CosmosMsg>, ContractError> {
+    let mut msgs = vec![];
+    if asset.info.to_string() != cfg.astro_token || !asset.is_native_token() {
+        let astro_converter = cfg
+            .astro_converter
+            .clone()
+            .ok_or(ContractError::AstroConverterNotSet {})?
+            .to_string();
+        msgs.push(match &asset.info {
+            AssetInfo::NativeToken { denom } => CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: astro_converter,
+                msg: to_json_binary(&AstroConverterExecuteMsg::Convert { receiver: None })?,
+                funds: coins(asset.amount.u128(), denom),
+            }),
+            AssetInfo::Token { contract_addr } => CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                    contract: astro_converter,
+                    amount: asset.amount,
+                    msg: to_json_binary(&AstroConverterCw20HookMsg { receiver: None })?,
+                })?,
+                funds: vec![],
+            }),
+        });
+    }
+    msgs.push(astro_convert_msg(
+        cfg.astro_staking.to_string(),
+        &coin(asset.amount.u128(), &cfg.astro_token),
+    )?);
+    Ok(msgs)
+}
+
 pub fn convert_eclipastro_msg(voter: String, coin: &Coin) -> StdResult<CosmosMsg> {
     Ok(CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: voter,
@@ -2250,6 +2970,25 @@ pub fn calculate_single_user_rewards(
         user_lockup_info.unclaimed_rewards.eclip += user_rewards.eclip;
         Uint128::zero()
     };
+    // partner asset rewards which are not claimed stay in unclaimed rewards
+    let mut asset_rewards = vec![];
+    let mut unclaimed_asset_rewards = vec![];
+    for reward in user_lockup_info.unclaimed_rewards.assets.drain(..).chain(
+        user_rewards
+            .assets
+            .into_iter()
+            .map(|r| r.info.with_balance(r.amount)),
+    ) {
+        if reward.amount.is_zero() {
+            continue;
+        }
+        if assets.is_none() || assets.clone().unwrap().contains(&reward.info) {
+            add_asset(&mut asset_rewards, reward);
+        } else {
+            add_asset(&mut unclaimed_asset_rewards, reward);
+        }
+    }
+    user_lockup_info.unclaimed_rewards.assets = unclaimed_asset_rewards;
     user_lockup_info.last_claimed = Some(block_time);
 
     if !adjust_reward.is_zero() && !eclipastro_rewards.is_zero() {
@@ -2271,6 +3010,13 @@ pub fn calculate_single_user_rewards(
         eclipastro: eclipastro_rewards,
         beclip: beclip_rewards,
         eclip: eclip_rewards,
+        assets: asset_rewards
+            .into_iter()
+            .map(|a| RewardAmount {
+                info: a.info,
+                amount: a.amount,
+            })
+            .collect(),
     })
 }
 
@@ -110,6 +110,9 @@ pub fn try_instantiate(
         init_early_unlock_penalty: msg
             .init_early_unlock_penalty
             .unwrap_or(Decimal::from_str(DEFAULT_INIT_EARLY_UNLOCK_PENALTY).unwrap_or_default()),
+        referral_bonus: 0u64,
+        astro_converter: None,
+        accepted_astro_assets: vec![],
     };
 
     REWARD_DISTRIBUTION_CONFIG.save(deps.storage, &DEFAULT_REWARD_DISTRIBUTION_CONFIG)?;
@@ -1,7 +1,7 @@
 use std::{cmp::min, str::FromStr};
 
 use astroport::{
-    asset::{Asset, AssetInfo},
+    asset::{Asset, AssetInfo, AssetInfoExt},
     pair::{PoolResponse, QueryMsg as PoolQueryMsg},
     staking::QueryMsg as AstroportStakingQueryMsg,
 };
@@ -12,23 +12,30 @@ use cosmwasm_std::{
 use equinox_msg::{
     lockdrop::{
         BlacklistRewards, Config, DetailedLpLockupInfo, DetailedSingleLockupInfo, IncentiveAmounts,
-        LockdropIncentive, LockdropIncentives, LpLockupInfoResponse, LpLockupStateResponse,
-        LpStakingRewardWeights, LpStakingRewards, LpUserLockupInfo, RewardDistributionConfig,
+        LockdropIncentive, LockdropIncentiveVesting, LockdropIncentives, LockdropIncentivesVesting,
+        LpLockupInfoResponse, LpLockupStateResponse, LpStakingRewardWeights, LpStakingRewards,
+        LpUserLockupInfo, ReferralInfo, ReferralInfoResponse, RewardDistributionConfig,
         SingleLockupInfoResponse, SingleLockupStateResponse, SingleStakingRewardsByDuration,
         StakeType, UserLpLockupInfoResponse, UserSingleLockupInfoResponse,
     },
     lp_staking::{QueryMsg as LpStakingQueryMsg, RewardAmount},
     single_sided_staking::{QueryMsg as SingleSidedQueryMsg, UserReward},
+    utils::{add_asset, paginate_addresses},
 };
 
 use crate::{
-    config::{BPS_DENOMINATOR, DEFAULT_LAST_EARLY_UNLOCK_PENALTY},
+    config::{
+        BPS_DENOMINATOR, DEFAULT_LAST_EARLY_UNLOCK_PENALTY, DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT,
+    },
     error::ContractError,
+    math::calculate_weighted_xastro,
     state::{
         ADJUST_REWARDS, BLACK_LIST, BLACK_LIST_REWARDS, CONFIG, LP_LOCKDROP_INCENTIVES,
-        LP_LOCKUP_INFO, LP_LOCKUP_STATE, LP_STAKING_REWARD_WEIGHTS, LP_USER_LOCKUP_INFO, OWNER,
+        LP_LOCKUP_INFO, LP_LOCKUP_STATE, LP_REFERRAL_INFO, LP_STAKING_REWARD_WEIGHTS,
+        LP_TOTAL_REFERRAL_WEIGHT, LP_USER_LOCKUP_INFO, OWNER, REFERRERS,
         REWARD_DISTRIBUTION_CONFIG, SINGLE_LOCKDROP_INCENTIVES, SINGLE_LOCKUP_INFO,
-        SINGLE_LOCKUP_STATE, SINGLE_STAKING_REWARD_WEIGHTS, SINGLE_USER_LOCKUP_INFO,
+        SINGLE_LOCKUP_STATE, SINGLE_REFERRAL_INFO, SINGLE_STAKING_REWARD_WEIGHTS,
+        SINGLE_TOTAL_REFERRAL_WEIGHT, SINGLE_USER_LOCKUP_INFO,
     },
 };
 
@@ -159,9 +166,10 @@ pub fn query_lp_lockup_state(deps: Deps, _env: Env) -> StdResult<LpLockupStateRe
 /// query eclipASTRO user lockup info
 pub fn query_user_single_lockup_info(
     deps: Deps,
-    _env: Env,
+    env: Env,
     user_address: String,
 ) -> StdResult<Vec<UserSingleLockupInfoResponse>> {
+    let block_time = env.block.time.seconds();
     let cfg = CONFIG.load(deps.storage)?;
     let state = SINGLE_LOCKUP_STATE.load(deps.storage)?;
     let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
@@ -222,10 +230,13 @@ pub fn query_user_single_lockup_info(
                     )
                     .unwrap()
                 };
+                let lockdrop_incentives_vesting =
+                    calculate_lockdrop_incentives_vesting(deps, block_time, &lockdrop_incentives)
+                        .unwrap();
                 let staking_rewards = if blacklist.contains(&user_address) {
                     vec![]
                 } else {
-                    vec![
+                    let mut staking_rewards = vec![
                         Asset {
                             info: cfg.eclipastro_token.clone().unwrap(),
                             amount: single_staking_reward.eclipastro
@@ -241,7 +252,23 @@ pub fn query_user_single_lockup_info(
                             amount: single_staking_reward.eclip
                                 + user_lockup_info.unclaimed_rewards.eclip,
                         },
-                    ]
+                    ];
+                    for reward in user_lockup_info
+                        .unclaimed_rewards
+                        .assets
+                        .iter()
+                        .cloned()
+                        .chain(
+                            single_staking_reward
+                                .assets
+                                .iter()
+                                .map(|r| r.info.with_balance(r.amount)),
+                        )
+                        .filter(|r| !r.amount.is_zero())
+                    {
+                        add_asset(&mut staking_rewards, reward);
+                    }
+                    staking_rewards
                 };
                 UserSingleLockupInfoResponse {
                     duration,
@@ -249,6 +276,7 @@ pub fn query_user_single_lockup_info(
                     eclipastro_staked: user_lockup_info.total_eclipastro_staked,
                     eclipastro_withdrawed: user_lockup_info.total_eclipastro_withdrawed,
                     lockdrop_incentives,
+                    lockdrop_incentives_vesting,
                     withdrawal_flag: user_lockup_info.withdrawal_flag,
                     staking_rewards,
                     countdown_start_at: cfg.countdown_start_at,
@@ -272,6 +300,9 @@ pub fn query_user_single_lockup_info(
                     )
                     .unwrap()
                 };
+                let lockdrop_incentives_vesting =
+                    calculate_lockdrop_incentives_vesting(deps, block_time, &lockdrop_incentives)
+                        .unwrap();
                 UserSingleLockupInfoResponse {
                     duration,
                     xastro_amount_in_lockups: user_lockup_info.xastro_amount_in_lockups,
@@ -279,6 +310,7 @@ pub fn query_user_single_lockup_info(
                     eclipastro_withdrawed: Uint128::zero(),
                     withdrawal_flag: user_lockup_info.withdrawal_flag,
                     lockdrop_incentives,
+                    lockdrop_incentives_vesting,
                     staking_rewards: vec![],
                     countdown_start_at: cfg.countdown_start_at,
                 }
@@ -336,6 +368,12 @@ pub fn query_user_lp_lockup_info(
                 } else {
                     user_lockup_info.lockdrop_incentives.clone()
                 };
+                let lockdrop_incentives_vesting = calculate_lockdrop_incentives_vesting(
+                    deps,
+                    env.block.time.seconds(),
+                    &lockdrop_incentives,
+                )
+                .unwrap();
                 let staking_rewards = if blacklist.contains(&user_address) {
                     vec![]
                 } else {
@@ -362,6 +400,7 @@ pub fn query_user_lp_lockup_info(
                     lp_token_staked: user_lockup_info.total_lp_staked,
                     lp_token_withdrawed: user_lockup_info.total_lp_withdrawed,
                     lockdrop_incentives,
+                    lockdrop_incentives_vesting,
                     withdrawal_flag: user_lockup_info.withdrawal_flag,
                     staking_rewards,
                     countdown_start_at: cfg.countdown_start_at,
@@ -386,6 +425,12 @@ pub fn query_user_lp_lockup_info(
                     )
                     .unwrap()
                 };
+                let lockdrop_incentives_vesting = calculate_lockdrop_incentives_vesting(
+                    deps,
+                    env.block.time.seconds(),
+                    &lockdrop_incentives,
+                )
+                .unwrap();
                 UserLpLockupInfoResponse {
                     duration,
                     xastro_amount_in_lockups: user_lockup_info.xastro_amount_in_lockups,
@@ -393,6 +438,7 @@ pub fn query_user_lp_lockup_info(
                     lp_token_withdrawed: Uint128::zero(),
                     withdrawal_flag: user_lockup_info.withdrawal_flag,
                     lockdrop_incentives,
+                    lockdrop_incentives_vesting,
                     staking_rewards: vec![],
                     countdown_start_at: cfg.countdown_start_at,
                     reward_weights: user_lockup_info.reward_weights,
@@ -413,8 +459,17 @@ pub fn query_incentives(deps: Deps, stake_type: StakeType) -> StdResult<Incentiv
         .unwrap_or_default())
 }
 
-pub fn query_blacklist(deps: Deps) -> StdResult<Vec<String>> {
-    Ok(BLACK_LIST.load(deps.storage).unwrap_or_default())
+pub fn query_blacklist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    Ok(paginate_addresses(
+        BLACK_LIST.load(deps.storage).unwrap_or_default(),
+        start_after,
+        limit,
+    ))
 }
 
 pub fn query_blacklist_rewards(deps: Deps, env: Env) -> StdResult<BlacklistRewards> {
@@ -482,6 +537,16 @@ pub fn query_blacklist_rewards(deps: Deps, env: Env) -> StdResult<BlacklistRewar
                 + user_lockup_info.unclaimed_rewards.beclip;
             blacklist_rewards.eclipastro +=
                 single_staking_reward.eclipastro + user_lockup_info.unclaimed_rewards.eclipastro;
+            for reward in user_lockup_info.unclaimed_rewards.assets.into_iter().chain(
+                single_staking_reward
+                    .assets
+                    .into_iter()
+                    .map(|r| r.info.with_balance(r.amount)),
+            ) {
+                if !reward.amount.is_zero() {
+                    add_asset(&mut blacklist_rewards.assets, reward);
+                }
+            }
         }
         let lp_total_rewards = calculate_lp_total_rewards(deps, env.contract.address.to_string())?;
         let updated_reward_weights =
@@ -549,6 +614,41 @@ pub fn calculate_pending_lockdrop_incentive(
     deps: Deps,
     current_time: u64,
     incentive: LockdropIncentive,
+) -> StdResult<Uint128> {
+    let vested_amount = calculate_vested_lockdrop_incentive(deps, current_time, &incentive)?;
+    Ok(vested_amount.saturating_sub(incentive.claimed))
+}
+
+pub fn calculate_lockdrop_incentives_vesting(
+    deps: Deps,
+    current_time: u64,
+    incentives: &LockdropIncentives,
+) -> StdResult<LockdropIncentivesVesting> {
+    Ok(LockdropIncentivesVesting {
+        eclip: calculate_lockdrop_incentive_vesting(deps, current_time, &incentives.eclip)?,
+        beclip: calculate_lockdrop_incentive_vesting(deps, current_time, &incentives.beclip)?,
+    })
+}
+
+pub fn calculate_lockdrop_incentive_vesting(
+    deps: Deps,
+    current_time: u64,
+    incentive: &LockdropIncentive,
+) -> StdResult<LockdropIncentiveVesting> {
+    let vested = calculate_vested_lockdrop_incentive(deps, current_time, incentive)?;
+    Ok(LockdropIncentiveVesting {
+        claimable: vested.saturating_sub(incentive.claimed),
+        vested,
+        remaining: incentive.allocated - vested,
+    })
+}
+
+/// instant part of allocated incentive is vested when claims are allowed,
+/// the rest is vested linearly from countdown_start_at during vesting_period
+pub fn calculate_vested_lockdrop_incentive(
+    deps: Deps,
+    current_time: u64,
+    incentive: &LockdropIncentive,
 ) -> StdResult<Uint128> {
     let cfg = CONFIG.load(deps.storage)?;
     let reward_cfg = REWARD_DISTRIBUTION_CONFIG.load(deps.storage)?;
@@ -560,9 +660,11 @@ pub fn calculate_pending_lockdrop_incentive(
         .allocated
         .multiply_ratio(reward_cfg.instant, BPS_DENOMINATOR);
     let vesting_amount = incentive.allocated - instant_amount;
-    let max_allowed_to_claim = if current_time >= cfg.countdown_start_at + reward_cfg.vesting_period
-    {
+    let vested_amount = if current_time >= cfg.countdown_start_at + reward_cfg.vesting_period {
         incentive.allocated
+    } else if current_time <= cfg.countdown_start_at {
+        // also covers zero vesting period before countdown start
+        instant_amount
     } else {
         instant_amount
             .checked_add(vesting_amount.multiply_ratio(
@@ -571,10 +673,7 @@ pub fn calculate_pending_lockdrop_incentive(
             ))
             .unwrap()
     };
-    let claimable_amount = max_allowed_to_claim
-        .checked_sub(incentive.claimed)
-        .unwrap_or_default();
-    Ok(claimable_amount)
+    Ok(vested_amount)
 }
 
 pub fn get_user_single_lockdrop_incentives(
@@ -589,86 +688,18 @@ pub fn get_user_single_lockdrop_incentives(
         return Ok(lockdrop_incentives);
     }
     let cfg = CONFIG.load(deps.storage)?;
-    let lock_configs = cfg.lock_configs.clone();
     let single_lockdrop_incentives = SINGLE_LOCKDROP_INCENTIVES
         .load(deps.storage)
         .unwrap_or_default();
-    let single_sided_state = SINGLE_LOCKUP_STATE.load(deps.storage)?;
-    let duration_multiplier = cfg
-        .lock_configs
-        .into_iter()
-        .find(|c| c.duration == duration)
-        .unwrap_or_default()
-        .multiplier;
-    let lockdrop_incentives = if cfg.claims_allowed {
-        LockdropIncentives {
-            eclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        single_lockdrop_incentives.eclip,
-                        single_sided_state.weighted_total_xastro,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-            beclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        single_lockdrop_incentives.beclip,
-                        single_sided_state.weighted_total_xastro,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-        }
-    } else {
-        let total_weighted_xastro_amount_for_single_staking = SINGLE_LOCKUP_INFO
-            .range(deps.storage, None, None, Order::Ascending)
-            .fold(Uint128::zero(), |acc, cur| {
-                let (duration, info) = cur.unwrap();
-                let duration_multiplier = lock_configs
-                    .clone()
-                    .into_iter()
-                    .find(|c| c.duration == duration)
-                    .unwrap_or_default()
-                    .multiplier;
-                acc.checked_add(
-                    info.xastro_amount_in_lockups
-                        .multiply_ratio(duration_multiplier, BPS_DENOMINATOR),
-                )
-                .unwrap()
-            });
-        LockdropIncentives {
-            eclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        single_lockdrop_incentives.eclip,
-                        total_weighted_xastro_amount_for_single_staking,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-            beclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        single_lockdrop_incentives.beclip,
-                        total_weighted_xastro_amount_for_single_staking,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-        }
-    };
-    Ok(lockdrop_incentives)
+    let weight = calculate_weighted_xastro(&cfg.lock_configs, duration, xastro_amount_in_lockups);
+    let total_weight = get_total_lockdrop_weight(deps, &StakeType::SingleStaking)?;
+    Ok(allocate_lockdrop_incentives(
+        weight,
+        total_weight,
+        single_lockdrop_incentives,
+    ))
 }
+
 pub fn get_user_lp_lockdrop_incentives(
     deps: Deps,
     lockdrop_incentives: LockdropIncentives,
@@ -681,82 +712,139 @@ pub fn get_user_lp_lockdrop_incentives(
         return Ok(lockdrop_incentives);
     }
     let cfg = CONFIG.load(deps.storage)?;
-    let lock_configs = cfg.lock_configs.clone();
     let lp_lockdrop_incentives = LP_LOCKDROP_INCENTIVES
         .load(deps.storage)
         .unwrap_or_default();
-    let lp_state = LP_LOCKUP_STATE.load(deps.storage)?;
-    let duration_multiplier = cfg
-        .lock_configs
-        .into_iter()
-        .find(|c| c.duration == duration)
-        .unwrap_or_default()
-        .multiplier;
-    let lockdrop_incentives = if cfg.claims_allowed {
-        LockdropIncentives {
-            eclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(lp_lockdrop_incentives.eclip, lp_state.weighted_total_xastro)
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-            beclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        lp_lockdrop_incentives.beclip,
-                        lp_state.weighted_total_xastro,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
+    let weight = calculate_weighted_xastro(&cfg.lock_configs, duration, xastro_amount_in_lockups);
+    let total_weight = get_total_lockdrop_weight(deps, &StakeType::LpStaking)?;
+    Ok(allocate_lockdrop_incentives(
+        weight,
+        total_weight,
+        lp_lockdrop_incentives,
+    ))
+}
+
+/// referrers share lockdrop incentives with referral_bonus part of referred users weight
+pub fn get_referrer_lockdrop_incentives(
+    deps: Deps,
+    stake_type: &StakeType,
+    referral_info: &ReferralInfo,
+) -> StdResult<LockdropIncentives> {
+    let lockdrop_incentives = referral_info.lockdrop_incentives.clone();
+    if !lockdrop_incentives.eclip.allocated.is_zero()
+        || !lockdrop_incentives.beclip.allocated.is_zero()
+    {
+        return Ok(lockdrop_incentives);
+    }
+    let cfg = CONFIG.load(deps.storage)?;
+    let incentives = match stake_type {
+        StakeType::SingleStaking => SINGLE_LOCKDROP_INCENTIVES.load(deps.storage),
+        StakeType::LpStaking => LP_LOCKDROP_INCENTIVES.load(deps.storage),
+    }
+    .unwrap_or_default();
+    let weight = referral_info
+        .weighted_xastro
+        .multiply_ratio(cfg.referral_bonus, BPS_DENOMINATOR);
+    let total_weight = get_total_lockdrop_weight(deps, stake_type)?;
+    Ok(allocate_lockdrop_incentives(
+        weight,
+        total_weight,
+        incentives,
+    ))
+}
+
+/// weight sharing lockdrop incentives, it's fixed when lockups are staked to vaults
+pub fn get_total_lockdrop_weight(deps: Deps, stake_type: &StakeType) -> StdResult<Uint128> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let (lockup_info, total_referral_weight) = match stake_type {
+        StakeType::SingleStaking => (SINGLE_LOCKUP_INFO, SINGLE_TOTAL_REFERRAL_WEIGHT),
+        StakeType::LpStaking => (LP_LOCKUP_INFO, LP_TOTAL_REFERRAL_WEIGHT),
+    };
+    let weighted_total_xastro = match (cfg.claims_allowed, stake_type) {
+        (true, StakeType::SingleStaking) => {
+            SINGLE_LOCKUP_STATE
+                .load(deps.storage)?
+                .weighted_total_xastro
         }
-    } else {
-        let total_weighted_xastro_amount_for_lp_staking = LP_LOCKUP_INFO
+        (true, StakeType::LpStaking) => LP_LOCKUP_STATE.load(deps.storage)?.weighted_total_xastro,
+        (false, _) => lockup_info
             .range(deps.storage, None, None, Order::Ascending)
-            .fold(Uint128::zero(), |acc, cur| {
-                let (duration, info) = cur.unwrap();
-                let duration_multiplier = lock_configs
-                    .clone()
-                    .into_iter()
-                    .find(|c| c.duration == duration)
-                    .unwrap_or_default()
-                    .multiplier;
-                acc.checked_add(
-                    info.xastro_amount_in_lockups
-                        .multiply_ratio(duration_multiplier, BPS_DENOMINATOR),
-                )
-                .unwrap()
-            });
-        LockdropIncentives {
-            eclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        lp_lockdrop_incentives.eclip,
-                        total_weighted_xastro_amount_for_lp_staking,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-            beclip: LockdropIncentive {
-                allocated: Uint256::from(xastro_amount_in_lockups)
-                    .multiply_ratio(duration_multiplier, BPS_DENOMINATOR)
-                    .multiply_ratio(
-                        lp_lockdrop_incentives.beclip,
-                        total_weighted_xastro_amount_for_lp_staking,
-                    )
-                    .try_into()
-                    .unwrap_or_default(),
-                claimed: Uint128::zero(),
-            },
-        }
+            .try_fold(Uint128::zero(), |acc, cur| -> StdResult<Uint128> {
+                let (duration, info) = cur?;
+                Ok(acc
+                    + calculate_weighted_xastro(
+                        &cfg.lock_configs,
+                        duration,
+                        info.xastro_amount_in_lockups,
+                    ))
+            })?,
     };
-    Ok(lockdrop_incentives)
+    let referral_weight = total_referral_weight
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .multiply_ratio(cfg.referral_bonus, BPS_DENOMINATOR);
+    Ok(weighted_total_xastro + referral_weight)
+}
+
+fn allocate_lockdrop_incentives(
+    weight: Uint128,
+    total_weight: Uint128,
+    incentives: IncentiveAmounts,
+) -> LockdropIncentives {
+    if total_weight.is_zero() {
+        return LockdropIncentives::default();
+    }
+    LockdropIncentives {
+        eclip: LockdropIncentive {
+            allocated: Uint256::from(weight)
+                .multiply_ratio(incentives.eclip, total_weight)
+                .try_into()
+                .unwrap_or_default(),
+            claimed: Uint128::zero(),
+        },
+        beclip: LockdropIncentive {
+            allocated: Uint256::from(weight)
+                .multiply_ratio(incentives.beclip, total_weight)
+                .try_into()
+                .unwrap_or_default(),
+            claimed: Uint128::zero(),
+        },
+    }
+}
+
+pub fn query_referrer(deps: Deps, user: String) -> StdResult<Option<String>> {
+    REFERRERS.may_load(deps.storage, &user)
+}
+
+pub fn query_referral_info(
+    deps: Deps,
+    env: Env,
+    referrer: String,
+) -> StdResult<Vec<ReferralInfoResponse>> {
+    let block_time = env.block.time.seconds();
+    [StakeType::SingleStaking, StakeType::LpStaking]
+        .into_iter()
+        .map(|stake_type| {
+            let referral_info = match stake_type {
+                StakeType::SingleStaking => SINGLE_REFERRAL_INFO.may_load(deps.storage, &referrer),
+                StakeType::LpStaking => LP_REFERRAL_INFO.may_load(deps.storage, &referrer),
+            }?
+            .unwrap_or_default();
+            let lockdrop_incentives =
+                get_referrer_lockdrop_incentives(deps, &stake_type, &referral_info)?;
+            let claimable = calculate_pending_lockdrop_incentives(
+                deps,
+                block_time,
+                lockdrop_incentives.clone(),
+            )?;
+            Ok(ReferralInfoResponse {
+                stake_type,
+                weighted_xastro: referral_info.weighted_xastro,
+                lockdrop_incentives,
+                claimable,
+            })
+        })
+        .collect()
 }
 
 pub fn calculate_lp_total_rewards(deps: Deps, user: String) -> StdResult<LpStakingRewards> {
@@ -1,5 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_utils::{ParseReplyError, PaymentError};
+use equinox_msg::ownership::OwnershipError;
 use thiserror::Error;
 
 use cw_controllers::AdminError;
@@ -15,9 +16,21 @@ pub enum ContractError {
     #[error("Withdraw allowes only one time during withdraw window")]
     AlreadyWithdrawed {},
 
+    #[error("User is already in blacklist: {0}")]
+    AlreadyBlacklisted(String),
+
+    #[error("Accepted ASTRO assets don't match ASTRO converter config")]
+    AstroConverterMismatch {},
+
+    #[error("ASTRO converter is not set")]
+    AstroConverterNotSet {},
+
     #[error("User is in blacklist")]
     Blacklisted {},
 
+    #[error("User is not in blacklist: {0}")]
+    NotBlacklisted(String),
+
     #[error("Claim reward not allowed")]
     ClaimRewardNotAllowed {},
 
@@ -72,6 +85,9 @@ pub enum ContractError {
     #[error("Invalid init_timestamp. Current timestamp : {0}")]
     InvalidInitTimestamp(u64),
 
+    #[error("Invalid instant bps {0}")]
+    InvalidInstantBps(u64),
+
     #[error("Duplicated duration or invalid reward multiplier")]
     InvalidLockConfig {},
 
@@ -84,12 +100,21 @@ pub enum ContractError {
     #[error("Invalid Penalty bps {0}")]
     InvalidPenalty(u64),
 
+    #[error("Invalid referral bonus bps {0}")]
+    InvalidReferralBonus(u64),
+
+    #[error("User can't refer self")]
+    InvalidReferrer {},
+
     #[error("Invalid token balance")]
     InvalidTokenBalance {},
 
     #[error("Time window must be greater than 86400, but got {0}")]
     InvalidTimeWindow(u64),
 
+    #[error("Duration {0} is not a timelock of lp vault")]
+    InvalidVaultDuration(u64),
+
     #[error("Lockdrop has ended")]
     LockdropEnded {},
 
@@ -105,12 +130,21 @@ pub enum ContractError {
     #[error("Contract is undergoing")]
     Migrating {},
 
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
     #[error("{0}")]
     ParseReplyError(#[from] ParseReplyError),
 
+    #[error("Referrer is already set: {0}")]
+    ReferrerAlreadySet(String),
+
+    #[error("Referrer can be set only on own deposit")]
+    ReferrerNotAllowed {},
+
     #[error("Semver parsing error: {0}")]
     SemVer(String),
 
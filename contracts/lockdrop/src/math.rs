@@ -1,7 +1,9 @@
 use cosmwasm_std::{Decimal256, StdResult, Uint128, Uint256};
-use equinox_msg::lockdrop::Config;
+use equinox_msg::lockdrop::{Config, LockConfig};
 use std::cmp::min;
 
+use crate::config::BPS_DENOMINATOR;
+
 pub fn calculate_weight(amount: Uint128, duration: u64, config: &Config) -> StdResult<Uint256> {
     let lock_config = config
         .lock_configs
@@ -15,6 +17,20 @@ pub fn calculate_weight(amount: Uint128, duration: u64, config: &Config) -> StdR
         .to_uint_floor())
 }
 
+/// xASTRO amount weighted by lockdrop multiplier of duration
+pub fn calculate_weighted_xastro(
+    lock_configs: &[LockConfig],
+    duration: u64,
+    amount: Uint128,
+) -> Uint128 {
+    let multiplier = lock_configs
+        .iter()
+        .find(|c| c.duration == duration)
+        .map(|c| c.multiplier)
+        .unwrap_or_default();
+    amount.multiply_ratio(multiplier, BPS_DENOMINATOR)
+}
+
 pub fn calculate_max_withdrawal_amount_allowed(
     current_timestamp: u64,
     config: &Config,
@@ -3,8 +3,8 @@ use cw_controllers::Admin;
 use cw_storage_plus::{Item, Map};
 use equinox_msg::lockdrop::{
     BlacklistRewards, Config, IncentiveAmounts, LockupInfo, LpLockupState, LpStakingRewardWeights,
-    LpUserLockupInfo, RewardDistributionConfig, SingleLockupState, SingleStakingRewardWeights,
-    SingleUserLockupInfo,
+    LpUserLockupInfo, ReferralInfo, RewardDistributionConfig, SingleLockupState,
+    SingleStakingRewardWeights, SingleUserLockupInfo,
 };
 
 /// Contract name that is used for migration.
@@ -41,3 +41,11 @@ pub const LP_LOCKDROP_INCENTIVES: Item<IncentiveAmounts> = Item::new("lp_lockdro
 pub const BLACK_LIST: Item<Vec<String>> = Item::new("blacklist");
 pub const BLACK_LIST_REWARDS: Item<BlacklistRewards> = Item::new("blacklist_rewards");
 pub const ADJUST_REWARDS: Map<&(String, u64), Uint128> = Map::new("adjust_rewards");
+/// referrer by user address
+pub const REFERRERS: Map<&String, String> = Map::new("referrers");
+/// referral info by referrer address
+pub const SINGLE_REFERRAL_INFO: Map<&String, ReferralInfo> = Map::new("single_referral_info");
+pub const LP_REFERRAL_INFO: Map<&String, ReferralInfo> = Map::new("lp_referral_info");
+/// weighted xASTRO of all referred users
+pub const SINGLE_TOTAL_REFERRAL_WEIGHT: Item<Uint128> = Item::new("single_total_referral_weight");
+pub const LP_TOTAL_REFERRAL_WEIGHT: Item<Uint128> = Item::new("lp_total_referral_weight");
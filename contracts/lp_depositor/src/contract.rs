@@ -1,14 +1,20 @@
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
 };
-use equinox_msg::lp_depositor::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use equinox_msg::{
+    lp_depositor::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    ownership::{
+        claim_ownership, drop_ownership_proposal, propose_new_owner, query_ownership_proposal,
+    },
+};
 
 use crate::{
     entry::{
-        execute::{_try_callback, try_convert},
+        execute::{_try_callback, try_convert, try_update_config},
         instantiate::try_instantiate,
-        query::{query_config, query_simulate},
+        query::{query_config, query_owner, query_simulate},
     },
+    state::{CONFIG, OWNER},
     ContractError,
 };
 
@@ -32,7 +38,22 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Convert { recipient } => try_convert(deps, env, info, recipient),
+        ExecuteMsg::Convert {
+            recipient,
+            min_lp_out,
+            max_spread,
+        } => try_convert(deps, env, info, recipient, min_lp_out, max_spread, false),
+        ExecuteMsg::ConvertAndStake {
+            recipient,
+            min_lp_out,
+            max_spread,
+        } => try_convert(deps, env, info, recipient, min_lp_out, max_spread, true),
+        ExecuteMsg::UpdateConfig { config } => try_update_config(deps, env, info, config),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => Ok(propose_new_owner(
+            deps, env, info, &OWNER, owner, expires_in,
+        )?),
+        ExecuteMsg::DropOwnershipProposal {} => Ok(drop_ownership_proposal(deps, info, &OWNER)?),
+        ExecuteMsg::ClaimOwnership {} => Ok(claim_ownership(deps, env, info, &OWNER)?),
         ExecuteMsg::Callback(msg) => _try_callback(deps, env, info, msg),
     }
 }
@@ -42,11 +63,24 @@ pub fn execute(
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => Ok(to_json_binary(&query_config(deps)?)?),
-        QueryMsg::Simulate { asset } => Ok(to_json_binary(&query_simulate(deps, asset)?)?),
+        QueryMsg::Owner {} => Ok(to_json_binary(&query_owner(deps)?)?),
+        QueryMsg::OwnershipProposal {} => Ok(to_json_binary(&query_ownership_proposal(deps)?)?),
+        QueryMsg::Simulate { assets } => Ok(to_json_binary(&query_simulate(deps, assets)?)?),
     }
 }
 /// Manages contract migration.
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    if let Some(lp_staking) = msg.lp_staking {
+        let lp_staking = deps.api.addr_validate(&lp_staking)?;
+        CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+            config.lp_staking = Some(lp_staking);
+            Ok(config)
+        })?;
+    }
+    if let Some(owner) = msg.owner {
+        let owner = deps.api.addr_validate(&owner)?;
+        OWNER.set(deps.branch(), Some(owner))?;
+    }
     Ok(Response::new())
 }
@@ -4,73 +4,137 @@ use astroport::{
     staking::ExecuteMsg as AstroportStakingExecuteMsg,
 };
 use cosmwasm_std::{
-    coin, ensure, ensure_eq, to_json_binary, CosmosMsg, DepsMut, Env, MessageInfo, Response,
-    Uint128, WasmMsg,
+    coin, ensure, ensure_eq, to_json_binary, BankMsg, CosmosMsg, Decimal, DepsMut, Env,
+    MessageInfo, Response, Uint128, WasmMsg,
 };
-use cw_utils::one_coin;
+use cw_utils::PaymentError;
 use eclipse_base::voter::msg::ExecuteMsg as VoterExecuteMsg;
-use equinox_msg::lp_depositor::CallbackMsg;
+use equinox_msg::{
+    lp_depositor::{CallbackMsg, UpdateConfigMsg},
+    lp_staking::ExecuteMsg as LpStakingExecuteMsg,
+    utils::has_unique_elements,
+};
+
+use crate::{
+    entry::query::get_amounts_to_convert,
+    state::{CONFIG, OWNER},
+    ContractError,
+};
 
-use crate::{entry::query::get_asset_amount_to_convert_eclipastro, state::CONFIG, ContractError};
+/// Update config
+/// Only owner
+pub fn try_update_config(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_config: UpdateConfigMsg,
+) -> Result<Response, ContractError> {
+    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    let mut res: Response = Response::new().add_attribute("action", "update config");
+    if let Some(voter) = new_config.voter {
+        config.voter = deps.api.addr_validate(&voter)?;
+        res = res.add_attribute("voter", voter);
+    }
+    if let Some(lp_staking) = new_config.lp_staking {
+        config.lp_staking = Some(deps.api.addr_validate(&lp_staking)?);
+        res = res.add_attribute("lp_staking", lp_staking);
+    }
+    CONFIG.save(deps.storage, &config)?;
+    Ok(res)
+}
 
 pub fn try_convert(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     recipient: Option<String>,
+    min_lp_out: Option<Uint128>,
+    max_spread: Option<Decimal>,
+    stake: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let asset = one_coin(&info)?;
-    let recipient = recipient.unwrap_or(info.sender.to_string());
-
+    let sender = info.sender.to_string();
+    let recipient = recipient.unwrap_or(sender.clone());
+    ensure!(
+        !stake || config.lp_staking.is_some(),
+        ContractError::LpStakingNotSet {}
+    );
+    ensure!(!info.funds.is_empty(), PaymentError::NoFunds {});
     ensure!(
-        asset.denom == config.astro
-            || asset.denom == config.xastro
-            || asset.denom == config.eclipastro,
-        ContractError::InvalidCoinAsset(asset.denom)
+        has_unique_elements(info.funds.iter().map(|c| c.denom.clone())),
+        ContractError::DuplicatedAssets {}
     );
 
+    let (mut astro, mut xastro, mut eclipastro) =
+        (Uint128::zero(), Uint128::zero(), Uint128::zero());
+    for asset in info.funds {
+        match asset.denom {
+            denom if denom == config.astro => astro += asset.amount,
+            denom if denom == config.xastro => xastro += asset.amount,
+            denom if denom == config.eclipastro => eclipastro += asset.amount,
+            denom => Err(ContractError::InvalidCoinAsset(denom))?,
+        }
+    }
+
+    let amounts = get_amounts_to_convert(deps.as_ref(), astro, xastro, eclipastro)?;
     let mut msgs = vec![];
 
-    if asset.denom != config.eclipastro {
-        let amount_to_eclipastro = get_asset_amount_to_convert_eclipastro(
-            deps.as_ref(),
-            &Asset {
-                info: AssetInfo::NativeToken {
-                    denom: asset.denom.clone(),
-                },
-                amount: asset.amount,
-            },
-        )?;
-        let amount_to_xastro = asset.amount - amount_to_eclipastro;
+    if !amounts.astro_to_xastro.is_zero() {
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.staking_contract.to_string(),
+            msg: to_json_binary(&AstroportStakingExecuteMsg::Enter { receiver: None })?,
+            funds: vec![coin(amounts.astro_to_xastro.u128(), config.astro.clone())],
+        }));
+    }
+    if !amounts.astro_to_eclipastro.is_zero() {
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.voter.to_string(),
+            msg: to_json_binary(&VoterExecuteMsg::SwapToEclipAstro {})?,
+            funds: vec![coin(amounts.astro_to_eclipastro.u128(), config.astro)],
+        }));
+    }
+    if !amounts.xastro_to_eclipastro.is_zero() {
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.voter.to_string(),
+            msg: to_json_binary(&VoterExecuteMsg::SwapToEclipAstro {})?,
+            funds: vec![coin(amounts.xastro_to_eclipastro.u128(), config.xastro)],
+        }));
+    }
 
-        if asset.denom == config.astro {
-            if !amount_to_xastro.is_zero() {
-                msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: config.staking_contract.to_string(),
-                    msg: to_json_binary(&AstroportStakingExecuteMsg::Enter { receiver: None })?,
-                    funds: vec![coin(amount_to_xastro.u128(), config.astro.clone())],
-                }));
+    if stake {
+        // lp tokens are received by the contract and staked on behalf of recipient
+        let prev_lp_balance = deps
+            .querier
+            .query_balance(env.contract.address.to_string(), config.lp_token)?
+            .amount;
+        msgs.push(
+            CallbackMsg::DepositIntoPool {
+                recipient: env.contract.address.to_string(),
+                sender,
+                min_lp_out,
+                max_spread,
             }
-            if !amount_to_eclipastro.is_zero() {
-                msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: config.voter.to_string(),
-                    msg: to_json_binary(&VoterExecuteMsg::SwapToEclipAstro {})?,
-                    funds: vec![coin(amount_to_eclipastro.u128(), config.astro)],
-                }));
+            .to_cosmos_msg(&env)?,
+        );
+        msgs.push(
+            CallbackMsg::StakeLpToken {
+                recipient,
+                prev_lp_balance,
             }
-        }
-        if asset.denom == config.xastro {
-            if !amount_to_eclipastro.is_zero() {
-                msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: config.voter.to_string(),
-                    msg: to_json_binary(&VoterExecuteMsg::SwapToEclipAstro {})?,
-                    funds: vec![coin(amount_to_eclipastro.u128(), config.xastro)],
-                }));
+            .to_cosmos_msg(&env)?,
+        );
+    } else {
+        msgs.push(
+            CallbackMsg::DepositIntoPool {
+                recipient,
+                sender,
+                min_lp_out,
+                max_spread,
             }
-        }
+            .to_cosmos_msg(&env)?,
+        );
     }
-    msgs.push(CallbackMsg::DepositIntoPool { recipient }.to_cosmos_msg(&env)?);
     Ok(Response::new().add_messages(msgs))
 }
 
@@ -87,7 +151,16 @@ pub fn _try_callback(
         ContractError::InvalidCallbackInvoke {}
     );
     match msg {
-        CallbackMsg::DepositIntoPool { recipient } => try_deposit_into_pool(deps, env, recipient),
+        CallbackMsg::DepositIntoPool {
+            recipient,
+            sender,
+            min_lp_out,
+            max_spread,
+        } => try_deposit_into_pool(deps, env, recipient, sender, min_lp_out, max_spread),
+        CallbackMsg::StakeLpToken {
+            recipient,
+            prev_lp_balance,
+        } => try_stake_lp_token(deps, env, recipient, prev_lp_balance),
     }
 }
 
@@ -95,8 +168,14 @@ fn try_deposit_into_pool(
     deps: DepsMut,
     env: Env,
     recipient: String,
+    sender: String,
+    min_lp_out: Option<Uint128>,
+    max_spread: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
+    let astro_balance = deps
+        .querier
+        .query_balance(env.contract.address.to_string(), cfg.astro.clone())?;
     let eclipastro_balance = deps
         .querier
         .query_balance(env.contract.address.to_string(), cfg.eclipastro.clone())?;
@@ -118,7 +197,7 @@ fn try_deposit_into_pool(
     if !xastro_balance.amount.is_zero() {
         funds.push(coin(xastro_balance.amount.u128(), cfg.xastro.clone()));
     }
-    let msgs = vec![CosmosMsg::Wasm(WasmMsg::Execute {
+    let mut msgs = vec![CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: cfg.lp_contract.to_string(),
         msg: to_json_binary(&PairExecuteMsg::ProvideLiquidity {
             assets: vec![
@@ -135,12 +214,49 @@ fn try_deposit_into_pool(
                     amount: xastro_balance.amount,
                 },
             ],
-            slippage_tolerance: None,
+            slippage_tolerance: max_spread,
             auto_stake: Some(false),
             receiver: Some(recipient),
-            min_lp_to_receive: None,
+            min_lp_to_receive: min_lp_out,
         })?,
         funds,
     })];
+    // refund ASTRO which wasn't converted
+    if !astro_balance.amount.is_zero() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: sender,
+            amount: vec![astro_balance],
+        }));
+    }
     Ok(Response::new().add_messages(msgs))
 }
+
+fn try_stake_lp_token(
+    deps: DepsMut,
+    env: Env,
+    recipient: String,
+    prev_lp_balance: Uint128,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let lp_staking = cfg.lp_staking.ok_or(ContractError::LpStakingNotSet {})?;
+    let lp_balance = deps
+        .querier
+        .query_balance(env.contract.address, cfg.lp_token.clone())?
+        .amount;
+    let lp_amount = lp_balance.checked_sub(prev_lp_balance)?;
+    ensure!(!lp_amount.is_zero(), ContractError::ZeroAmount {});
+
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: lp_staking.to_string(),
+        msg: to_json_binary(&LpStakingExecuteMsg::Stake {
+            duration: None,
+            recipient: Some(recipient.clone()),
+        })?,
+        funds: vec![coin(lp_amount.u128(), cfg.lp_token)],
+    });
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "stake lp token")
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", lp_amount))
+}
@@ -3,19 +3,25 @@ use cw2::set_contract_version;
 use equinox_msg::lp_depositor::{Config, InstantiateMsg};
 
 use crate::{
-    state::{CONFIG, CONTRACT_NAME, CONTRACT_VERSION},
+    state::{CONFIG, CONTRACT_NAME, CONTRACT_VERSION, OWNER},
     ContractError,
 };
 
 pub fn try_instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     let _ = env;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let owner = match msg.owner {
+        Some(owner) => deps.api.addr_validate(&owner)?,
+        None => info.sender,
+    };
+    OWNER.set(deps.branch(), Some(owner))?;
+
     CONFIG.save(
         deps.storage,
         &Config {
@@ -26,6 +32,10 @@ pub fn try_instantiate(
             voter: deps.api.addr_validate(&msg.voter)?,
             lp_contract: deps.api.addr_validate(&msg.lp_contract)?,
             lp_token: msg.lp_token,
+            lp_staking: msg
+                .lp_staking
+                .map(|x| deps.api.addr_validate(&x))
+                .transpose()?,
         },
     )?;
 
@@ -1,74 +1,224 @@
-use crate::state::CONFIG;
+use crate::{
+    math::calc_provide_fee_rate,
+    state::{CONFIG, OWNER},
+};
 use astroport::{
     asset::{Asset, AssetInfo},
+    cosmwasm_ext::{ConvertInto, DecimalToInteger, IntegerToDecimal},
     pair::{ConfigResponse, PoolResponse},
     pair_concentrated::{ConcentratedPoolParams, QueryMsg as ConcentratedQueryMsg},
     staking::QueryMsg as AstroportStakingQueryMsg,
     DecimalCheckedOps,
 };
-use cosmwasm_std::{from_json, Decimal, Deps, StdResult, Uint128};
-use equinox_msg::lp_depositor::Config;
+use cosmwasm_std::{
+    ensure, from_json, Addr, Decimal, Decimal256, Deps, StdError, StdResult, Uint128,
+};
+use equinox_msg::{
+    lp_depositor::{Config, SimulateResponse},
+    utils::has_unique_elements,
+};
 
 /// query config
 pub fn query_config(deps: Deps) -> StdResult<Config> {
     let config = CONFIG.load(deps.storage)?;
     Ok(config)
 }
-pub fn query_simulate(deps: Deps, asset: Asset) -> StdResult<Uint128> {
-    let config = CONFIG.load(deps.storage)?;
 
-    if asset.info.to_string() != config.eclipastro
-        && asset.info.to_string() != config.astro
-        && asset.info.to_string() != config.xastro
-    {
-        return Ok(Uint128::zero());
+/// query owner, it's not set on contracts deployed before ownership was added until migration
+pub fn query_owner(deps: Deps) -> StdResult<Addr> {
+    OWNER.get(deps)?.ok_or_else(|| StdError::not_found("owner"))
+}
+
+pub fn query_simulate(deps: Deps, assets: Vec<Asset>) -> StdResult<SimulateResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(
+        has_unique_elements(assets.iter().map(|a| a.info.to_string())),
+        StdError::generic_err("Ensure list contains unique assets")
+    );
+    let (mut astro, mut xastro, mut eclipastro) =
+        (Uint128::zero(), Uint128::zero(), Uint128::zero());
+    let mut leftovers = vec![];
+    for asset in assets {
+        match asset.info.to_string() {
+            denom if denom == config.astro => astro += asset.amount,
+            denom if denom == config.xastro => xastro += asset.amount,
+            denom if denom == config.eclipastro => eclipastro += asset.amount,
+            _ => leftovers.push(asset),
+        }
     }
-    let astro_staking_total_deposit = query_astro_staking_total_deposit(deps)?;
-    let astro_staking_total_shares = query_astro_staking_total_shares(deps)?;
-    let mut eclipastro_amount = asset.amount;
-    let mut xastro_amount = Uint128::zero();
-    if asset.info.to_string() != config.eclipastro {
-        let amount_to_eclipastro = get_asset_amount_to_convert_eclipastro(deps, &asset)?;
-        eclipastro_amount = if asset.info.to_string() == config.xastro {
-            amount_to_eclipastro
-                .multiply_ratio(astro_staking_total_deposit, astro_staking_total_shares)
-        } else {
-            amount_to_eclipastro
-        };
-        xastro_amount = if asset.info.to_string() == config.xastro {
-            asset.amount - amount_to_eclipastro
-        } else {
-            (asset.amount - amount_to_eclipastro)
-                .multiply_ratio(astro_staking_total_shares, astro_staking_total_deposit)
-        };
+    let amounts = get_amounts_to_convert(deps, astro, xastro, eclipastro)?;
+    if amounts.eclipastro_amount.is_zero() && amounts.xastro_amount.is_zero() {
+        return Ok(SimulateResponse {
+            lp_amount: Uint128::zero(),
+            leftovers,
+            price_impact: Decimal::zero(),
+            fee: Uint128::zero(),
+        });
     }
-    deps.querier.query_wasm_smart(
-        config.lp_contract,
+    let lp_amount: Uint128 = deps.querier.query_wasm_smart(
+        &config.lp_contract,
         &ConcentratedQueryMsg::SimulateProvide {
             assets: vec![
                 Asset {
                     info: AssetInfo::NativeToken {
                         denom: config.eclipastro,
                     },
-                    amount: eclipastro_amount,
+                    amount: amounts.eclipastro_amount,
                 },
                 Asset {
                     info: AssetInfo::NativeToken {
                         denom: config.xastro,
                     },
-                    amount: xastro_amount,
+                    amount: amounts.xastro_amount,
                 },
             ],
             slippage_tolerance: None,
         },
-    )
+    )?;
+
+    let pool: PoolResponse = deps
+        .querier
+        .query_wasm_smart(&config.lp_contract, &ConcentratedQueryMsg::Pool {})?;
+    let params = query_lp_pool_params(deps)?;
+    let price_scale = Decimal256::from(params.price_scale);
+    let pool_amount = |denom: &str| {
+        pool.assets
+            .iter()
+            .find(|a| a.info.to_string() == denom)
+            .map(|a| a.amount)
+            .unwrap_or_default()
+    };
+    let pool_eclipastro = pool_amount(&config.eclipastro);
+    let pool_xastro = pool_amount(&config.xastro);
+
+    // amounts are scaled by price_scale the same way as in the pool
+    let deposits = [
+        amounts.eclipastro_amount.to_decimal256(0u8)?,
+        amounts.xastro_amount.to_decimal256(0u8)? * price_scale,
+    ];
+    let xp = [
+        (pool_eclipastro + amounts.eclipastro_amount).to_decimal256(0u8)?,
+        (pool_xastro + amounts.xastro_amount).to_decimal256(0u8)? * price_scale,
+    ];
+    let fee_rate = calc_provide_fee_rate(&deposits, &xp, &params);
+    let lp_amount_dec = lp_amount.to_decimal256(0u8)?;
+    let fee = if fee_rate < Decimal256::one() {
+        (lp_amount_dec / (Decimal256::one() - fee_rate) - lp_amount_dec).to_uint(0u8)?
+    } else {
+        Uint128::zero()
+    };
+
+    // lp amount minted for deposit at price_scale without fee
+    let pool_value =
+        pool_eclipastro.to_decimal256(0u8)? + pool_xastro.to_decimal256(0u8)? * price_scale;
+    let price_impact = if pool_value.is_zero() {
+        Decimal256::zero()
+    } else {
+        let ideal_lp_amount =
+            pool.total_share.to_decimal256(0u8)? * (deposits[0] + deposits[1]) / pool_value;
+        if ideal_lp_amount > lp_amount_dec {
+            Decimal256::one() - lp_amount_dec / ideal_lp_amount
+        } else {
+            Decimal256::zero()
+        }
+    };
+
+    Ok(SimulateResponse {
+        lp_amount,
+        leftovers,
+        price_impact: price_impact.conv()?,
+        fee,
+    })
+}
+
+/// Amounts of provided assets to convert and expected amounts to deposit to the pool
+#[derive(Default)]
+pub struct ConvertAmounts {
+    pub astro_to_xastro: Uint128,
+    pub astro_to_eclipastro: Uint128,
+    pub xastro_to_eclipastro: Uint128,
+    pub eclipastro_amount: Uint128,
+    pub xastro_amount: Uint128,
+}
+
+/// split provided assets, each asset is split against the pool with previous assets added
+/// eclipASTRO can't be converted so it's added first, ASTRO can be converted to both so it's the last
+pub fn get_amounts_to_convert(
+    deps: Deps,
+    astro: Uint128,
+    xastro: Uint128,
+    eclipastro: Uint128,
+) -> StdResult<ConvertAmounts> {
+    let config = CONFIG.load(deps.storage)?;
+    let astro_staking_total_deposit = query_astro_staking_total_deposit(deps)?;
+    let astro_staking_total_shares = query_astro_staking_total_shares(deps)?;
+    let mut lp_pool_assets = query_lp_pool_assets(deps)?;
+    let mut amounts = ConvertAmounts {
+        eclipastro_amount: eclipastro,
+        ..Default::default()
+    };
+    add_pool_asset_amount(&mut lp_pool_assets, &config.eclipastro, eclipastro);
+
+    if !xastro.is_zero() {
+        let xastro_to_eclipastro = get_asset_amount_to_convert_eclipastro(
+            deps,
+            &Asset {
+                info: AssetInfo::NativeToken {
+                    denom: config.xastro.clone(),
+                },
+                amount: xastro,
+            },
+            &lp_pool_assets,
+        )?;
+        let eclipastro_amount = xastro_to_eclipastro
+            .multiply_ratio(astro_staking_total_deposit, astro_staking_total_shares);
+        let xastro_amount = xastro - xastro_to_eclipastro;
+        add_pool_asset_amount(&mut lp_pool_assets, &config.eclipastro, eclipastro_amount);
+        add_pool_asset_amount(&mut lp_pool_assets, &config.xastro, xastro_amount);
+        amounts.xastro_to_eclipastro = xastro_to_eclipastro;
+        amounts.eclipastro_amount += eclipastro_amount;
+        amounts.xastro_amount += xastro_amount;
+    }
+
+    if !astro.is_zero() {
+        let astro_to_eclipastro = get_asset_amount_to_convert_eclipastro(
+            deps,
+            &Asset {
+                info: AssetInfo::NativeToken {
+                    denom: config.astro.clone(),
+                },
+                amount: astro,
+            },
+            &lp_pool_assets,
+        )?;
+        let astro_to_xastro = astro - astro_to_eclipastro;
+        amounts.astro_to_eclipastro = astro_to_eclipastro;
+        amounts.astro_to_xastro = astro_to_xastro;
+        amounts.eclipastro_amount += astro_to_eclipastro;
+        amounts.xastro_amount +=
+            astro_to_xastro.multiply_ratio(astro_staking_total_shares, astro_staking_total_deposit);
+    }
+
+    Ok(amounts)
 }
 
-pub fn get_asset_amount_to_convert_eclipastro(deps: Deps, asset: &Asset) -> StdResult<Uint128> {
+fn add_pool_asset_amount(lp_pool_assets: &mut [Asset], denom: &str, amount: Uint128) {
+    if let Some(asset) = lp_pool_assets
+        .iter_mut()
+        .find(|a| a.info.to_string() == denom)
+    {
+        asset.amount += amount;
+    }
+}
+
+pub fn get_asset_amount_to_convert_eclipastro(
+    deps: Deps,
+    asset: &Asset,
+    lp_pool_assets: &[Asset],
+) -> StdResult<Uint128> {
     let config = CONFIG.load(deps.storage)?;
     let astro_staking_total_deposit = query_astro_staking_total_deposit(deps)?;
     let astro_staking_total_shares = query_astro_staking_total_shares(deps)?;
-    let lp_pool_assets = query_lp_pool_assets(deps)?;
     let params = query_lp_pool_params(deps)?;
 
     let eclipastro_asset = lp_pool_assets
@@ -100,8 +250,7 @@ pub fn get_asset_amount_to_convert_eclipastro(deps: Deps, asset: &Asset) -> StdR
             if astro_amount_to_xastro.lt(&asset.amount) {
                 asset_amount_remained = asset.amount.checked_sub(astro_amount_to_xastro).unwrap();
             }
-        }
-        if xastro_amount.lt(&asset.amount) {
+        } else if xastro_amount.lt(&asset.amount) {
             asset_amount_remained = asset.amount.checked_sub(xastro_amount).unwrap();
         }
     }
@@ -159,7 +308,7 @@ pub fn get_asset_amount_to_convert_eclipastro(deps: Deps, asset: &Asset) -> StdR
     } else {
         Ok(asset_amount_remained.multiply_ratio(
             price_scale
-                .checked_mul_uint128(astro_staking_total_deposit)
+                .checked_mul_uint128(astro_staking_total_shares)
                 .unwrap(),
             price_scale
                 .checked_mul_uint128(astro_staking_total_shares)
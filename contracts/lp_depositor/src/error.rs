@@ -1,14 +1,22 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError};
+use cw_controllers::AdminError;
 use cw_utils::PaymentError;
+use equinox_msg::ownership::OwnershipError;
 use thiserror::Error;
 
 /// ## Description
 /// This enum describes registry contract errors!
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
+    #[error("{0}")]
+    Admin(#[from] AdminError),
+
     #[error("Callbacks cannot be invoked externally")]
     InvalidCallbackInvoke {},
 
+    #[error("Ensure list contains unique assets")]
+    DuplicatedAssets {},
+
     #[error("Asset is not allowed")]
     InvalidAsset {},
 
@@ -18,6 +26,15 @@ pub enum ContractError {
     #[error("Invalid token balance")]
     InvalidTokenBalance {},
 
+    #[error("Lp staking contract isn't set")]
+    LpStakingNotSet {},
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
@@ -5,6 +5,8 @@ pub mod entry {
     pub mod query;
 }
 mod error;
+/// concentrated pool math
+pub mod math;
 /// state on the blockchain
 pub mod state;
 pub use crate::error::ContractError;
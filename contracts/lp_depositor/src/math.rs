@@ -0,0 +1,129 @@
+use astroport::{cosmwasm_ext::AbsDiff, pair_concentrated::ConcentratedPoolParams};
+use cosmwasm_std::Decimal256;
+
+/// Number of assets in the pool
+const N: u8 = 2;
+
+/// Fee rate of concentrated pool with balances `xp`, same as pair_concentrated `Config::fee`
+pub fn calc_fee_rate(xp: &[Decimal256; 2], params: &ConcentratedPoolParams) -> Decimal256 {
+    let mid_fee = Decimal256::from(params.mid_fee);
+    let out_fee = Decimal256::from(params.out_fee);
+    let fee_gamma = Decimal256::from(params.fee_gamma);
+    let sum = xp[0] + xp[1];
+    if sum.is_zero() {
+        return out_fee;
+    }
+
+    let k = xp[0] * xp[1] * Decimal256::from_ratio(N * N, 1u8) / (sum * sum);
+    let k = fee_gamma / (fee_gamma + Decimal256::one() - k);
+    k * mid_fee + (Decimal256::one() - k) * out_fee
+}
+
+/// Share of minted lp tokens charged for imbalanced provide,
+/// same as pair_concentrated `calc_provide_fee`.
+/// Deviation of both deposits from average is the same with 2 assets, so only the first is counted.
+/// `deposits` and `xp` must be scaled by price_scale
+pub fn calc_provide_fee_rate(
+    deposits: &[Decimal256; 2],
+    xp: &[Decimal256; 2],
+    params: &ConcentratedPoolParams,
+) -> Decimal256 {
+    let sum = deposits[0] + deposits[1];
+    if sum.is_zero() {
+        return Decimal256::zero();
+    }
+
+    let avg = sum / Decimal256::from_ratio(N, 1u8);
+    deposits[0].diff(avg) * calc_fee_rate(xp, params) / sum
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::Decimal;
+
+    use super::*;
+
+    fn params(fee_gamma: &str) -> ConcentratedPoolParams {
+        ConcentratedPoolParams {
+            amp: Decimal::from_str("40").unwrap(),
+            gamma: Decimal::from_str("0.000145").unwrap(),
+            mid_fee: Decimal::from_str("0.0026").unwrap(),
+            out_fee: Decimal::from_str("0.0045").unwrap(),
+            fee_gamma: Decimal::from_str(fee_gamma).unwrap(),
+            repeg_profit_threshold: Decimal::from_str("0.000002").unwrap(),
+            min_price_scale_delta: Decimal::from_str("0.000146").unwrap(),
+            price_scale: Decimal::one(),
+            ma_half_time: 600,
+            track_asset_balances: None,
+            fee_share: None,
+        }
+    }
+
+    fn dec(value: &str) -> Decimal256 {
+        Decimal256::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn fee_rate() {
+        // balanced pool is charged with mid fee
+        assert_eq!(
+            calc_fee_rate(&[dec("100"), dec("100")], &params("0.00023")),
+            dec("0.0026")
+        );
+        // k = fee_gamma / (fee_gamma + 1) = 0.5
+        assert_eq!(
+            calc_fee_rate(&[dec("100"), dec("0")], &params("1")),
+            dec("0.00355")
+        );
+        // k = 4 * 300 * 100 / 400^2 = 0.75, k = 1 / (1 + 1 - 0.75) = 0.8
+        assert_eq!(
+            calc_fee_rate(&[dec("300"), dec("100")], &params("1")),
+            dec("0.00298")
+        );
+        assert_eq!(
+            calc_fee_rate(&[dec("0"), dec("0")], &params("1")),
+            dec("0.0045")
+        );
+    }
+
+    #[test]
+    fn provide_fee_rate() {
+        // balanced deposit has no fee
+        assert_eq!(
+            calc_provide_fee_rate(
+                &[dec("50"), dec("50")],
+                &[dec("100"), dec("100")],
+                &params("0.00023")
+            ),
+            Decimal256::zero()
+        );
+        // |100 - 50| * 0.0026 / 100
+        assert_eq!(
+            calc_provide_fee_rate(
+                &[dec("100"), dec("0")],
+                &[dec("100"), dec("100")],
+                &params("0.00023")
+            ),
+            dec("0.0013")
+        );
+        // |100 - 200| * 0.00298 / 400
+        assert_eq!(
+            calc_provide_fee_rate(
+                &[dec("100"), dec("300")],
+                &[dec("300"), dec("100")],
+                &params("1")
+            ),
+            dec("0.000745")
+        );
+        assert_eq!(
+            calc_provide_fee_rate(
+                &[dec("0"), dec("0")],
+                &[dec("100"), dec("100")],
+                &params("1")
+            ),
+            Decimal256::zero()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use cw_controllers::Admin;
 use cw_storage_plus::Item;
 use equinox_msg::lp_depositor::Config;
 
@@ -7,4 +8,5 @@ pub const CONTRACT_NAME: &str = "lp_depositor";
 /// Contract version that is used for migration.
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub const OWNER: Admin = Admin::new("owner");
 pub const CONFIG: Item<Config> = Item::new("config");
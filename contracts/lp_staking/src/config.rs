@@ -4,11 +4,15 @@ pub const DEFAULT_REWARD_DISTRIBUTION: RewardDistribution = RewardDistribution {
     users: 8000,
     treasury: 1750,
     funding_dao: 250,
+    routes: Vec::new(),
 };
 
 pub const ONE_DAY: u64 = 86400u64;
 pub const BPS_DENOMINATOR: u32 = 10_000u32;
 pub const DEFAULT_REWARD_DISTRIBUTION_PERIOD: u64 = 30 * ONE_DAY;
+/// max spread of reward swaps if route doesn't set it, permille
+pub const DEFAULT_MAX_SPREAD: u64 = 5;
 
 pub const DEFAULT_REWARD_PERIOD: u64 = 31_536_000; // 1 year
-pub const MAX_PROPOSAL_TTL: u64 = 1209600;
+pub const DEFAULT_PAGE_LIMIT: u32 = 30;
+pub const MAX_PAGE_LIMIT: u32 = 100;
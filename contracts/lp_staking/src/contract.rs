@@ -3,28 +3,35 @@ use cosmwasm_std::{
     StdResult,
 };
 
-use equinox_msg::lp_staking::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use equinox_msg::{
+    lp_staking::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, WithdrawOutput},
+    ownership::{
+        claim_ownership, drop_ownership_proposal, propose_new_owner, query_ownership_proposal,
+    },
+};
 
 use crate::{
     entry::{
         execute::{
-            _handle_callback, add_rewards, allow_users, block_users, claim,
-            claim_blacklist_rewards, claim_ownership, drop_ownership_proposal,
-            handle_swap_to_astro_reply, handle_withdraw_liquidity_reply, propose_new_owner,
-            remove_from_blacklist, stake, unbond, unstake, update_config,
+            _handle_callback, add_rewards, add_to_blacklist, allow_users, block_users,
+            cancel_unbond, claim, claim_blacklist_rewards, handle_swap_to_astro_reply,
+            handle_withdraw_liquidity_reply, remove_from_blacklist, restake, stake,
+            stake_lockdrop_position, swap_rewards, unbond, unlock, unstake, update_config,
             update_reward_distribution, withdraw,
         },
         instantiate::try_instantiate,
         migrate::migrate_contract,
         query::{
-            query_blacklist, query_blacklist_rewards, query_config, query_owner, query_reward,
+            query_blacklist, query_blacklist_rewards, query_config, query_locked_staking,
+            query_owner, query_pending_swap_rewards, query_protocol_fees, query_reward,
             query_reward_distribution, query_reward_schedule, query_reward_weights, query_staking,
-            query_total_staking, query_unbonded, query_user_reward_weights,
+            query_timelock_config, query_total_staking, query_unbonded, query_unbonding_tiers,
+            query_user_reward_weights,
         },
     },
     error::ContractError,
     state::{
-        ALLOWED_USERS, ECLIP_ASTRO_TO_ASTRO_REPLY_ID, WITHDRAW_LIQUIDITY_REPLY_ID,
+        ALLOWED_USERS, ECLIP_ASTRO_TO_ASTRO_REPLY_ID, OWNER, WITHDRAW_LIQUIDITY_REPLY_ID,
         XASTRO_TO_ASTRO_REPLY_ID,
     },
 };
@@ -53,12 +60,39 @@ pub fn execute(
         ExecuteMsg::UpdateRewardDistribution { distribution } => {
             update_reward_distribution(deps, env, info, distribution)
         }
-        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
-            propose_new_owner(deps, env, info, owner, expires_in)
-        }
-        ExecuteMsg::DropOwnershipProposal {} => drop_ownership_proposal(deps, info),
-        ExecuteMsg::ClaimOwnership {} => claim_ownership(deps, env, info),
-        ExecuteMsg::Stake { recipient } => stake(deps, env, info, recipient),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => Ok(propose_new_owner(
+            deps, env, info, &OWNER, owner, expires_in,
+        )?),
+        ExecuteMsg::DropOwnershipProposal {} => Ok(drop_ownership_proposal(deps, info, &OWNER)?),
+        ExecuteMsg::ClaimOwnership {} => Ok(claim_ownership(deps, env, info, &OWNER)?),
+        ExecuteMsg::Stake {
+            duration,
+            recipient,
+        } => stake(deps, env, info, duration, recipient),
+        ExecuteMsg::Restake {
+            from_duration,
+            locked_at,
+            amount,
+            to_duration,
+        } => restake(
+            deps,
+            env,
+            info,
+            from_duration,
+            locked_at,
+            amount,
+            to_duration,
+        ),
+        ExecuteMsg::StakeLockdropPosition {
+            duration,
+            locked_at,
+            recipient,
+        } => stake_lockdrop_position(deps, env, info, duration, locked_at, recipient),
+        ExecuteMsg::Unlock {
+            duration,
+            locked_at,
+            amount,
+        } => unlock(deps, env, info, duration, locked_at, amount),
         ExecuteMsg::Claim { assets } => {
             claim(deps, env, info.clone(), info.sender.to_string(), assets)
         }
@@ -66,7 +100,33 @@ pub fn execute(
         ExecuteMsg::Unstake { amount, recipient } => unstake(deps, env, info, amount, recipient),
 
         ExecuteMsg::Unbond { amount, period } => unbond(deps, env, info, amount, period),
-        ExecuteMsg::Withdraw { recipient } => withdraw(deps, env, info, recipient),
+        ExecuteMsg::CancelUnbond { index } => cancel_unbond(deps, env, info, index),
+        ExecuteMsg::Withdraw {
+            recipient,
+            min_astro_out,
+        } => withdraw(
+            deps,
+            env,
+            info,
+            recipient,
+            WithdrawOutput::Astro,
+            min_astro_out,
+            None,
+        ),
+        ExecuteMsg::WithdrawAs {
+            recipient,
+            output,
+            min_receive,
+            min_assets_to_receive,
+        } => withdraw(
+            deps,
+            env,
+            info,
+            recipient,
+            output,
+            min_receive,
+            min_assets_to_receive,
+        ),
 
         ExecuteMsg::AddRewards {
             from,
@@ -78,7 +138,9 @@ pub fn execute(
         ExecuteMsg::AllowUsers { users } => allow_users(deps, info, users),
         ExecuteMsg::BlockUsers { users } => block_users(deps, info, users),
 
+        ExecuteMsg::AddToBlacklist { user } => add_to_blacklist(deps, env, info, user),
         ExecuteMsg::RemoveFromBlacklist { user } => remove_from_blacklist(deps, env, info, user),
+        ExecuteMsg::SwapRewards { assets } => swap_rewards(deps, assets),
     }
 }
 
@@ -90,9 +152,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::RewardDistribution {} => {
             Ok(to_json_binary(&query_reward_distribution(deps, env)?)?)
         }
+        QueryMsg::ProtocolFees {} => Ok(to_json_binary(&query_protocol_fees(deps)?)?),
+        QueryMsg::PendingSwapRewards {} => Ok(to_json_binary(&query_pending_swap_rewards(deps)?)?),
         QueryMsg::Owner {} => Ok(to_json_binary(&query_owner(deps, env)?)?),
+        QueryMsg::OwnershipProposal {} => Ok(to_json_binary(&query_ownership_proposal(deps)?)?),
         QueryMsg::Staking { user } => Ok(to_json_binary(&query_staking(deps, env, user)?)?),
 
+        QueryMsg::LockedStaking { user } => {
+            Ok(to_json_binary(&query_locked_staking(deps, env, user)?)?)
+        }
+        QueryMsg::TimelockConfig {} => Ok(to_json_binary(&query_timelock_config(deps)?)?),
+
         QueryMsg::Unbonded { user } => to_json_binary(&query_unbonded(deps, env, user)?),
 
         QueryMsg::TotalStaking {} => Ok(to_json_binary(&query_total_staking(deps, env)?)?),
@@ -101,7 +171,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::UserRewardWeights { user } => Ok(to_json_binary(&query_user_reward_weights(
             deps, env, user,
         )?)?),
-        QueryMsg::Blacklist {} => Ok(to_json_binary(&query_blacklist(deps)?)?),
+        QueryMsg::Blacklist { start_after, limit } => {
+            Ok(to_json_binary(&query_blacklist(deps, start_after, limit)?)?)
+        }
         QueryMsg::BlacklistRewards => Ok(to_json_binary(&query_blacklist_rewards(deps, env)?)?),
         QueryMsg::IsAllowed { user } => {
             let is_allowed = ALLOWED_USERS.load(deps.storage, &user).unwrap_or_default();
@@ -110,6 +182,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::RewardSchedule { from } => {
             Ok(to_json_binary(&query_reward_schedule(deps, env, from)?)?)
         }
+        QueryMsg::UnbondingTiers {} => Ok(to_json_binary(&query_unbonding_tiers(deps)?)?),
     }
 }
 
@@ -1,19 +1,25 @@
 use std::str::FromStr;
 
 use astroport::{
-    asset::{Asset, AssetInfo, AssetInfoExt},
+    asset::{Asset, AssetInfo, AssetInfoExt, PairInfo},
     incentives::ExecuteMsg as IncentivesExecuteMsg,
-    staking::ExecuteMsg as StakingExecuteMsg,
+    pair::QueryMsg as PairQueryMsg,
+    router::{
+        Cw20HookMsg as RouterCw20HookMsg, ExecuteMsg as RouterExecuteMsg,
+        QueryMsg as RouterQueryMsg, SimulateSwapOperationsResponse, SwapOperation,
+    },
+    staking::{ExecuteMsg as StakingExecuteMsg, QueryMsg as StakingQueryMsg},
 };
 use cosmwasm_std::{
-    attr, coin, coins, ensure, ensure_eq, to_json_binary, wasm_execute, BankMsg, CosmosMsg,
-    Decimal256, DepsMut, Env, MessageInfo, Order, ReplyOn, Response, StdResult, SubMsg,
-    SubMsgResult, Uint128, WasmMsg,
+    coin, coins, ensure, ensure_eq, to_json_binary, wasm_execute, Addr, BankMsg, CosmosMsg,
+    Decimal, Decimal256, Deps, DepsMut, Env, Event, MessageInfo, Order, ReplyOn, Response,
+    StdResult, Storage, SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
+use cw20::Cw20ExecuteMsg;
 use cw_storage_plus::Bound;
 use cw_utils::{one_coin, ParseReplyError};
 use eclipse_base::{
-    converters::{str_to_dec, u128_to_dec},
+    converters::u128_to_dec,
     staking::{
         msg::{ExecuteMsg as EclipStakingExecuteMsg, QueryMsg as EclipStakingQueryMsg},
         types::Config as EclipStakingConfig,
@@ -25,25 +31,33 @@ use eclipse_base::{
 };
 use equinox_msg::{
     lp_staking::{
-        CallbackMsg, Config, OwnershipProposal, Reward, RewardDistribution, RewardWeight,
-        UpdateConfigMsg, UserStaking,
+        CallbackMsg, Config, PendingSwapReward, Reward, RewardAmount, RewardDistribution,
+        RewardWeight, RouteType, UpdateConfigMsg, UserLockedStaking, UserStaking, WithdrawOutput,
+        WithdrawTempData,
     },
     single_sided_staking::UnbondedItem,
-    utils::{check_unbonding_period, has_unique_elements, UNBONDING_FEE_RATE, UNBONDING_PERIOD_0},
+    utils::{
+        add_asset, check_unbonding_tiers, default_unbonding_tiers, get_unbonding_tier,
+        has_unique_elements,
+    },
 };
 
 use crate::{
-    config::{BPS_DENOMINATOR, DEFAULT_REWARD_DISTRIBUTION_PERIOD, MAX_PROPOSAL_TTL},
+    config::{BPS_DENOMINATOR, DEFAULT_MAX_SPREAD, DEFAULT_REWARD_DISTRIBUTION_PERIOD},
     entry::query::{
-        calculate_incentive_pending_rewards, calculate_pending_eclipse_rewards,
-        calculate_updated_reward_weights, calculate_user_staking_rewards, calculate_vault_rewards,
+        add_vault_reward_weights, calculate_incentive_pending_rewards,
+        calculate_pending_eclipse_rewards, calculate_position_boost_reward_weights,
+        calculate_position_rewards, calculate_updated_boost_reward_weights,
+        calculate_updated_reward_weights, calculate_user_staking_rewards,
+        calculate_vault_reward_weights, get_lock_end_time,
     },
     error::ContractError,
     state::{
-        ALLOWED_USERS, BLACK_LIST, BLACK_LIST_REWARDS, CONFIG, ECLIP_ASTRO_TO_ASTRO_REPLY_ID,
-        LAST_CLAIMED, OWNER, OWNERSHIP_PROPOSAL, REWARD, REWARD_DISTRIBUTION, REWARD_WEIGHTS,
-        STAKING, TOTAL_STAKING, USER_UNBONDED, WITHDRAW_LIQUIDITY_REPLY_ID, WITHDRAW_TEMP_DATA,
-        XASTRO_TO_ASTRO_REPLY_ID,
+        ALLOWED_USERS, BLACK_LIST, BLACK_LIST_REWARDS, BOOST_BY_END_TIME, BOOST_REWARD_WEIGHTS,
+        BOOST_REWARD_WEIGHTS_AT, CONFIG, ECLIP_ASTRO_TO_ASTRO_REPLY_ID, LAST_CLAIMED,
+        LOCKED_STAKING, OWNER, PENDING_SWAP_REWARDS, PROTOCOL_FEES, REWARD, REWARD_DISTRIBUTION,
+        REWARD_WEIGHTS, STAKING, TIMELOCK_CONFIG, TOTAL_BOOST, TOTAL_STAKING, UNBONDING_TIERS,
+        USER_UNBONDED, WITHDRAW_LIQUIDITY_REPLY_ID, WITHDRAW_TEMP_DATA, XASTRO_TO_ASTRO_REPLY_ID,
     },
 };
 
@@ -91,82 +105,27 @@ pub fn update_config(
         config.beclip = deps.api.addr_validate(&beclip)?;
         res = res.add_attribute("beclip", beclip);
     }
+    if let Some(unbonding_tiers) = new_config.unbonding_tiers {
+        check_unbonding_tiers(&unbonding_tiers, ContractError::IncorrectUnbondingPeriod)?;
+        UNBONDING_TIERS.save(deps.storage, &unbonding_tiers)?;
+        res = res.add_attribute("unbonding_tiers", "update unbonding tiers")
+    }
+    if let Some(timelock_config) = new_config.timelock_config {
+        // flexible staking isn't boosted and boosts can't be negative
+        ensure!(
+            has_unique_elements(timelock_config.iter().map(|c| c.duration))
+                && timelock_config
+                    .iter()
+                    .all(|c| c.duration != 0 && c.reward_multiplier >= u64::from(BPS_DENOMINATOR)),
+            ContractError::InvalidTimeLockConfig {}
+        );
+        TIMELOCK_CONFIG.save(deps.storage, &timelock_config)?;
+        res = res.add_attribute("timelock_config", "update timelock config")
+    }
     CONFIG.save(deps.storage, &config)?;
     Ok(res)
 }
 
-pub fn propose_new_owner(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    new_owner: String,
-    expires_in: u64,
-) -> Result<Response, ContractError> {
-    // only owner can propose new owner
-    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
-    let new_owner_addr = deps.api.addr_validate(&new_owner)?;
-
-    // Check that the new owner is not the same as the current one
-    ensure_eq!(
-        OWNER.is_admin(deps.as_ref(), &new_owner_addr).unwrap(),
-        false,
-        ContractError::SameOwner {}
-    );
-
-    if MAX_PROPOSAL_TTL < expires_in {
-        return Err(ContractError::ExpiresInErr(MAX_PROPOSAL_TTL));
-    }
-
-    let new_proposal = OwnershipProposal {
-        owner: new_owner_addr,
-        ttl: env.block.time.seconds() + expires_in,
-    };
-
-    OWNERSHIP_PROPOSAL.save(deps.storage, &new_proposal)?;
-
-    Ok(Response::new().add_attributes(vec![
-        attr("action", "propose_new_owner"),
-        attr("new_owner", new_owner),
-    ]))
-}
-
-pub fn drop_ownership_proposal(
-    deps: DepsMut,
-    info: MessageInfo,
-) -> Result<Response, ContractError> {
-    // only owner can drop ownership proposal
-    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
-
-    OWNERSHIP_PROPOSAL.remove(deps.storage);
-
-    Ok(Response::new().add_attributes(vec![attr("action", "drop_ownership_proposal")]))
-}
-
-pub fn claim_ownership(
-    mut deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-) -> Result<Response, ContractError> {
-    // only owner can drop ownership proposal
-    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
-
-    let proposal = OWNERSHIP_PROPOSAL.load(deps.storage)?;
-
-    ensure!(
-        env.block.time.seconds() > proposal.ttl,
-        ContractError::OwnershipProposalExpired {}
-    );
-
-    OWNER.set(deps.branch(), Some(proposal.owner.clone()))?;
-
-    OWNERSHIP_PROPOSAL.remove(deps.storage);
-
-    Ok(Response::new().add_attributes(vec![
-        attr("action", "claim_ownership"),
-        attr("new_owner", proposal.owner),
-    ]))
-}
-
 /// Update reward distribution
 pub fn update_reward_distribution(
     deps: DepsMut,
@@ -182,6 +141,39 @@ pub fn update_reward_distribution(
         BPS_DENOMINATOR,
         ContractError::RewardDistributionErr {}
     );
+    let cfg = CONFIG.load(deps.storage)?;
+    ensure!(
+        has_unique_elements(distribution.routes.iter().map(|r| r.info.to_string())),
+        ContractError::InvalidRewardRoute("duplicated asset".to_string())
+    );
+    for r in distribution.routes.iter() {
+        match &r.route {
+            RouteType::Send => {}
+            RouteType::StakeToXastro => ensure!(
+                r.info.to_string() == cfg.astro,
+                ContractError::InvalidRewardRoute(r.info.to_string())
+            ),
+            RouteType::Swap {
+                router, operations, ..
+            } => {
+                deps.api.addr_validate(router)?;
+                // the first operation should offer the reward asset
+                let offer_asset_info = match operations.first() {
+                    Some(SwapOperation::NativeSwap { offer_denom, .. }) => {
+                        Some(AssetInfo::native(offer_denom))
+                    }
+                    Some(SwapOperation::AstroSwap {
+                        offer_asset_info, ..
+                    }) => Some(offer_asset_info.clone()),
+                    None => None,
+                };
+                ensure!(
+                    offer_asset_info.as_ref() == Some(&r.info),
+                    ContractError::InvalidRewardRoute(r.info.to_string())
+                );
+            }
+        }
+    }
     REWARD_DISTRIBUTION.save(deps.storage, &distribution)?;
     Ok(Response::new().add_attribute("action", "update reward distribution"))
 }
@@ -207,15 +199,52 @@ pub fn _handle_callback(
 
 // stake lp token when it is native token
 pub fn stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: Option<u64>,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let locked_at = env.block.time.seconds();
+    _stake(
+        deps,
+        env,
+        info,
+        duration.unwrap_or_default(),
+        locked_at,
+        recipient,
+    )
+}
+
+/// stake lp token of lockdrop position keeping its lock time, only lockdrop
+/// position is flexible if lock is ended already
+pub fn stake_lockdrop_position(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+    locked_at: u64,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    ensure_eq!(info.sender, cfg.lockdrop, ContractError::Unauthorized {});
+    let recipient = deps.api.addr_validate(&recipient)?.to_string();
+    _stake(deps, env, info, duration, locked_at, Some(recipient))
+}
+
+fn _stake(
     mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    duration: u64,
+    locked_at: u64,
     recipient: Option<String>,
 ) -> Result<Response, ContractError> {
     let asset = one_coin(&info)?;
     let cfg = CONFIG.load(deps.storage)?;
     let sender = info.sender.clone();
     let recipient = recipient.unwrap_or(info.sender.to_string());
+    let block_time = env.block.time.seconds();
     let mut total_staking = TOTAL_STAKING.load(deps.storage).unwrap_or_default();
     let (mut user_staking, _, response) = _claim(deps.branch(), env, recipient.clone(), None)?;
 
@@ -239,18 +268,189 @@ pub fn stake(
     })];
 
     total_staking += asset.amount;
-    user_staking.staked += asset.amount;
+    if duration == 0 || get_lock_end_time(duration, locked_at) <= block_time {
+        user_staking.staked += asset.amount;
+        STAKING.save(deps.storage, &recipient, &user_staking)?;
+    } else {
+        _lock(deps.storage, &recipient, duration, locked_at, asset.amount)?;
+    }
 
     TOTAL_STAKING.save(deps.storage, &total_staking)?;
-    STAKING.save(deps.storage, &recipient, &user_staking)?;
     Ok(response
         .add_messages(msgs)
         .add_attribute("action", "stake")
         .add_attribute("sender", sender)
         .add_attribute("amount", asset.amount.to_string())
+        .add_attribute("duration", duration.to_string())
         .add_attribute("recipient", recipient))
 }
 
+/// add amount to locked position locked at given time, rewards must be claimed before
+fn _lock(
+    storage: &mut dyn Storage,
+    user: &String,
+    duration: u64,
+    locked_at: u64,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let reward_multiplier = TIMELOCK_CONFIG
+        .load(storage)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|c| c.duration == duration)
+        .ok_or(ContractError::NoLockingPeriodFound(duration))?
+        .reward_multiplier;
+    let boost = amount.multiply_ratio(
+        reward_multiplier.saturating_sub(BPS_DENOMINATOR.into()),
+        BPS_DENOMINATOR,
+    );
+    let mut position = LOCKED_STAKING
+        .may_load(storage, (user, duration, locked_at))?
+        .unwrap_or(UserLockedStaking {
+            reward_weights: REWARD_WEIGHTS.load(storage).unwrap_or_default(),
+            boost_reward_weights: BOOST_REWARD_WEIGHTS.load(storage).unwrap_or_default(),
+            ..UserLockedStaking::default()
+        });
+    position.staked += amount;
+    position.boost += boost;
+    LOCKED_STAKING.save(storage, (user, duration, locked_at), &position)?;
+
+    let total_boost = TOTAL_BOOST.may_load(storage)?.unwrap_or_default();
+    TOTAL_BOOST.save(storage, &(total_boost + boost))?;
+    BOOST_BY_END_TIME.update(
+        storage,
+        get_lock_end_time(duration, locked_at),
+        |x| -> StdResult<_> { Ok(x.unwrap_or_default() + boost) },
+    )?;
+    Ok(())
+}
+
+/// remove amount from locked position, boost is removed from total if lock isn't ended yet
+/// returns remaining position amount
+fn _unlock(
+    storage: &mut dyn Storage,
+    user: &String,
+    duration: u64,
+    locked_at: u64,
+    block_time: u64,
+    amount: Option<Uint128>,
+) -> Result<Uint128, ContractError> {
+    let mut position = LOCKED_STAKING
+        .may_load(storage, (user, duration, locked_at))?
+        .ok_or(ContractError::NoLockedAmount {})?;
+    let amount = amount.unwrap_or(position.staked);
+    ensure!(!amount.is_zero(), ContractError::ZeroAmount {});
+    ensure!(
+        amount <= position.staked,
+        ContractError::ExeedingUnstakeAmount {
+            got: amount.u128(),
+            expected: position.staked.u128()
+        }
+    );
+    let boost = position.boost.multiply_ratio(amount, position.staked);
+    let end_time = get_lock_end_time(duration, locked_at);
+    if end_time > block_time {
+        let total_boost = TOTAL_BOOST.may_load(storage)?.unwrap_or_default();
+        TOTAL_BOOST.save(storage, &total_boost.checked_sub(boost).unwrap_or_default())?;
+        BOOST_BY_END_TIME.update(storage, end_time, |x| -> StdResult<_> {
+            Ok(x.unwrap_or_default().checked_sub(boost).unwrap_or_default())
+        })?;
+    }
+    position.staked -= amount;
+    position.boost -= boost;
+    if position.staked.is_zero() {
+        LOCKED_STAKING.remove(storage, (user, duration, locked_at));
+    } else {
+        LOCKED_STAKING.save(storage, (user, duration, locked_at), &position)?;
+    }
+    Ok(amount)
+}
+
+/// lock flexible staking or locked position for the same or longer duration
+pub fn restake(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from_duration: u64,
+    locked_at: Option<u64>,
+    amount: Option<Uint128>,
+    to_duration: u64,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.to_string();
+    let block_time = env.block.time.seconds();
+    ensure!(
+        to_duration != 0 && from_duration <= to_duration,
+        ContractError::ExtendDurationErr(from_duration, to_duration)
+    );
+    let (mut user_staking, _, response) = _claim(deps.branch(), env, sender.clone(), None)?;
+    let amount = if from_duration == 0 {
+        let amount = amount.unwrap_or(user_staking.staked);
+        ensure!(!amount.is_zero(), ContractError::ZeroAmount {});
+        ensure!(
+            amount <= user_staking.staked,
+            ContractError::ExeedingUnstakeAmount {
+                got: amount.u128(),
+                expected: user_staking.staked.u128()
+            }
+        );
+        user_staking.staked -= amount;
+        STAKING.save(deps.storage, &sender, &user_staking)?;
+        amount
+    } else {
+        _unlock(
+            deps.storage,
+            &sender,
+            from_duration,
+            locked_at.unwrap_or_default(),
+            block_time,
+            amount,
+        )?
+    };
+    _lock(deps.storage, &sender, to_duration, block_time, amount)?;
+
+    Ok(response
+        .add_attribute("action", "restake")
+        .add_attribute("from_duration", from_duration.to_string())
+        .add_attribute("locked_at", locked_at.unwrap_or_default().to_string())
+        .add_attribute("to_duration", to_duration.to_string())
+        .add_attribute("amount", amount.to_string()))
+}
+
+/// move locked position to flexible staking after lock end
+pub fn unlock(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+    locked_at: u64,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.to_string();
+    let block_time = env.block.time.seconds();
+    let end_time = get_lock_end_time(duration, locked_at);
+    ensure!(
+        end_time <= block_time,
+        ContractError::LockNotEnded(end_time)
+    );
+    let (mut user_staking, _, response) = _claim(deps.branch(), env, sender.clone(), None)?;
+    let amount = _unlock(
+        deps.storage,
+        &sender,
+        duration,
+        locked_at,
+        block_time,
+        amount,
+    )?;
+    user_staking.staked += amount;
+    STAKING.save(deps.storage, &sender, &user_staking)?;
+
+    Ok(response
+        .add_attribute("action", "unlock")
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("locked_at", locked_at.to_string())
+        .add_attribute("amount", amount.to_string()))
+}
+
 pub fn _claim(
     deps: DepsMut,
     env: Env,
@@ -258,6 +458,7 @@ pub fn _claim(
     assets: Option<Vec<AssetInfo>>,
 ) -> Result<(UserStaking, Vec<RewardWeight>, Response), ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
+    let block_time = env.block.time.seconds();
     let total_staking = TOTAL_STAKING.load(deps.storage).unwrap_or_default();
     let mut user_staking = STAKING.load(deps.storage, &sender).unwrap_or_default();
     let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
@@ -265,6 +466,7 @@ pub fn _claim(
     let is_allowed_user = ALLOWED_USERS
         .load(deps.storage, &sender)
         .unwrap_or_default();
+    let is_blacklisted = blacklist.contains(&sender);
     let mut msgs = vec![];
 
     let assets_list = assets
@@ -294,95 +496,113 @@ pub fn _claim(
 
     let astroport_rewards =
         calculate_incentive_pending_rewards(deps.as_ref(), env.contract.address.clone())?;
-    let vault_rewards = calculate_vault_rewards(deps.as_ref(), env.block.time.seconds())?;
+    let vault_reward_weights = calculate_vault_reward_weights(deps.as_ref(), block_time)?;
     let pending_eclipse_rewards =
         calculate_pending_eclipse_rewards(deps.as_ref(), astroport_rewards.clone())?;
     let updated_reward_weights =
-        calculate_updated_reward_weights(deps.as_ref(), astroport_rewards, vault_rewards)?;
+        calculate_updated_reward_weights(deps.as_ref(), astroport_rewards, &vault_reward_weights)?;
+    let updated_boost_reward_weights =
+        calculate_updated_boost_reward_weights(deps.as_ref(), &vault_reward_weights)?;
+    let mut user_rewards = vec![];
     if !user_staking.staked.is_zero() {
-        let user_rewards = calculate_user_staking_rewards(
+        let rewards = calculate_user_staking_rewards(
             deps.as_ref(),
             sender.clone(),
             updated_reward_weights.clone(),
         )?;
+        user_staking.reward_weights = settle_position_rewards(
+            &user_staking.reward_weights,
+            &updated_reward_weights,
+            rewards,
+            &assets,
+            is_blacklisted,
+            &mut user_rewards,
+            &mut blacklist_rewards,
+        );
+    } else {
+        user_staking
+            .reward_weights
+            .clone_from(&updated_reward_weights);
+    }
 
-        let mut updated_user_reward_weights = vec![];
-        for r in user_rewards {
-            let claimable = !blacklist.contains(&sender)
-                && (assets.clone().is_none()
-                    || (assets.clone().is_some()
-                        && assets.clone().unwrap().iter().any(|a| a.equal(&r.info))));
-            if !r.amount.is_zero() && claimable {
-                if r.info.is_native_token() {
-                    msgs.push(r.info.with_balance(r.amount).into_msg(sender.clone())?);
-                    response = response
-                        .add_attribute("action", "claim")
-                        .add_attribute("denom", r.info.to_string())
-                        .add_attribute("amount", r.amount);
-                } else {
-                    if r.info.to_string() == cfg.beclip {
-                        if is_allowed_user {
-                            msgs.push(
-                                AssetInfo::NativeToken {
-                                    denom: cfg.eclip.clone(),
-                                }
-                                .with_balance(r.amount)
-                                .into_msg(sender.clone())?,
-                            );
-                        } else {
-                            msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                                contract_addr: cfg.eclip_staking.to_string(),
-                                msg: to_json_binary(&EclipStakingExecuteMsg::BondFor {
-                                    address_and_amount_list: vec![(sender.clone(), r.amount)],
-                                })?,
-                                funds: coins(r.amount.u128(), cfg.eclip.clone()),
-                            }));
+    // claim rewards of locked positions
+    let locked_staking = LOCKED_STAKING
+        .prefix(&sender)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((duration, locked_at), mut position) in locked_staking {
+        let position_boost_reward_weights = calculate_position_boost_reward_weights(
+            deps.as_ref(),
+            block_time,
+            get_lock_end_time(duration, locked_at),
+            &updated_boost_reward_weights,
+            &vault_reward_weights,
+        )?;
+        let rewards = calculate_position_rewards(
+            position.staked,
+            &position.reward_weights,
+            &updated_reward_weights,
+        )?;
+        position.reward_weights = settle_position_rewards(
+            &position.reward_weights,
+            &updated_reward_weights,
+            rewards,
+            &assets,
+            is_blacklisted,
+            &mut user_rewards,
+            &mut blacklist_rewards,
+        );
+        let boost_rewards = calculate_position_rewards(
+            position.boost,
+            &position.boost_reward_weights,
+            &position_boost_reward_weights,
+        )?;
+        position.boost_reward_weights = settle_position_rewards(
+            &position.boost_reward_weights,
+            &position_boost_reward_weights,
+            boost_rewards,
+            &assets,
+            is_blacklisted,
+            &mut user_rewards,
+            &mut blacklist_rewards,
+        );
+        LOCKED_STAKING.save(deps.storage, (&sender, duration, locked_at), &position)?;
+    }
+
+    for r in user_rewards {
+        if r.info.is_native_token() {
+            msgs.push(r.info.with_balance(r.amount).into_msg(sender.clone())?);
+            response = response
+                .add_attribute("action", "claim")
+                .add_attribute("denom", r.info.to_string())
+                .add_attribute("amount", r.amount);
+        } else {
+            if r.info.to_string() == cfg.beclip {
+                if is_allowed_user {
+                    msgs.push(
+                        AssetInfo::NativeToken {
+                            denom: cfg.eclip.clone(),
                         }
-                    } else {
-                        msgs.push(r.info.with_balance(r.amount).into_msg(sender.clone())?);
-                    }
-                    response = response
-                        .add_attribute("action", "claim")
-                        .add_attribute("address", r.info.to_string())
-                        .add_attribute("amount", r.amount);
+                        .with_balance(r.amount)
+                        .into_msg(sender.clone())?,
+                    );
+                } else {
+                    msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr: cfg.eclip_staking.to_string(),
+                        msg: to_json_binary(&EclipStakingExecuteMsg::BondFor {
+                            address_and_amount_list: vec![(sender.clone(), r.amount)],
+                        })?,
+                        funds: coins(r.amount.u128(), cfg.eclip.clone()),
+                    }));
                 }
-                updated_user_reward_weights.push(
-                    updated_reward_weights
-                        .clone()
-                        .into_iter()
-                        .find(|w| w.info.equal(&r.info))
-                        .unwrap(),
-                );
             } else {
-                if blacklist.contains(&sender) {
-                    let position = blacklist_rewards.iter().position(|x| x.info.equal(&r.info));
-                    match position {
-                        Some(p) => {
-                            blacklist_rewards[p].amount += r.amount;
-                        }
-                        None => {
-                            blacklist_rewards.push(r.clone());
-                        }
-                    }
-                }
-                updated_user_reward_weights.push(
-                    user_staking
-                        .reward_weights
-                        .clone()
-                        .into_iter()
-                        .find(|w| w.info.equal(&r.info))
-                        .unwrap_or(RewardWeight {
-                            info: r.info,
-                            reward_weight: Decimal256::zero(),
-                        }),
-                );
+                msgs.push(r.info.with_balance(r.amount).into_msg(sender.clone())?);
             }
+            response = response
+                .add_attribute("action", "claim")
+                .add_attribute("address", r.info.to_string())
+                .add_attribute("amount", r.amount);
         }
-        user_staking.reward_weights = updated_user_reward_weights;
-    } else {
-        user_staking
-            .reward_weights
-            .clone_from(&updated_reward_weights);
     }
 
     if !pending_eclipse_rewards.is_empty() {
@@ -394,9 +614,24 @@ pub fn _claim(
         );
     }
 
+    // fix boost reward weights at reached lock end times and remove boosts of ended locks
+    let boost_reward_weights = BOOST_REWARD_WEIGHTS.load(deps.storage).unwrap_or_default();
+    let mut total_boost = TOTAL_BOOST.load(deps.storage).unwrap_or_default();
+    for c in vault_reward_weights.expired {
+        BOOST_REWARD_WEIGHTS_AT.save(
+            deps.storage,
+            c.end_time,
+            &add_vault_reward_weights(&cfg, boost_reward_weights.clone(), c.eclip, c.beclip),
+        )?;
+        BOOST_BY_END_TIME.remove(deps.storage, c.end_time);
+        total_boost = total_boost.checked_sub(c.boost).unwrap_or_default();
+    }
+    TOTAL_BOOST.save(deps.storage, &total_boost)?;
+    BOOST_REWARD_WEIGHTS.save(deps.storage, &updated_boost_reward_weights)?;
+
     REWARD_WEIGHTS.save(deps.storage, &updated_reward_weights)?;
     STAKING.save(deps.storage, &sender, &user_staking)?;
-    LAST_CLAIMED.save(deps.storage, &env.block.time.seconds())?;
+    LAST_CLAIMED.save(deps.storage, &block_time)?;
     BLACK_LIST_REWARDS.save(deps.storage, &blacklist_rewards)?;
 
     Ok((
@@ -406,6 +641,51 @@ pub fn _claim(
     ))
 }
 
+/// collect claimable rewards of position and return its reward weights after claim,
+/// weights of rewards which aren't claimed are kept to claim them later
+fn settle_position_rewards(
+    position_reward_weights: &[RewardWeight],
+    reward_weights: &[RewardWeight],
+    rewards: Vec<RewardAmount>,
+    assets: &Option<Vec<AssetInfo>>,
+    is_blacklisted: bool,
+    user_rewards: &mut Vec<RewardAmount>,
+    blacklist_rewards: &mut Vec<RewardAmount>,
+) -> Vec<RewardWeight> {
+    let mut updated_reward_weights = vec![];
+    for r in rewards {
+        let claimable = !is_blacklisted
+            && assets
+                .as_ref()
+                .map_or(true, |a| a.iter().any(|a| a.equal(&r.info)));
+        if !r.amount.is_zero() && claimable {
+            updated_reward_weights.push(
+                reward_weights
+                    .iter()
+                    .find(|w| w.info.equal(&r.info))
+                    .unwrap()
+                    .clone(),
+            );
+            add_asset(user_rewards, r);
+        } else {
+            if is_blacklisted {
+                add_asset(blacklist_rewards, r.clone());
+            }
+            updated_reward_weights.push(
+                position_reward_weights
+                    .iter()
+                    .find(|w| w.info.equal(&r.info))
+                    .cloned()
+                    .unwrap_or(RewardWeight {
+                        info: r.info,
+                        reward_weight: Decimal256::zero(),
+                    }),
+            );
+        }
+    }
+    updated_reward_weights
+}
+
 /// Claim user rewards
 pub fn claim(
     deps: DepsMut,
@@ -507,23 +787,52 @@ pub fn block_users(
     Ok(Response::new().add_attribute("action", "update allowed users"))
 }
 
-/// it's only way to remove user from bl after instantiation
-pub fn remove_from_blacklist(
-    deps: DepsMut,
-    _env: Env,
+/// rewards accrued before blacklisting are sent to the user, rewards after go to blacklist rewards
+pub fn add_to_blacklist(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     user: String,
 ) -> Result<Response, ContractError> {
     OWNER.assert_admin(deps.as_ref(), &info.sender)?;
     deps.api.addr_validate(&user)?;
+    let mut blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        !blacklist.contains(&user),
+        ContractError::AlreadyBlacklisted(user)
+    );
 
-    BLACK_LIST.update(deps.storage, |mut x| -> StdResult<_> {
-        x.retain(|y| y != &user);
+    let (_, _, response) = _claim(deps.branch(), env, user.clone(), None)?;
+    blacklist.push(user.clone());
+    BLACK_LIST.save(deps.storage, &blacklist)?;
 
-        Ok(x)
-    })?;
+    Ok(response
+        .add_attribute("action", "add_to_blacklist")
+        .add_attribute("user", user))
+}
 
-    Ok(Response::new().add_attribute("action", "remove_from_blacklist"))
+/// rewards accrued while user was blacklisted go to blacklist rewards
+pub fn remove_from_blacklist(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    user: String,
+) -> Result<Response, ContractError> {
+    OWNER.assert_admin(deps.as_ref(), &info.sender)?;
+    deps.api.addr_validate(&user)?;
+    let mut blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        blacklist.contains(&user),
+        ContractError::NotBlacklisted(user)
+    );
+
+    let (_, _, response) = _claim(deps.branch(), env, user.clone(), None)?;
+    blacklist.retain(|x| x != &user);
+    BLACK_LIST.save(deps.storage, &blacklist)?;
+
+    Ok(response
+        .add_attribute("action", "remove_from_blacklist")
+        .add_attribute("user", user))
 }
 
 pub fn unbond(
@@ -536,7 +845,13 @@ pub fn unbond(
     let sender = &info.sender;
     let block_time = env.block.time.seconds();
     let config = CONFIG.load(deps.storage)?;
-    check_unbonding_period(period, ContractError::IncorrectUnbondingPeriod)?;
+    let unbonding_tier = get_unbonding_tier(
+        &UNBONDING_TIERS
+            .may_load(deps.storage)?
+            .unwrap_or_else(default_unbonding_tiers),
+        period,
+        ContractError::IncorrectUnbondingPeriod,
+    )?;
 
     let mut total_staking = TOTAL_STAKING.load(deps.storage)?;
     let (mut user_staking, _, response) = _claim(deps.branch(), env, sender.to_string(), None)?;
@@ -558,16 +873,14 @@ pub fn unbond(
 
     USER_UNBONDED.update(deps.storage, sender, |x| -> StdResult<_> {
         let mut unbonded = x.unwrap_or_default();
-        let fee = if period == UNBONDING_PERIOD_0 {
-            (str_to_dec(UNBONDING_FEE_RATE) * u128_to_dec(amount_to_unstake)).to_uint_floor()
-        } else {
-            Uint128::zero()
-        };
+        let fee = (unbonding_tier.fee_rate * u128_to_dec(amount_to_unstake)).to_uint_floor();
 
         unbonded.push(UnbondedItem {
             amount: amount_to_unstake - fee,
             fee,
             release_date: block_time + period,
+            duration: 0,
+            locked_at: 0,
         });
 
         Ok(unbonded)
@@ -589,11 +902,57 @@ pub fn unbond(
     Ok(response.add_attribute("action", "unbond").add_message(msg))
 }
 
+/// stake unbonded item back, unbonding fee is refunded
+pub fn cancel_unbond(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    index: u32,
+) -> Result<Response, ContractError> {
+    let sender = &info.sender;
+    let cfg = CONFIG.load(deps.storage)?;
+    let mut unbonded = USER_UNBONDED.load(deps.storage, sender).unwrap_or_default();
+    ensure!(
+        (index as usize) < unbonded.len(),
+        ContractError::UnbondedItemNotFound(index)
+    );
+    let item = unbonded.remove(index as usize);
+    if unbonded.is_empty() {
+        USER_UNBONDED.remove(deps.storage, sender);
+    } else {
+        USER_UNBONDED.save(deps.storage, sender, &unbonded)?;
+    }
+
+    let amount = item.amount + item.fee;
+    let mut total_staking = TOTAL_STAKING.load(deps.storage).unwrap_or_default();
+    let (mut user_staking, _, response) = _claim(deps.branch(), env, sender.to_string(), None)?;
+    total_staking += amount;
+    user_staking.staked += amount;
+    TOTAL_STAKING.save(deps.storage, &total_staking)?;
+    STAKING.save(deps.storage, &sender.to_string(), &user_staking)?;
+
+    // stake LP token to Astroport incentives contract again
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: cfg.astroport_incentives.to_string(),
+        msg: to_json_binary(&IncentivesExecuteMsg::Deposit { recipient: None })?,
+        funds: coins(amount.u128(), cfg.lp_token.to_string()),
+    });
+
+    Ok(response
+        .add_message(msg)
+        .add_attribute("action", "cancel unbond")
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("fee", item.fee.to_string()))
+}
+
 pub fn withdraw(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     recipient: Option<String>,
+    output: WithdrawOutput,
+    min_receive: Option<Uint128>,
+    min_assets_to_receive: Option<Vec<Asset>>,
 ) -> Result<Response, ContractError> {
     let sender = &info.sender;
     let recipient = recipient
@@ -602,6 +961,11 @@ pub fn withdraw(
         .unwrap_or(sender.to_owned());
     let block_time = env.block.time.seconds();
     let config = CONFIG.load(deps.storage)?;
+    // one minimum can't be checked against both pair assets
+    ensure!(
+        output != WithdrawOutput::PairAssets || min_receive.is_none(),
+        ContractError::MinReceiveNotSupported {}
+    );
     let unbonded = USER_UNBONDED.load(deps.storage, sender).unwrap_or_default();
     let (amount_to_send, fee_to_send) = unbonded.iter().fold(
         (Uint128::zero(), Uint128::zero()),
@@ -629,8 +993,39 @@ pub fn withdraw(
         USER_UNBONDED.save(deps.storage, sender, &items_to_preserve)?;
     }
 
+    // lp tokens don't need to be withdrawn from pair
+    if output == WithdrawOutput::LpToken {
+        let mut msgs = vec![config
+            .lp_token
+            .with_balance(amount_to_send)
+            .into_msg(recipient)?];
+        if !fee_to_send.is_zero() {
+            msgs.push(
+                config
+                    .lp_token
+                    .with_balance(fee_to_send)
+                    .into_msg(config.treasury)?,
+            );
+        }
+        return Ok(Response::new()
+            .add_messages(msgs)
+            .add_attribute("action", "withdraw")
+            .add_attribute("amount_to_send", amount_to_send)
+            .add_attribute("fee_to_send", fee_to_send));
+    }
+
     let fee_rate = u128_to_dec(fee_to_send) / u128_to_dec(amount_to_send + fee_to_send);
-    WITHDRAW_TEMP_DATA.save(deps.storage, &(recipient, Uint128::zero(), fee_rate))?;
+    WITHDRAW_TEMP_DATA.save(
+        deps.storage,
+        &WithdrawTempData {
+            recipient,
+            output,
+            min_receive,
+            fee_rate,
+            astro_amount: Uint128::zero(),
+            xastro_amount: Uint128::zero(),
+        },
+    )?;
 
     // withdraw liquidity from pair contract
     let submsg = SubMsg {
@@ -639,7 +1034,7 @@ pub fn withdraw(
             config.lp_contract,
             &astroport::pair::ExecuteMsg::WithdrawLiquidity {
                 assets: vec![],
-                min_assets_to_receive: None,
+                min_assets_to_receive,
             },
             coins(
                 (amount_to_send + fee_to_send).u128(),
@@ -664,21 +1059,20 @@ pub fn handle_withdraw_liquidity_reply(
         .into_result()
         .map_err(|e| ContractError::ParseReplyError(ParseReplyError::SubMsgFailure(e)))?;
 
-    // parse received lp token amount
-    let mut refund_assets = String::default();
-    for event in res.events.iter() {
-        for attr in event.attributes.iter() {
-            if attr.key == "refund_assets" {
-                refund_assets = attr.value.to_string();
-            }
-        }
-    }
-
     let Config {
         xastro,
         eclip_staking,
+        lp_contract,
+        treasury,
         ..
     } = &CONFIG.load(deps.storage)?;
+
+    // parse assets received from pair contract
+    let PairInfo { asset_infos, .. } = deps
+        .querier
+        .query_wasm_smart(lp_contract, &PairQueryMsg::Pair {})?;
+    let refund_assets = parse_refund_assets(&res.events, lp_contract, &asset_infos)?;
+
     let EclipStakingConfig { equinox_voter, .. } = deps
         .querier
         .query_wasm_smart(eclip_staking, &EclipStakingQueryMsg::QueryConfig {})?;
@@ -687,8 +1081,64 @@ pub fn handle_withdraw_liquidity_reply(
         .querier
         .query_wasm_smart(&equinox_voter, &VoterQueryMsg::TokenConfig {})?;
 
-    let eclip_astro_amount = get_token_amount(&refund_assets, eclip_astro);
-    let xastro_amount = get_token_amount(&refund_assets, xastro);
+    let eclip_astro_amount = get_refund_amount(&refund_assets, eclip_astro)?;
+    let xastro_amount = get_refund_amount(&refund_assets, xastro)?;
+    let mut withdraw_data = WITHDRAW_TEMP_DATA.load(deps.storage)?;
+
+    match withdraw_data.output {
+        WithdrawOutput::PairAssets => {
+            let mut fees = vec![];
+            for (denom, amount) in [(eclip_astro, eclip_astro_amount), (xastro, xastro_amount)] {
+                let fee = (withdraw_data.fee_rate * u128_to_dec(amount)).to_uint_floor();
+                let amount_to_send = amount - fee;
+                if !amount_to_send.is_zero() {
+                    response = response.add_message(BankMsg::Send {
+                        to_address: withdraw_data.recipient.to_string(),
+                        amount: coins(amount_to_send.u128(), denom),
+                    });
+                }
+                if !fee.is_zero() {
+                    fees.push(coin(fee.u128(), denom));
+                }
+            }
+            if !fees.is_empty() {
+                response = response.add_message(BankMsg::Send {
+                    to_address: treasury.to_string(),
+                    amount: fees,
+                });
+            }
+            return Ok(response
+                .add_attribute("action", "withdraw")
+                .add_attribute("eclipastro_amount", eclip_astro_amount)
+                .add_attribute("xastro_amount", xastro_amount));
+        }
+        WithdrawOutput::Xastro => {
+            // keep xAstro, swap only eclipAstro to astro
+            withdraw_data.xastro_amount = xastro_amount;
+            if eclip_astro_amount.is_zero() {
+                // nothing to swap, send xAstro directly
+                return send_xastro_output(
+                    deps.as_ref(),
+                    response,
+                    &withdraw_data,
+                    Uint128::zero(),
+                );
+            }
+            WITHDRAW_TEMP_DATA.save(deps.storage, &withdraw_data)?;
+            return Ok(response.add_submessage(SubMsg {
+                id: ECLIP_ASTRO_TO_ASTRO_REPLY_ID,
+                msg: wasm_execute(
+                    &equinox_voter,
+                    &VoterExecuteMsg::SwapToAstro { recipient: None },
+                    coins(eclip_astro_amount.u128(), eclip_astro),
+                )?
+                .into(),
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+            }));
+        }
+        _ => {}
+    }
 
     // swap eclipAstro to astro
     response = response.add_submessage(SubMsg {
@@ -739,29 +1189,43 @@ pub fn handle_swap_to_astro_reply(
         }
     }
 
-    let (recipient, mut amount, fee_rate) = WITHDRAW_TEMP_DATA.load(deps.storage)?;
+    let mut withdraw_data = WITHDRAW_TEMP_DATA.load(deps.storage)?;
+    if withdraw_data.output == WithdrawOutput::Xastro {
+        // stake astro and send it with xastro received from pair
+        return send_xastro_output(deps.as_ref(), response, &withdraw_data, astro_amount);
+    }
+
+    let WithdrawTempData {
+        recipient,
+        fee_rate,
+        min_receive,
+        ..
+    } = withdraw_data.clone();
+    let Config {
+        astro, treasury, ..
+    } = CONFIG.load(deps.storage)?;
 
     // 1st time only update amount info
-    if amount.is_zero() {
-        WITHDRAW_TEMP_DATA.save(deps.storage, &(recipient, astro_amount, fee_rate))?;
+    if withdraw_data.astro_amount.is_zero() {
+        withdraw_data.astro_amount = astro_amount;
+        WITHDRAW_TEMP_DATA.save(deps.storage, &withdraw_data)?;
 
         return Ok(response);
     }
 
     // 2nd time send tokens
-    amount += astro_amount;
+    let amount = withdraw_data.astro_amount + astro_amount;
     let fee_to_send = (fee_rate * u128_to_dec(amount)).to_uint_floor();
     let amount_to_send = amount - fee_to_send;
-
-    let Config {
-        astro, treasury, ..
-    } = CONFIG.load(deps.storage)?;
+    check_min_receive(amount_to_send, min_receive)?;
 
     // return astro to user
-    response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
-        to_address: recipient.to_string(),
-        amount: coins(amount_to_send.u128(), &astro),
-    }));
+    if !amount_to_send.is_zero() {
+        response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount_to_send.u128(), &astro),
+        }));
+    }
 
     // send fee to treasury
     if !fee_to_send.is_zero() {
@@ -777,6 +1241,72 @@ pub fn handle_swap_to_astro_reply(
         .add_attribute("fee_to_send", fee_to_send))
 }
 
+/// Send xAstro received from pair with astro staked to xAstro to recipient, fee goes to treasury
+fn send_xastro_output(
+    deps: Deps,
+    mut response: Response,
+    withdraw_data: &WithdrawTempData,
+    astro_amount: Uint128,
+) -> Result<Response, ContractError> {
+    let WithdrawTempData {
+        recipient,
+        fee_rate,
+        min_receive,
+        xastro_amount: pair_xastro_amount,
+        ..
+    } = withdraw_data;
+    let Config {
+        astro,
+        xastro,
+        astro_staking,
+        treasury,
+        ..
+    } = CONFIG.load(deps.storage)?;
+
+    let xastro_fee = (*fee_rate * u128_to_dec(*pair_xastro_amount)).to_uint_floor();
+    let astro_fee = (*fee_rate * u128_to_dec(astro_amount)).to_uint_floor();
+    let xastro_to_send = *pair_xastro_amount - xastro_fee;
+    let astro_to_stake = astro_amount - astro_fee;
+    let mut amount_to_send = xastro_to_send;
+    if !astro_to_stake.is_zero() {
+        let total_shares: Uint128 = deps
+            .querier
+            .query_wasm_smart(&astro_staking, &StakingQueryMsg::TotalShares {})?;
+        let total_deposit: Uint128 = deps
+            .querier
+            .query_wasm_smart(&astro_staking, &StakingQueryMsg::TotalDeposit {})?;
+        amount_to_send += astro_to_stake.multiply_ratio(total_shares, total_deposit);
+    }
+    check_min_receive(amount_to_send, *min_receive)?;
+
+    for (receiver, xastro_amount, astro_amount) in [
+        (recipient.to_string(), xastro_to_send, astro_to_stake),
+        (treasury.to_string(), xastro_fee, astro_fee),
+    ] {
+        if !xastro_amount.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: receiver.clone(),
+                amount: coins(xastro_amount.u128(), &xastro),
+            });
+        }
+        if !astro_amount.is_zero() {
+            response = response.add_message(wasm_execute(
+                &astro_staking,
+                &StakingExecuteMsg::Enter {
+                    receiver: Some(receiver),
+                },
+                coins(astro_amount.u128(), &astro),
+            )?);
+        }
+    }
+
+    Ok(response
+        .add_attribute("action", "withdraw")
+        .add_attribute("amount_to_send", amount_to_send)
+        .add_attribute("fee_to_send", xastro_fee)
+        .add_attribute("astro_fee_to_send", astro_fee))
+}
+
 /// Unstake amount and claim rewards of user
 /// check unstake amount
 pub fn unstake(
@@ -903,55 +1433,339 @@ pub fn distribute_eclipse_rewards(
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
     let reward_distribution = REWARD_DISTRIBUTION.load(deps.storage)?;
+    let mut protocol_fees = PROTOCOL_FEES.load(deps.storage).unwrap_or_default();
+    let mut pending_swap_rewards = PENDING_SWAP_REWARDS.load(deps.storage).unwrap_or_default();
     let mut msgs = vec![];
     for asset in assets {
-        if asset.info.to_string() == cfg.astro.clone() {
-            let funding_dao_rewards = asset.amount.multiply_ratio(
-                reward_distribution.funding_dao,
-                BPS_DENOMINATOR - reward_distribution.users,
-            );
-            let treasury_rewards = asset
-                .amount
-                .checked_sub(funding_dao_rewards)
-                .unwrap_or_default();
-            if funding_dao_rewards.gt(&Uint128::zero()) {
-                msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: cfg.astro_staking.to_string(),
-                    msg: to_json_binary(&StakingExecuteMsg::Enter {
-                        receiver: Some(cfg.funding_dao.to_string()),
-                    })?,
-                    funds: vec![coin(funding_dao_rewards.u128(), cfg.astro.clone())],
-                }));
+        if asset.amount.is_zero() {
+            continue;
+        }
+        let route = get_route(&cfg, &reward_distribution, &asset.info);
+        let funding_dao_rewards = asset.amount.multiply_ratio(
+            reward_distribution.funding_dao,
+            BPS_DENOMINATOR - reward_distribution.users,
+        );
+        let treasury_rewards = asset
+            .amount
+            .checked_sub(funding_dao_rewards)
+            .unwrap_or_default();
+        for (receiver, amount) in [
+            (&cfg.funding_dao, funding_dao_rewards),
+            (&cfg.treasury, treasury_rewards),
+        ] {
+            if amount.is_zero() {
+                continue;
             }
-            if treasury_rewards.gt(&Uint128::zero()) {
-                msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: cfg.astro_staking.to_string(),
-                    msg: to_json_binary(&StakingExecuteMsg::Enter {
-                        receiver: Some(cfg.treasury.clone().to_string()),
-                    })?,
-                    funds: vec![coin(treasury_rewards.u128(), cfg.astro.clone())],
-                }));
+            // swaps are executed by swap_rewards, so failed route doesn't revert user actions
+            if let RouteType::Swap { .. } = route {
+                add_asset(
+                    &mut pending_swap_rewards,
+                    PendingSwapReward {
+                        receiver: receiver.clone(),
+                        asset: asset.info.with_balance(amount),
+                    },
+                );
+            } else {
+                msgs.push(get_route_msg(
+                    &cfg,
+                    &route,
+                    asset.info.with_balance(amount),
+                    receiver,
+                    None,
+                )?);
             }
         }
+        add_asset(&mut protocol_fees, asset);
     }
+    PROTOCOL_FEES.save(deps.storage, &protocol_fees)?;
+    PENDING_SWAP_REWARDS.save(deps.storage, &pending_swap_rewards)?;
     Ok(Response::new().add_messages(msgs))
 }
 
-fn get_token_amount(input: &str, target_token: &str) -> Uint128 {
-    for token_part in input.split(',').map(|s| s.trim()) {
-        if token_part.contains(target_token) {
-            let numeric_prefix = token_part
-                .chars()
-                .take_while(|c| c.is_ascii_digit())
-                .collect::<String>();
-
-            if !numeric_prefix.is_empty() {
-                if let Ok(amount) = numeric_prefix.parse::<u128>() {
-                    return Uint128::new(amount);
-                }
+/// Swap pending treasury and funding dao rewards
+/// Minimum receive amount is simulated amount reduced by max spread of route
+/// Only rewards of given assets are swapped if set, the others stay pending
+/// Anyone can call
+pub fn swap_rewards(
+    deps: DepsMut,
+    assets: Option<Vec<AssetInfo>>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let reward_distribution = REWARD_DISTRIBUTION.load(deps.storage)?;
+    let (pending_swap_rewards, remaining): (Vec<_>, Vec<_>) = PENDING_SWAP_REWARDS
+        .load(deps.storage)
+        .unwrap_or_default()
+        .into_iter()
+        .partition(|r| {
+            assets
+                .as_ref()
+                .map_or(true, |a| a.iter().any(|a| a.equal(&r.asset.info)))
+        });
+    let mut msgs = vec![];
+    let mut response = Response::new().add_attribute("action", "swap_rewards");
+    for PendingSwapReward { receiver, asset } in pending_swap_rewards {
+        let route = get_route(&cfg, &reward_distribution, &asset.info);
+        let minimum_receive = match &route {
+            RouteType::Swap {
+                router,
+                operations,
+                max_spread,
+            } => {
+                let simulation: SimulateSwapOperationsResponse = deps.querier.query_wasm_smart(
+                    router,
+                    &RouterQueryMsg::SimulateSwapOperations {
+                        offer_amount: asset.amount,
+                        operations: operations.clone(),
+                    },
+                )?;
+                let max_spread = max_spread.unwrap_or(Decimal::permille(DEFAULT_MAX_SPREAD));
+                Some(simulation.amount * (Decimal::one() - max_spread))
             }
+            _ => None,
+        };
+        response = response
+            .add_attribute("receiver", receiver.to_string())
+            .add_attribute("asset", asset.to_string());
+        msgs.push(get_route_msg(
+            &cfg,
+            &route,
+            asset,
+            &receiver,
+            minimum_receive,
+        )?);
+    }
+    PENDING_SWAP_REWARDS.save(deps.storage, &remaining)?;
+    Ok(response.add_messages(msgs))
+}
+
+/// reward route of asset, ASTRO is staked and other assets are sent by default
+fn get_route(
+    cfg: &Config,
+    reward_distribution: &RewardDistribution,
+    info: &AssetInfo,
+) -> RouteType {
+    reward_distribution
+        .routes
+        .iter()
+        .find(|r| r.info.equal(info))
+        .map(|r| r.route.clone())
+        .unwrap_or(if info.to_string() == cfg.astro {
+            RouteType::StakeToXastro
+        } else {
+            RouteType::Send
+        })
+}
+
+fn get_route_msg(
+    cfg: &Config,
+    route: &RouteType,
+    asset: Asset,
+    receiver: &Addr,
+    minimum_receive: Option<Uint128>,
+) -> Result<CosmosMsg, ContractError> {
+    let msg = match route {
+        RouteType::Send => asset.into_msg(receiver.to_string())?,
+        RouteType::StakeToXastro => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: cfg.astro_staking.to_string(),
+            msg: to_json_binary(&StakingExecuteMsg::Enter {
+                receiver: Some(receiver.to_string()),
+            })?,
+            funds: vec![coin(asset.amount.u128(), cfg.astro.clone())],
+        }),
+        RouteType::Swap {
+            router,
+            operations,
+            max_spread,
+        } => match asset.info {
+            AssetInfo::NativeToken { denom } => CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: router.to_string(),
+                msg: to_json_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                    operations: operations.clone(),
+                    minimum_receive,
+                    to: Some(receiver.to_string()),
+                    max_spread: *max_spread,
+                })?,
+                funds: vec![coin(asset.amount.u128(), denom)],
+            }),
+            AssetInfo::Token { contract_addr } => CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                    contract: router.to_string(),
+                    amount: asset.amount,
+                    msg: to_json_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                        operations: operations.clone(),
+                        minimum_receive,
+                        to: Some(receiver.to_string()),
+                        max_spread: *max_spread,
+                    })?,
+                })?,
+                funds: vec![],
+            }),
+        },
+    };
+    Ok(msg)
+}
+
+fn check_min_receive(amount: Uint128, min_receive: Option<Uint128>) -> Result<(), ContractError> {
+    let min_receive = min_receive.unwrap_or_default();
+    ensure!(
+        amount >= min_receive,
+        ContractError::ReceivedAmountTooLow {
+            got: amount.u128(),
+            expected: min_receive.u128()
         }
+    );
+    Ok(())
+}
+
+/// decode refund_assets attribute of withdraw_liquidity event emitted by pair contract,
+/// it's formatted as "{amount}{asset_info}, {amount}{asset_info}" in order of pair assets
+fn parse_refund_assets(
+    events: &[Event],
+    lp_contract: &Addr,
+    asset_infos: &[AssetInfo],
+) -> Result<Vec<Asset>, ContractError> {
+    let refund_assets = events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .find(|e| {
+            e.attributes
+                .iter()
+                .any(|a| a.key == "_contract_address" && a.value == lp_contract.as_str())
+                && e.attributes
+                    .iter()
+                    .any(|a| a.key == "action" && a.value == "withdraw_liquidity")
+        })
+        .and_then(|e| e.attributes.iter().find(|a| a.key == "refund_assets"))
+        .ok_or(ContractError::WithdrawLiquidityEventNotFound)?;
+
+    refund_assets
+        .value
+        .split(',')
+        .map(str::trim)
+        .map(|asset| {
+            asset_infos
+                .iter()
+                .find_map(|info| {
+                    asset
+                        .strip_suffix(&info.to_string())
+                        .and_then(|amount| Uint128::from_str(amount).ok())
+                        .map(|amount| info.with_balance(amount))
+                })
+                .ok_or_else(|| ContractError::InvalidRefundAsset(asset.to_string()))
+        })
+        .collect()
+}
+
+fn get_refund_amount(refund_assets: &[Asset], denom: &str) -> Result<Uint128, ContractError> {
+    refund_assets
+        .iter()
+        .find(|a| a.info.to_string() == denom)
+        .map(|a| a.amount)
+        .ok_or(ContractError::RefundAssetNotFound(denom.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAIR: &str = "neutron1pair";
+    const ECLIPASTRO: &str =
+        "factory/neutron19q93n64nyet24ynvw04qjqmejffkmyxakdvl08sf3n3yeyr92lrs2makhx/eclipASTRO";
+    const XASTRO: &str =
+        "factory/neutron1zlf3hutsa4qnmue53lz2tfxrutp8y2e3rj4nkghg3rupgl4mqy8s5jgxsn/xASTRO";
+    const CW20: &str = "neutron1kdrqyrvy9v8h8r0j9afxaelnwtrdvhtwqhv6kk6yxchuq4qhu8zqrmwtxh";
+
+    fn withdraw_liquidity_event(contract: &str, refund_assets: &str) -> Event {
+        Event::new("wasm")
+            .add_attribute("_contract_address", contract)
+            .add_attribute("action", "withdraw_liquidity")
+            .add_attribute("sender", "neutron1lpstaking")
+            .add_attribute("withdrawn_share", "1000")
+            .add_attribute("refund_assets", refund_assets)
     }
 
-    Uint128::zero()
+    fn native_infos() -> Vec<AssetInfo> {
+        vec![AssetInfo::native(ECLIPASTRO), AssetInfo::native(XASTRO)]
+    }
+
+    #[test]
+    fn parse_native_refund_assets() {
+        let events = vec![
+            Event::new("execute").add_attribute("_contract_address", PAIR),
+            withdraw_liquidity_event(PAIR, &format!("1053{ECLIPASTRO}, 957{XASTRO}")),
+        ];
+        assert_eq!(
+            parse_refund_assets(&events, &Addr::unchecked(PAIR), &native_infos()).unwrap(),
+            vec![
+                AssetInfo::native(ECLIPASTRO).with_balance(1053u128),
+                AssetInfo::native(XASTRO).with_balance(957u128),
+            ]
+        );
+
+        // zero refund of pair asset
+        let events = vec![withdraw_liquidity_event(
+            PAIR,
+            &format!("0{ECLIPASTRO}, 957{XASTRO}"),
+        )];
+        assert_eq!(
+            parse_refund_assets(&events, &Addr::unchecked(PAIR), &native_infos()).unwrap(),
+            vec![
+                AssetInfo::native(ECLIPASTRO).with_balance(0u128),
+                AssetInfo::native(XASTRO).with_balance(957u128),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_cw20_refund_assets() {
+        let asset_infos = vec![
+            AssetInfo::cw20(Addr::unchecked(CW20)),
+            AssetInfo::native("untrn"),
+        ];
+        let events = vec![withdraw_liquidity_event(
+            PAIR,
+            &format!("250000{CW20}, 1000000untrn"),
+        )];
+        assert_eq!(
+            parse_refund_assets(&events, &Addr::unchecked(PAIR), &asset_infos).unwrap(),
+            vec![
+                AssetInfo::cw20(Addr::unchecked(CW20)).with_balance(250000u128),
+                AssetInfo::native("untrn").with_balance(1000000u128),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_refund_assets() {
+        // event of other contract is ignored
+        let events = vec![withdraw_liquidity_event(
+            "neutron1otherpair",
+            &format!("1053{ECLIPASTRO}, 957{XASTRO}"),
+        )];
+        assert_eq!(
+            parse_refund_assets(&events, &Addr::unchecked(PAIR), &native_infos()).unwrap_err(),
+            ContractError::WithdrawLiquidityEventNotFound
+        );
+
+        // asset isn't pair asset
+        let events = vec![withdraw_liquidity_event(
+            PAIR,
+            &format!("1053{ECLIPASTRO}, 957uastro"),
+        )];
+        assert_eq!(
+            parse_refund_assets(&events, &Addr::unchecked(PAIR), &native_infos()).unwrap_err(),
+            ContractError::InvalidRefundAsset("957uastro".to_string())
+        );
+
+        // amount is missing
+        let events = vec![withdraw_liquidity_event(
+            PAIR,
+            &format!("{ECLIPASTRO}, 957{XASTRO}"),
+        )];
+        assert_eq!(
+            parse_refund_assets(&events, &Addr::unchecked(PAIR), &native_infos()).unwrap_err(),
+            ContractError::InvalidRefundAsset(ECLIPASTRO.to_string())
+        );
+    }
 }
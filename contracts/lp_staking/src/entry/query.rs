@@ -11,14 +11,21 @@ use cosmwasm_std::{
 use cw_storage_plus::Bound;
 use equinox_msg::{
     lp_staking::{
-        Config, Reward, RewardAmount, RewardDistribution, RewardWeight, UserStaking, VaultRewards,
+        BoostCheckpoint, Config, LockedPosition, PendingSwapReward, Reward, RewardAmount,
+        RewardDistribution, RewardWeight, UserStaking, VaultRewardWeights,
     },
-    single_sided_staking::UnbondedItem,
+    single_sided_staking::{TimeLockConfig, UnbondedItem},
+    utils::{add_asset, default_unbonding_tiers, paginate_addresses, UnbondingTier},
 };
 
-use crate::state::{
-    BLACK_LIST, BLACK_LIST_REWARDS, CONFIG, LAST_CLAIMED, OWNER, REWARD, REWARD_DISTRIBUTION,
-    REWARD_WEIGHTS, STAKING, TOTAL_STAKING, USER_UNBONDED,
+use crate::{
+    config::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT, ONE_DAY},
+    state::{
+        BLACK_LIST, BLACK_LIST_REWARDS, BOOST_BY_END_TIME, BOOST_REWARD_WEIGHTS,
+        BOOST_REWARD_WEIGHTS_AT, CONFIG, LAST_CLAIMED, LOCKED_STAKING, OWNER, PENDING_SWAP_REWARDS,
+        PROTOCOL_FEES, REWARD, REWARD_DISTRIBUTION, REWARD_WEIGHTS, STAKING, TIMELOCK_CONFIG,
+        TOTAL_BOOST, TOTAL_STAKING, UNBONDING_TIERS, USER_UNBONDED,
+    },
 };
 
 /// query owner
@@ -39,12 +46,45 @@ pub fn query_reward_distribution(deps: Deps, _env: Env) -> StdResult<RewardDistr
     Ok(config)
 }
 
+/// query treasury and funding dao rewards accumulated from incentive assets
+pub fn query_protocol_fees(deps: Deps) -> StdResult<Vec<Asset>> {
+    Ok(PROTOCOL_FEES.load(deps.storage).unwrap_or_default())
+}
+
+/// query treasury and funding dao rewards waiting to be swapped
+pub fn query_pending_swap_rewards(deps: Deps) -> StdResult<Vec<PendingSwapReward>> {
+    Ok(PENDING_SWAP_REWARDS.load(deps.storage).unwrap_or_default())
+}
+
 /// query user staking
 pub fn query_staking(deps: Deps, _env: Env, user: String) -> StdResult<UserStaking> {
     let user_staking = STAKING.load(deps.storage, &user).unwrap_or_default();
     Ok(user_staking)
 }
 
+/// query locked user positions
+pub fn query_locked_staking(deps: Deps, _env: Env, user: String) -> StdResult<Vec<LockedPosition>> {
+    LOCKED_STAKING
+        .prefix(&user)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|p| {
+            let ((duration, locked_at), position) = p?;
+            Ok(LockedPosition {
+                duration,
+                locked_at,
+                end_time: get_lock_end_time(duration, locked_at),
+                amount: position.staked,
+                boost: position.boost,
+            })
+        })
+        .collect()
+}
+
+/// query lock durations and reward multipliers
+pub fn query_timelock_config(deps: Deps) -> StdResult<Vec<TimeLockConfig>> {
+    Ok(TIMELOCK_CONFIG.load(deps.storage).unwrap_or_default())
+}
+
 pub fn query_unbonded(deps: Deps, _env: Env, user: String) -> StdResult<Vec<UnbondedItem>> {
     let user = deps.api.addr_validate(&user)?;
     Ok(USER_UNBONDED.load(deps.storage, &user).unwrap_or_default())
@@ -59,24 +99,39 @@ pub fn query_total_staking(deps: Deps, _env: Env) -> StdResult<Uint128> {
 /// query user reward
 pub fn query_reward(deps: Deps, env: Env, user: String) -> StdResult<Vec<RewardAmount>> {
     let user_staking = STAKING.load(deps.storage, &user).unwrap_or_default();
+    let is_locked = LOCKED_STAKING
+        .prefix(&user)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
     let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
-    if user_staking.staked.is_zero() || blacklist.contains(&user) {
+    if (user_staking.staked.is_zero() && !is_locked) || blacklist.contains(&user) {
         Ok(vec![])
     } else {
+        let block_time = env.block.time.seconds();
         let astroport_rewards = calculate_incentive_pending_rewards(deps, env.contract.address)?;
-        let vault_rewards = calculate_vault_rewards(deps, env.block.time.seconds())?;
+        let vault_reward_weights = calculate_vault_reward_weights(deps, block_time)?;
         let updated_reward_weights =
-            calculate_updated_reward_weights(deps, astroport_rewards, vault_rewards)?;
-        let user_rewards = calculate_user_staking_rewards(deps, user, updated_reward_weights)?;
+            calculate_updated_reward_weights(deps, astroport_rewards, &vault_reward_weights)?;
+        let updated_boost_reward_weights =
+            calculate_updated_boost_reward_weights(deps, &vault_reward_weights)?;
+        let user_rewards = calculate_user_rewards(
+            deps,
+            &user,
+            block_time,
+            &updated_reward_weights,
+            &updated_boost_reward_weights,
+            &vault_reward_weights,
+        )?;
         Ok(user_rewards)
     }
 }
 
 pub fn query_reward_weights(deps: Deps, env: Env) -> StdResult<Vec<RewardWeight>> {
     let astroport_rewards = calculate_incentive_pending_rewards(deps, env.contract.address)?;
-    let vault_rewards = calculate_vault_rewards(deps, env.block.time.seconds())?;
+    let vault_reward_weights = calculate_vault_reward_weights(deps, env.block.time.seconds())?;
     let updated_reward_weights =
-        calculate_updated_reward_weights(deps, astroport_rewards, vault_rewards)?;
+        calculate_updated_reward_weights(deps, astroport_rewards, &vault_reward_weights)?;
     Ok(updated_reward_weights)
 }
 
@@ -89,8 +144,17 @@ pub fn query_user_reward_weights(
     Ok(user_staking.reward_weights)
 }
 
-pub fn query_blacklist(deps: Deps) -> StdResult<Vec<String>> {
-    Ok(BLACK_LIST.load(deps.storage).unwrap_or_default())
+pub fn query_blacklist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    Ok(paginate_addresses(
+        BLACK_LIST.load(deps.storage).unwrap_or_default(),
+        start_after,
+        limit,
+    ))
 }
 
 pub fn query_blacklist_rewards(deps: Deps, env: Env) -> StdResult<Vec<RewardAmount>> {
@@ -98,24 +162,22 @@ pub fn query_blacklist_rewards(deps: Deps, env: Env) -> StdResult<Vec<RewardAmou
     let block_time = env.block.time.seconds();
     let mut blacklist_rewards = BLACK_LIST_REWARDS.load(deps.storage).unwrap_or_default();
     let astroport_rewards = calculate_incentive_pending_rewards(deps, env.contract.address)?;
-    let vault_rewards = calculate_vault_rewards(deps, block_time)?;
+    let vault_reward_weights = calculate_vault_reward_weights(deps, block_time)?;
     let updated_reward_weights =
-        calculate_updated_reward_weights(deps, astroport_rewards, vault_rewards)?;
+        calculate_updated_reward_weights(deps, astroport_rewards, &vault_reward_weights)?;
+    let updated_boost_reward_weights =
+        calculate_updated_boost_reward_weights(deps, &vault_reward_weights)?;
     for user in blacklist.iter() {
-        let user_rewards =
-            calculate_user_staking_rewards(deps, user.to_string(), updated_reward_weights.clone())?;
+        let user_rewards = calculate_user_rewards(
+            deps,
+            user,
+            block_time,
+            &updated_reward_weights,
+            &updated_boost_reward_weights,
+            &vault_reward_weights,
+        )?;
         for user_reward in user_rewards {
-            let position = blacklist_rewards
-                .iter()
-                .position(|x| x.info.equal(&user_reward.info));
-            match position {
-                Some(p) => {
-                    blacklist_rewards[p].amount += user_reward.amount;
-                }
-                None => {
-                    blacklist_rewards.push(user_reward);
-                }
-            }
+            add_asset(&mut blacklist_rewards, user_reward);
         }
     }
     Ok(blacklist_rewards)
@@ -127,33 +189,123 @@ pub fn calculate_user_staking_rewards(
     reward_weights: Vec<RewardWeight>,
 ) -> StdResult<Vec<RewardAmount>> {
     let user_staking = STAKING.load(deps.storage, &user)?;
-    let mut user_rewards = vec![];
+    calculate_position_rewards(
+        user_staking.staked,
+        &user_staking.reward_weights,
+        &reward_weights,
+    )
+}
+
+/// rewards of staked amount from position reward weights to current reward weights
+pub fn calculate_position_rewards(
+    staked: Uint128,
+    position_reward_weights: &[RewardWeight],
+    reward_weights: &[RewardWeight],
+) -> StdResult<Vec<RewardAmount>> {
+    let mut rewards = vec![];
     for reward_weight in reward_weights {
-        let user_reward = user_staking
-            .reward_weights
-            .clone()
-            .into_iter()
-            .find(|r| reward_weight.info == r.info);
-        let user_reward_weight = match user_reward {
-            Some(r) => r.reward_weight,
-            None => Decimal256::zero(),
-        };
-        let user_reward_amount: Uint128 = reward_weight
+        let position_reward_weight = position_reward_weights
+            .iter()
+            .find(|r| reward_weight.info == r.info)
+            .map(|r| r.reward_weight)
+            .unwrap_or_default();
+        let amount: Uint128 = reward_weight
             .reward_weight
-            .checked_sub(user_reward_weight)
+            .checked_sub(position_reward_weight)
             .unwrap_or_default()
-            .checked_mul(Decimal256::from_ratio(user_staking.staked, 1u128))
+            .checked_mul(Decimal256::from_ratio(staked, 1u128))
             .unwrap()
             .to_uint_floor()
             .try_into()?;
-        user_rewards.push(RewardAmount {
-            info: reward_weight.info,
-            amount: user_reward_amount,
+        rewards.push(RewardAmount {
+            info: reward_weight.info.clone(),
+            amount,
         });
     }
+    Ok(rewards)
+}
+
+/// pending rewards of flexible staking and locked positions of user
+pub fn calculate_user_rewards(
+    deps: Deps,
+    user: &String,
+    block_time: u64,
+    reward_weights: &[RewardWeight],
+    boost_reward_weights: &[RewardWeight],
+    vault_reward_weights: &VaultRewardWeights,
+) -> StdResult<Vec<RewardAmount>> {
+    let user_staking = STAKING.load(deps.storage, user).unwrap_or_default();
+    let mut user_rewards = vec![];
+    if !user_staking.staked.is_zero() {
+        for r in calculate_position_rewards(
+            user_staking.staked,
+            &user_staking.reward_weights,
+            reward_weights,
+        )? {
+            add_asset(&mut user_rewards, r);
+        }
+    }
+    for p in LOCKED_STAKING
+        .prefix(user)
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let ((duration, locked_at), position) = p?;
+        let position_boost_reward_weights = calculate_position_boost_reward_weights(
+            deps,
+            block_time,
+            get_lock_end_time(duration, locked_at),
+            boost_reward_weights,
+            vault_reward_weights,
+        )?;
+        let rewards =
+            calculate_position_rewards(position.staked, &position.reward_weights, reward_weights)?
+                .into_iter()
+                .chain(calculate_position_rewards(
+                    position.boost,
+                    &position.boost_reward_weights,
+                    &position_boost_reward_weights,
+                )?);
+        for r in rewards {
+            add_asset(&mut user_rewards, r);
+        }
+    }
     Ok(user_rewards)
 }
 
+/// positions are unlocked at the start of the day after duration is passed
+pub fn get_lock_end_time(duration: u64, locked_at: u64) -> u64 {
+    (locked_at + duration) / ONE_DAY * ONE_DAY + ONE_DAY
+}
+
+/// boost reward weights which position is rewarded until,
+/// they are fixed at lock end time
+pub fn calculate_position_boost_reward_weights(
+    deps: Deps,
+    block_time: u64,
+    end_time: u64,
+    boost_reward_weights: &[RewardWeight],
+    vault_reward_weights: &VaultRewardWeights,
+) -> StdResult<Vec<RewardWeight>> {
+    if end_time > block_time {
+        return Ok(boost_reward_weights.to_vec());
+    }
+    if let Some(reward_weights) = BOOST_REWARD_WEIGHTS_AT.may_load(deps.storage, end_time)? {
+        return Ok(reward_weights);
+    }
+    let cfg = CONFIG.load(deps.storage)?;
+    let reward_weights = BOOST_REWARD_WEIGHTS.load(deps.storage).unwrap_or_default();
+    Ok(
+        match vault_reward_weights
+            .expired
+            .iter()
+            .find(|c| c.end_time == end_time)
+        {
+            Some(c) => add_vault_reward_weights(&cfg, reward_weights, c.eclip, c.beclip),
+            None => reward_weights,
+        },
+    )
+}
+
 pub fn calculate_incentive_pending_rewards(deps: Deps, contract: Addr) -> StdResult<Vec<Asset>> {
     let cfg = CONFIG.load(deps.storage)?;
     Ok(deps
@@ -168,14 +320,99 @@ pub fn calculate_incentive_pending_rewards(deps: Deps, contract: Addr) -> StdRes
         .unwrap_or_default())
 }
 
-pub fn calculate_vault_rewards(deps: Deps, current_time: u64) -> StdResult<VaultRewards> {
+/// ECLIP, bECLIP reward weights per boosted lp token from last claim time,
+/// total boosted staking is decreased at each lock end time
+pub fn calculate_vault_reward_weights(
+    deps: Deps,
+    current_time: u64,
+) -> StdResult<VaultRewardWeights> {
     let last_claimed = LAST_CLAIMED.load(deps.storage).unwrap_or(current_time);
+    let mut total_boosted = TOTAL_STAKING.load(deps.storage).unwrap_or_default()
+        + TOTAL_BOOST.load(deps.storage).unwrap_or_default();
+    let mut reward_weights = VaultRewardWeights {
+        eclip: Decimal256::zero(),
+        beclip: Decimal256::zero(),
+        expired: vec![],
+    };
+    let mut start_time = last_claimed;
+    let expired = BOOST_BY_END_TIME
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(current_time)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+    for (end_time, boost) in expired {
+        let segment_end_time = max(start_time, end_time);
+        let (eclip_reward, beclip_reward) =
+            calculate_eclip_beclip_reward(deps, start_time, segment_end_time)?;
+        if !total_boosted.is_zero() {
+            reward_weights.eclip += Decimal256::from_ratio(eclip_reward, total_boosted);
+            reward_weights.beclip += Decimal256::from_ratio(beclip_reward, total_boosted);
+        }
+        reward_weights.expired.push(BoostCheckpoint {
+            end_time,
+            eclip: reward_weights.eclip,
+            beclip: reward_weights.beclip,
+            boost,
+        });
+        total_boosted = total_boosted.checked_sub(boost).unwrap_or_default();
+        start_time = segment_end_time;
+    }
     let (eclip_reward, beclip_reward) =
-        calculate_eclip_beclip_reward(deps, last_claimed, current_time)?;
-    Ok(VaultRewards {
-        eclip: eclip_reward,
-        beclip: beclip_reward,
-    })
+        calculate_eclip_beclip_reward(deps, start_time, current_time)?;
+    if !total_boosted.is_zero() {
+        reward_weights.eclip += Decimal256::from_ratio(eclip_reward, total_boosted);
+        reward_weights.beclip += Decimal256::from_ratio(beclip_reward, total_boosted);
+    }
+    Ok(reward_weights)
+}
+
+/// add ECLIP, bECLIP reward weights to the list
+pub fn add_vault_reward_weights(
+    cfg: &Config,
+    mut reward_weights: Vec<RewardWeight>,
+    eclip: Decimal256,
+    beclip: Decimal256,
+) -> Vec<RewardWeight> {
+    for (info, reward_weight) in [
+        (
+            AssetInfo::NativeToken {
+                denom: cfg.eclip.clone(),
+            },
+            eclip,
+        ),
+        (
+            AssetInfo::Token {
+                contract_addr: cfg.beclip.clone(),
+            },
+            beclip,
+        ),
+    ] {
+        add_asset(
+            &mut reward_weights,
+            RewardWeight {
+                info,
+                reward_weight,
+            },
+        );
+    }
+    reward_weights
+}
+
+pub fn calculate_updated_boost_reward_weights(
+    deps: Deps,
+    vault_reward_weights: &VaultRewardWeights,
+) -> StdResult<Vec<RewardWeight>> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let reward_weights = BOOST_REWARD_WEIGHTS.load(deps.storage).unwrap_or_default();
+    Ok(add_vault_reward_weights(
+        &cfg,
+        reward_weights,
+        vault_reward_weights.eclip,
+        vault_reward_weights.beclip,
+    ))
 }
 
 pub fn calculate_pending_eclipse_rewards(
@@ -198,7 +435,7 @@ pub fn calculate_pending_eclipse_rewards(
 pub fn calculate_updated_reward_weights(
     deps: Deps,
     astroport_rewards: Vec<Asset>,
-    vault_rewards: VaultRewards,
+    vault_reward_weights: &VaultRewardWeights,
 ) -> StdResult<Vec<RewardWeight>> {
     let total_staking = TOTAL_STAKING.load(deps.storage).unwrap_or_default();
     let rwd_dist = REWARD_DISTRIBUTION.load(deps.storage)?;
@@ -209,8 +446,8 @@ pub fn calculate_updated_reward_weights(
     let mut reward_weights = REWARD_WEIGHTS.load(deps.storage).unwrap_or_default();
     let mut is_beclip = false;
     let mut is_eclip = false;
-    let beclip_user_reward_weight = Decimal256::from_ratio(vault_rewards.beclip, total_staking);
-    let eclip_user_reward_weight = Decimal256::from_ratio(vault_rewards.eclip, total_staking);
+    let beclip_user_reward_weight = vault_reward_weights.beclip;
+    let eclip_user_reward_weight = vault_reward_weights.eclip;
     for reward in astroport_rewards {
         let mut is_exist = false;
         let user_reward = reward.amount.multiply_ratio(rwd_dist.users, 10_000u32);
@@ -310,6 +547,13 @@ pub fn query_native_token_supply(querier: &QuerierWrapper, denom: String) -> Std
     Ok(supply.amount)
 }
 
+/// query unbonding periods and fee rates
+pub fn query_unbonding_tiers(deps: Deps) -> StdResult<Vec<UnbondingTier>> {
+    Ok(UNBONDING_TIERS
+        .may_load(deps.storage)?
+        .unwrap_or_else(default_unbonding_tiers))
+}
+
 pub fn query_reward_schedule(
     deps: Deps,
     env: Env,
@@ -1,5 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_utils::{ParseReplyError, PaymentError};
+use equinox_msg::ownership::OwnershipError;
 use thiserror::Error;
 
 use cw_controllers::AdminError;
@@ -15,9 +16,15 @@ pub enum ContractError {
     #[error("Sender's asset denom {got} does not match one from config {expected}")]
     AssetsNotMatch { got: String, expected: String },
 
+    #[error("User is already in blacklist: {0}")]
+    AlreadyBlacklisted(String),
+
     #[error("User is in blacklist")]
     Blacklisted {},
 
+    #[error("User is not in blacklist: {0}")]
+    NotBlacklisted(String),
+
     #[error("Contract name must be same: {0}")]
     ContractNameErr(String),
 
@@ -35,9 +42,6 @@ pub enum ContractError {
     #[error("Amount {got} exceeds your staking {expected}")]
     ExeedingUnstakeAmount { got: u128, expected: u128 },
 
-    #[error("Parameter expires_in cannot be higher than {0}")]
-    ExpiresInErr(u64),
-
     #[error("Invalid asset")]
     InvalidAsset {},
 
@@ -56,8 +60,23 @@ pub enum ContractError {
     #[error("Staking amount is zero")]
     InvalidStakingAmount {},
 
-    #[error("Ownership proposal expired")]
-    OwnershipProposalExpired {},
+    #[error("No locking period found: {0}")]
+    NoLockingPeriodFound(u64),
+
+    #[error("No locked amount found")]
+    NoLockedAmount {},
+
+    #[error("Can't extend from duration {0} to duration {1}")]
+    ExtendDurationErr(u64, u64),
+
+    #[error("Lock isn't ended until {0}")]
+    LockNotEnded(u64),
+
+    #[error("Lock durations must be unique and rewards multipliers can't be less than 10000")]
+    InvalidTimeLockConfig {},
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
 
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
@@ -68,8 +87,8 @@ pub enum ContractError {
     #[error("Total Reward point must be 10000")]
     RewardDistributionErr {},
 
-    #[error("New owner cannot be same")]
-    SameOwner {},
+    #[error("Invalid reward route: {0}")]
+    InvalidRewardRoute(String),
 
     #[error("Semver parsing error: {0}")]
     SemVer(String),
@@ -80,6 +99,9 @@ pub enum ContractError {
     #[error("New version must be greater than previous one: {0}")]
     VersionErr(String),
 
+    #[error("Unauthorized")]
+    Unauthorized {},
+
     #[error("Token amount must not be zero")]
     ZeroAmount {},
 
@@ -89,6 +111,24 @@ pub enum ContractError {
     #[error("Early withdraw isn't allowed")]
     EarlyWithdraw,
 
+    #[error("Unbonded item not found: {0}")]
+    UnbondedItemNotFound(u32),
+
+    #[error("Withdraw liquidity event isn't found")]
+    WithdrawLiquidityEventNotFound,
+
+    #[error("Invalid refund asset: {0}")]
+    InvalidRefundAsset(String),
+
+    #[error("Refund asset isn't found: {0}")]
+    RefundAssetNotFound(String),
+
+    #[error("Received amount {got} is less than minimum {expected}")]
+    ReceivedAmountTooLow { got: u128, expected: u128 },
+
+    #[error("min_receive isn't supported for pair assets, use min_assets_to_receive")]
+    MinReceiveNotSupported {},
+
     #[error("Swap to ASTRO error")]
     SwapToAstroError,
 
@@ -1,12 +1,14 @@
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use astroport::asset::Asset;
+use cosmwasm_std::{Addr, Uint128};
 use cw_controllers::Admin;
 use cw_storage_plus::{Item, Map};
 use equinox_msg::{
     lp_staking::{
-        Config, OwnershipProposal, Reward, RewardAmount, RewardDistribution, RewardWeight,
-        UserStaking,
+        Config, PendingSwapReward, Reward, RewardAmount, RewardDistribution, RewardWeight,
+        UserLockedStaking, UserStaking, WithdrawTempData,
     },
-    single_sided_staking::UnbondedItem,
+    single_sided_staking::{TimeLockConfig, UnbondedItem},
+    utils::UnbondingTier,
 };
 
 /// Contract name that is used for migration.
@@ -16,22 +18,38 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const WITHDRAW_LIQUIDITY_REPLY_ID: u64 = 0;
 pub const ECLIP_ASTRO_TO_ASTRO_REPLY_ID: u64 = 1;
 pub const XASTRO_TO_ASTRO_REPLY_ID: u64 = 2;
-/// (recipient, amount_to_send, fee_rate)
-pub const WITHDRAW_TEMP_DATA: Item<(Addr, Uint128, Decimal)> = Item::new("withdraw_temp_data");
+pub const WITHDRAW_TEMP_DATA: Item<WithdrawTempData> = Item::new("withdraw_temp_data");
 
 pub const OWNER: Admin = Admin::new("owner");
 pub const CONFIG: Item<Config> = Item::new("config");
 /// unbonded items by user_address
 pub const USER_UNBONDED: Map<&Addr, Vec<UnbondedItem>> = Map::new("user_unbonded");
+/// unbonding periods and fees, default tiers are used if it's not set
+pub const UNBONDING_TIERS: Item<Vec<UnbondingTier>> = Item::new("unbonding_tiers");
 pub const STAKING: Map<&String, UserStaking> = Map::new("staking");
 pub const TOTAL_STAKING: Item<Uint128> = Item::new("total_staking");
 pub const REWARD_WEIGHTS: Item<Vec<RewardWeight>> = Item::new("reward_weights");
+/// lock durations and reward multipliers, only flexible staking is allowed if it's not set
+pub const TIMELOCK_CONFIG: Item<Vec<TimeLockConfig>> = Item::new("timelock_config");
+/// locked positions (address, duration, locked_at)
+pub const LOCKED_STAKING: Map<(&String, u64, u64), UserLockedStaking> = Map::new("locked_staking");
+/// sum of boosts of positions which lock isn't ended
+pub const TOTAL_BOOST: Item<Uint128> = Item::new("total_boost");
+/// sum of boosts by lock end time, removed when end time is reached
+pub const BOOST_BY_END_TIME: Map<u64, Uint128> = Map::new("boost_by_end_time");
+/// ECLIP, bECLIP reward weights of boosts
+pub const BOOST_REWARD_WEIGHTS: Item<Vec<RewardWeight>> = Item::new("boost_reward_weights");
+/// boost reward weights at lock end time
+pub const BOOST_REWARD_WEIGHTS_AT: Map<u64, Vec<RewardWeight>> =
+    Map::new("boost_reward_weights_at");
 pub const REWARD_DISTRIBUTION: Item<RewardDistribution> = Item::new("reward_distribution");
+/// treasury and funding dao rewards distributed from incentive assets
+pub const PROTOCOL_FEES: Item<Vec<Asset>> = Item::new("protocol_fees");
+/// treasury and funding dao rewards on swap routes, swapped separately so failed swap can't block users
+pub const PENDING_SWAP_REWARDS: Item<Vec<PendingSwapReward>> = Item::new("pending_swap_rewards");
 pub const REWARD: Map<(u64, u64), Reward> = Map::new("reward");
 
 pub const LAST_CLAIMED: Item<u64> = Item::new("last_claimed");
-/// Stores the latest contract ownership transfer proposal
-pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
 /// List of users who can't claim rewards
 pub const BLACK_LIST: Item<Vec<String>> = Item::new("blacklist");
 pub const BLACK_LIST_REWARDS: Item<Vec<RewardAmount>> = Item::new("blacklist_rewards");
@@ -5,9 +5,12 @@ pub const ECLIPASTRO_REWARD_DISTRIBUTION_PERIOD: u64 = 8 * ONE_DAY; // 8 days
 pub const DEFAULT_REWARD_DISTRIBUTION_PERIOD: u64 = 30 * ONE_DAY;
 pub const REWARD_DISTRIBUTION_TIME_DIFF: u64 = 6 * 3_600; // 6 hours
 pub const BPS_DENOMINATOR: u64 = 10000;
-pub const MAX_PROPOSAL_TTL: u64 = 1209600;
 pub const DEFAULT_INIT_EARLY_UNLOCK_PENALTY: &str = "0.7";
 pub const DEFAULT_LAST_EARLY_UNLOCK_PENALTY: &str = "0.5";
+pub const DEFAULT_PAGE_LIMIT: u32 = 30;
+pub const MAX_PAGE_LIMIT: u32 = 100;
+/// max staking entries read by a filtered page query
+pub const MAX_SCAN_LIMIT: usize = 1_000;
 
 pub const DEFAULT_TIMELOCK_CONFIG: &[TimeLockConfig] = &[
     TimeLockConfig {
@@ -1,3 +1,4 @@
+use astroport::asset::{Asset, AssetInfo};
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
     StdResult,
@@ -6,26 +7,32 @@ use cosmwasm_std::{
 use crate::{
     entry::{
         execute::{
-            _handle_callback, add_rewards, allow_users, block_users, claim, claim_all,
-            claim_blacklist_rewards, claim_ownership, drop_ownership_proposal,
-            handle_swap_to_astro_reply, propose_new_owner, restake, stake, unbond, unstake,
-            update_config, withdraw,
+            _handle_callback, add_asset_rewards, add_rewards, add_to_blacklist,
+            aggregate_positions, allow_users, backfill_snapshots, block_users, cancel_unbond,
+            checkpoint, claim, claim_all, claim_blacklist_rewards, compound, early_unlock,
+            handle_swap_to_astro_reply, receive_cw20, remove_from_blacklist, restake,
+            set_auto_compound, stake, transfer_position, unbond, unstake, update_config, withdraw,
         },
         instantiate::try_instantiate,
         migrate::migrate_contract,
         query::{
-            calculate_penalty, query_blacklist, query_blacklist_rewards,
-            query_calculate_penalty_amount, query_calculate_reward, query_config,
-            query_eclipastro_rewards, query_owner, query_reward, query_reward_list,
-            query_reward_schedule, query_staking, query_total_staking,
-            query_total_staking_by_duration, query_unbonded,
+            calculate_penalty, query_all_positions, query_all_stakers, query_blacklist,
+            query_blacklist_rewards, query_calculate_penalty_amount, query_calculate_reward,
+            query_config, query_eclipastro_rewards, query_owner, query_pending_days, query_reward,
+            query_reward_list, query_reward_schedule, query_staking, query_staking_at,
+            query_total_boosted_stake_at, query_total_staking, query_total_staking_by_duration,
+            query_unbonded, query_unbonding_tiers,
         },
     },
     error::ContractError,
-    state::{ALLOWED_USERS, SWAP_TO_ASTRO_REPLY_ID},
+    state::{ALLOWED_USERS, AUTO_COMPOUND, OWNER, SWAP_TO_ASTRO_REPLY_ID},
 };
-use equinox_msg::single_sided_staking::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, RestakeData,
+use cw_utils::one_coin;
+use equinox_msg::{
+    ownership::{
+        claim_ownership, drop_ownership_proposal, propose_new_owner, query_ownership_proposal,
+    },
+    single_sided_staking::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, RestakeData},
 };
 
 // Note, you can use StdResult in some functions where you do not
@@ -50,11 +57,11 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::UpdateConfig { config } => update_config(deps, env, info, config),
-        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
-            propose_new_owner(deps, env, info, owner, expires_in)
-        }
-        ExecuteMsg::DropOwnershipProposal {} => drop_ownership_proposal(deps, info),
-        ExecuteMsg::ClaimOwnership {} => claim_ownership(deps, env, info),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => Ok(propose_new_owner(
+            deps, env, info, &OWNER, owner, expires_in,
+        )?),
+        ExecuteMsg::DropOwnershipProposal {} => Ok(drop_ownership_proposal(deps, info, &OWNER)?),
+        ExecuteMsg::ClaimOwnership {} => Ok(claim_ownership(deps, env, info, &OWNER)?),
         ExecuteMsg::Claim {
             duration,
             locked_at,
@@ -81,7 +88,39 @@ pub fn execute(
             period,
         } => unbond(deps, env, info, duration, locked_at, period),
 
-        ExecuteMsg::Withdraw { recipient } => withdraw(deps, env, info, recipient),
+        ExecuteMsg::CancelUnbond { index } => cancel_unbond(deps, env, info, index),
+
+        ExecuteMsg::Withdraw {
+            recipient,
+            min_astro_out,
+        } => withdraw(deps, env, info, recipient, min_astro_out),
+
+        ExecuteMsg::EarlyUnlock {
+            duration,
+            locked_at,
+            amount,
+        } => early_unlock(deps, env, info, duration, locked_at, amount),
+
+        ExecuteMsg::TransferPosition {
+            duration,
+            locked_at,
+            amount,
+            recipient,
+        } => transfer_position(deps, env, info, duration, locked_at, amount, recipient),
+
+        ExecuteMsg::AggregatePositions {
+            duration,
+            locked_at_list,
+            target_duration,
+        } => aggregate_positions(deps, env, info, duration, locked_at_list, target_duration),
+
+        ExecuteMsg::Checkpoint { max_days } => checkpoint(deps, env, max_days),
+
+        ExecuteMsg::SetAutoCompound { duration, enabled } => {
+            set_auto_compound(deps, info, duration, enabled)
+        }
+
+        ExecuteMsg::Compound { users } => compound(deps, env, users),
 
         ExecuteMsg::Restake {
             from_duration,
@@ -116,7 +155,21 @@ pub fn execute(
             eclip,
             beclip,
         } => add_rewards(deps, env, info, from, duration, eclip, beclip),
+        ExecuteMsg::AddAssetRewards { from, duration } => {
+            let coin = one_coin(&info)?;
+            let asset = Asset {
+                info: AssetInfo::NativeToken { denom: coin.denom },
+                amount: coin.amount,
+            };
+            add_asset_rewards(deps, env, info.sender, from, duration, asset)
+        }
+        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
         ExecuteMsg::ClaimBlacklistRewards {} => claim_blacklist_rewards(deps, env),
+        ExecuteMsg::AddToBlacklist { user } => add_to_blacklist(deps, env, info, user),
+        ExecuteMsg::RemoveFromBlacklist { user } => remove_from_blacklist(deps, env, info, user),
+        ExecuteMsg::BackfillSnapshots { start_after, limit } => {
+            backfill_snapshots(deps, env, info, start_after, limit)
+        }
     }
 }
 
@@ -126,9 +179,24 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => Ok(to_json_binary(&query_config(deps, env)?)?),
         QueryMsg::Owner {} => Ok(to_json_binary(&query_owner(deps, env)?)?),
-        QueryMsg::Staking { user } => Ok(to_json_binary(&query_staking(deps, env, user)?)?),
+        QueryMsg::OwnershipProposal {} => Ok(to_json_binary(&query_ownership_proposal(deps)?)?),
+        QueryMsg::Staking {
+            user,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&query_staking(
+            deps,
+            env,
+            user,
+            start_after,
+            limit,
+        )?)?),
 
-        QueryMsg::Unbonded { user } => to_json_binary(&query_unbonded(deps, env, user)?),
+        QueryMsg::Unbonded {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&query_unbonded(deps, env, user, start_after, limit)?),
 
         QueryMsg::TotalStaking {} => Ok(to_json_binary(&query_total_staking(deps, env)?)?),
         QueryMsg::TotalStakingByDuration { timestamp } => Ok(to_json_binary(
@@ -161,15 +229,41 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             let is_allowed = ALLOWED_USERS.load(deps.storage, &user).unwrap_or_default();
             Ok(to_json_binary(&is_allowed)?)
         }
+        QueryMsg::AutoCompound { user, duration } => {
+            let auto_compound = AUTO_COMPOUND
+                .load(deps.storage, (&user, duration))
+                .unwrap_or_default();
+            Ok(to_json_binary(&auto_compound)?)
+        }
         QueryMsg::EclipastroRewards {} => {
             Ok(to_json_binary(&query_eclipastro_rewards(deps, env)?)?)
         }
-        QueryMsg::Blacklist {} => Ok(to_json_binary(&query_blacklist(deps)?)?),
-        QueryMsg::BlacklistRewards => Ok(to_json_binary(&query_blacklist_rewards(deps, env)?)?),
-        QueryMsg::RewardSchedule { from } => {
-            Ok(to_json_binary(&query_reward_schedule(deps, env, from)?)?)
+        QueryMsg::Blacklist { start_after, limit } => {
+            Ok(to_json_binary(&query_blacklist(deps, start_after, limit)?)?)
         }
-        QueryMsg::RewardList { user } => Ok(to_json_binary(&query_reward_list(deps, env, user)?)?),
+        QueryMsg::BlacklistRewards => Ok(to_json_binary(&query_blacklist_rewards(deps, env)?)?),
+        QueryMsg::RewardSchedule {
+            from,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&query_reward_schedule(
+            deps,
+            env,
+            from,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::RewardList {
+            user,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&query_reward_list(
+            deps,
+            env,
+            user,
+            start_after,
+            limit,
+        )?)?),
         QueryMsg::CalculatePenaltyAmount {
             amount,
             duration,
@@ -177,6 +271,29 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         } => Ok(to_json_binary(&query_calculate_penalty_amount(
             deps, env, amount, duration, locked_at,
         )?)?),
+        QueryMsg::StakingAt { user, timestamp } => Ok(to_json_binary(&query_staking_at(
+            deps, env, user, timestamp,
+        )?)?),
+        QueryMsg::TotalBoostedStakeAt { timestamp } => Ok(to_json_binary(
+            &query_total_boosted_stake_at(deps, env, timestamp)?,
+        )?),
+        QueryMsg::AllStakers { start_after, limit } => Ok(to_json_binary(&query_all_stakers(
+            deps,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::UnbondingTiers {} => Ok(to_json_binary(&query_unbonding_tiers(deps)?)?),
+        QueryMsg::PendingDays {} => Ok(to_json_binary(&query_pending_days(deps, env)?)?),
+        QueryMsg::AllPositions {
+            duration,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&query_all_positions(
+            deps,
+            duration,
+            start_after,
+            limit,
+        )?)?),
     }
 }
 
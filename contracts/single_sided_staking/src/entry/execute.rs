@@ -1,15 +1,16 @@
 use std::{cmp::min, str::FromStr};
 
-use astroport::asset::AssetInfo;
+use astroport::asset::{Asset, AssetInfo};
 use cosmwasm_std::{
-    attr, coins, ensure, ensure_eq, to_json_binary, wasm_execute, BankMsg, Coin, CosmosMsg,
-    Decimal256, DepsMut, Env, MessageInfo, Order, ReplyOn, Response, StdResult, Storage, SubMsg,
-    SubMsgResult, Uint128, WasmMsg,
+    coins, ensure, ensure_eq, from_json, to_json_binary, wasm_execute, Addr, BankMsg, Coin,
+    CosmosMsg, Decimal256, DepsMut, Env, MessageInfo, Order, ReplyOn, Response, StdResult, Storage,
+    SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
+use cw20::Cw20ReceiveMsg;
 use cw_storage_plus::Bound;
 use cw_utils::one_coin;
 use eclipse_base::{
-    converters::{str_to_dec, u128_to_dec},
+    converters::u128_to_dec,
     staking::msg::ExecuteMsg as EclipStakingExecuteMsg,
     voter::{
         msg::{ExecuteMsg as VoterExecuteMsg, QueryMsg as VoterQueryMsg},
@@ -18,29 +19,32 @@ use eclipse_base::{
 };
 
 use crate::{
-    config::{DEFAULT_REWARD_DISTRIBUTION_PERIOD, MAX_PROPOSAL_TTL, ONE_DAY},
-    entry::query::{calculate_total_user_reward, check_lock_ended},
+    config::{DEFAULT_PAGE_LIMIT, DEFAULT_REWARD_DISTRIBUTION_PERIOD, MAX_PAGE_LIMIT, ONE_DAY},
+    entry::query::{calculate_penalty, calculate_total_user_reward, check_lock_ended},
     error::ContractError,
     state::{
-        RewardWeights, TotalStakingByDuration, UserStaked, ALLOWED_USERS, BLACK_LIST,
-        BLACK_LIST_REWARDS, CONFIG, LAST_CLAIM_TIME, OWNER, OWNERSHIP_PROPOSAL,
-        PENDING_ECLIPASTRO_REWARDS, REWARD, REWARD_WEIGHTS, STAKING_DURATION_BY_END_TIME,
-        SWAP_TO_ASTRO_REPLY_ID, TOTAL_STAKING, TOTAL_STAKING_BY_DURATION, USER_STAKED,
-        USER_UNBONDED, WITHDRAW_TEMP_DATA,
+        backfill_user_total_staked, sync_user_total_staked, RewardWeights, TotalStakingByDuration,
+        UserStaked, ALLOWED_USERS, AUTO_COMPOUND, BLACK_LIST, BLACK_LIST_REWARDS, CONFIG,
+        LAST_CLAIM_TIME, OWNER, PENDING_ECLIPASTRO_REWARDS, REWARD, REWARD_WEIGHTS,
+        STAKING_DURATION_BY_END_TIME, SWAP_TO_ASTRO_REPLY_ID, TOTAL_STAKING,
+        TOTAL_STAKING_BY_DURATION, UNBONDING_TIERS, USER_STAKED, USER_UNBONDED, WITHDRAW_TEMP_DATA,
     },
 };
 
 use equinox_msg::{
     single_sided_staking::{
-        CallbackMsg, OwnershipProposal, RestakeData, Reward, UnbondedItem, UpdateConfigMsg,
-        UserReward,
+        CallbackMsg, Cw20HookMsg, RestakeData, Reward, RewardAmount, RewardWeight, UnbondedItem,
+        UpdateConfigMsg, UserReward,
+    },
+    utils::{
+        add_asset, check_unbonding_tiers, default_unbonding_tiers, get_unbonding_tier,
+        has_unique_elements,
     },
-    utils::{check_unbonding_period, has_unique_elements, UNBONDING_FEE_RATE, UNBONDING_PERIOD_0},
 };
 
 use super::query::{
-    calculate_eclip_beclip_reward, calculate_eclipastro_reward, calculate_user_reward,
-    query_eclipastro_pending_rewards,
+    calculate_asset_rewards, calculate_eclip_beclip_reward, calculate_eclipastro_reward,
+    calculate_user_reward, query_eclipastro_pending_rewards,
 };
 
 /// Update config
@@ -93,6 +97,11 @@ pub fn update_config(
             "update init_early_unlock_penalty",
         )
     }
+    if let Some(unbonding_tiers) = new_config.unbonding_tiers {
+        check_unbonding_tiers(&unbonding_tiers, ContractError::IncorrectUnbondingPeriod)?;
+        UNBONDING_TIERS.save(deps.storage, &unbonding_tiers)?;
+        res = res.add_attribute("unbonding_tiers", "update unbonding tiers")
+    }
     CONFIG.save(deps.storage, &config)?;
     Ok(res)
 }
@@ -131,76 +140,70 @@ pub fn block_users(
     Ok(Response::new().add_attribute("action", "update allowed users"))
 }
 
-pub fn propose_new_owner(
-    deps: DepsMut,
+/// rewards accrued before blacklisting are sent to the user, rewards after go to blacklist rewards
+pub fn add_to_blacklist(
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    new_owner: String,
-    expires_in: u64,
+    user: String,
 ) -> Result<Response, ContractError> {
-    // only owner can propose new owner
     OWNER.assert_admin(deps.as_ref(), &info.sender)?;
-    let new_owner_addr = deps.api.addr_validate(&new_owner)?;
-
-    // Check that the new owner is not the same as the current one
-    ensure_eq!(
-        OWNER.is_admin(deps.as_ref(), &new_owner_addr).unwrap(),
-        false,
-        ContractError::SameOwner {}
+    deps.api.addr_validate(&user)?;
+    let mut blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        !blacklist.contains(&user),
+        ContractError::AlreadyBlacklisted(user)
     );
 
-    if MAX_PROPOSAL_TTL < expires_in {
-        return Err(ContractError::ExpiresInErr(MAX_PROPOSAL_TTL));
-    }
-
-    let new_proposal = OwnershipProposal {
-        owner: new_owner_addr,
-        ttl: env.block.time.seconds() + expires_in,
-    };
+    let response = _claim_all(deps.branch(), env, user.clone(), true, None)?;
+    blacklist.push(user.clone());
+    BLACK_LIST.save(deps.storage, &blacklist)?;
 
-    OWNERSHIP_PROPOSAL.save(deps.storage, &new_proposal)?;
-
-    Ok(Response::new().add_attributes(vec![
-        attr("action", "propose_new_owner"),
-        attr("new_owner", new_owner),
-    ]))
+    Ok(response
+        .add_attribute("action", "add_to_blacklist")
+        .add_attribute("user", user))
 }
 
-pub fn drop_ownership_proposal(
-    deps: DepsMut,
+/// rewards accrued while user was blacklisted go to blacklist rewards
+pub fn remove_from_blacklist(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
+    user: String,
 ) -> Result<Response, ContractError> {
-    // only owner can drop ownership proposal
     OWNER.assert_admin(deps.as_ref(), &info.sender)?;
+    deps.api.addr_validate(&user)?;
+    let mut blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        blacklist.contains(&user),
+        ContractError::NotBlacklisted(user)
+    );
 
-    OWNERSHIP_PROPOSAL.remove(deps.storage);
+    let response = _claim_all(deps.branch(), env, user.clone(), true, None)?;
+    blacklist.retain(|x| x != &user);
+    BLACK_LIST.save(deps.storage, &blacklist)?;
 
-    Ok(Response::new().add_attributes(vec![attr("action", "drop_ownership_proposal")]))
+    Ok(response
+        .add_attribute("action", "remove_from_blacklist")
+        .add_attribute("user", user))
 }
 
-pub fn claim_ownership(
-    mut deps: DepsMut,
+/// save staking snapshots of users staked before snapshots were added, only owner
+/// call with last_user of the previous page until it is empty
+pub fn backfill_snapshots(
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    start_after: Option<String>,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
-    // only owner can drop ownership proposal
     OWNER.assert_admin(deps.as_ref(), &info.sender)?;
-
-    let proposal = OWNERSHIP_PROPOSAL.load(deps.storage)?;
-
-    ensure!(
-        env.block.time.seconds() > proposal.ttl,
-        ContractError::OwnershipProposalExpired {}
-    );
-
-    OWNER.set(deps.branch(), Some(proposal.owner.clone()))?;
-
-    OWNERSHIP_PROPOSAL.remove(deps.storage);
-
-    Ok(Response::new().add_attributes(vec![
-        attr("action", "claim_ownership"),
-        attr("new_owner", proposal.owner),
-    ]))
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let last_user =
+        backfill_user_total_staked(deps.storage, env.block.time.seconds(), start_after, limit)?;
+    Ok(Response::new()
+        .add_attribute("action", "backfill snapshots")
+        .add_attribute("last_user", last_user.unwrap_or_default()))
 }
 
 pub fn _handle_callback(
@@ -290,7 +293,6 @@ pub fn _stake(
 ) -> Result<Response, ContractError> {
     let block_time = env.block.time.seconds();
     let config = CONFIG.load(deps.storage)?;
-    let mut total_staking = TOTAL_STAKING.load(deps.storage).unwrap_or_default();
 
     // if flexible, locked_at is 0, else current time
     let locked_at = match lock_duration {
@@ -318,6 +320,7 @@ pub fn _stake(
         reward_weights,
         None,
     )?;
+    let mut total_staking = TOTAL_STAKING.load(deps.storage).unwrap_or_default();
     user_staking.staked = user_staking.staked.checked_add(amount).unwrap();
     total_staking = total_staking.checked_add(amount).unwrap();
 
@@ -326,6 +329,7 @@ pub fn _stake(
         (&recipient, lock_duration, locked_at),
         &user_staking,
     )?;
+    sync_user_total_staked(deps.storage, &recipient, block_time)?;
     TOTAL_STAKING.save(deps.storage, &total_staking)?;
     TotalStakingByDuration::add(deps.storage, amount, lock_duration, block_time)?;
     Ok(response
@@ -442,6 +446,8 @@ pub fn restake(
         (&recipient, to_duration, block_time),
         &user_staking_to,
     )?;
+    sync_user_total_staked(deps.storage, &sender, block_time)?;
+    sync_user_total_staked(deps.storage, &recipient, block_time)?;
 
     Ok(response
         .add_attribute("action", "add lock")
@@ -490,33 +496,62 @@ pub fn _claim_single(
         block_time,
     )?;
     let mut user_reward_to_claim = UserReward::default();
+    let eclipastro = AssetInfo::NativeToken {
+        denom: cfg.token.clone(),
+    };
+    // compounding changes staked amount, so all rewards are settled with the previous amount
+    let compounding = !user_reward.eclipastro.is_zero()
+        && assets
+            .as_ref()
+            .map_or(true, |a| a.iter().any(|asset| asset.equal(&eclipastro)))
+        && is_auto_compound(deps.storage, &sender, duration)?;
     // if assets is exist, only claim those, else claim all
-    if let Some(assets) = assets {
-        for asset in assets {
-            if asset.equal(&AssetInfo::NativeToken {
-                denom: cfg.token.clone(),
-            }) {
-                user_staking
-                    .reward_weights
-                    .eclipastro
-                    .clone_from(&reward_weights.eclipastro);
-                user_reward_to_claim.eclipastro = user_reward.eclipastro;
-            }
-            if asset.to_string() == cfg.eclip {
-                user_staking.reward_weights.eclip = reward_weights.eclip;
-                user_reward_to_claim.eclip = user_reward.eclip;
-            }
-            if asset.to_string() == cfg.beclip {
-                user_staking.reward_weights.beclip = reward_weights.beclip;
-                user_reward_to_claim.beclip = user_reward.beclip;
+    match assets {
+        Some(assets) if !compounding => {
+            for asset in assets {
+                if asset.equal(&eclipastro) {
+                    user_staking
+                        .reward_weights
+                        .eclipastro
+                        .clone_from(&reward_weights.eclipastro);
+                    user_reward_to_claim.eclipastro = user_reward.eclipastro;
+                }
+                if asset.to_string() == cfg.eclip {
+                    user_staking.reward_weights.eclip = reward_weights.eclip;
+                    user_reward_to_claim.eclip = user_reward.eclip;
+                }
+                if asset.to_string() == cfg.beclip {
+                    user_staking.reward_weights.beclip = reward_weights.beclip;
+                    user_reward_to_claim.beclip = user_reward.beclip;
+                }
+                if let Some(reward) = user_reward.assets.iter().find(|r| r.info.equal(&asset)) {
+                    user_staking
+                        .reward_weights
+                        .set_asset(&asset, reward_weights.asset(&asset));
+                    user_reward_to_claim.assets.push(reward.clone());
+                }
             }
         }
-    } else {
-        user_reward_to_claim = user_reward;
-        user_staking.reward_weights = reward_weights.clone();
+        _ => {
+            user_reward_to_claim = user_reward;
+            user_staking.reward_weights = reward_weights.clone();
+        }
     }
     // save user reward weights
     USER_STAKED.save(deps.storage, (&sender, duration, locked_at), &user_staking)?;
+    // restake eclipASTRO rewards if user enabled auto compound
+    if compounding {
+        _compound(
+            deps.storage,
+            &sender,
+            duration,
+            locked_at,
+            &mut user_staking,
+            user_reward_to_claim.eclipastro,
+            block_time,
+        )?;
+        user_reward_to_claim.eclipastro = Uint128::zero();
+    }
     // update last claim time
     LAST_CLAIM_TIME.save(deps.storage, &block_time)?;
     if total_staking.is_zero() {
@@ -537,28 +572,19 @@ pub fn _claim(
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
     let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
-    let mut blacklist_rewards = BLACK_LIST_REWARDS.load(deps.storage).unwrap_or_default();
-    let is_allowed_user = ALLOWED_USERS
-        .load(deps.storage, &sender)
-        .unwrap_or_default();
 
     // if user is in blacklist increase blacklist_rewards
     if blacklist.contains(&sender) {
-        blacklist_rewards.eclip += rewards.eclip;
-        blacklist_rewards.beclip += rewards.beclip;
-        blacklist_rewards.eclipastro += rewards.eclipastro;
-        BLACK_LIST_REWARDS.save(deps.storage, &blacklist_rewards)?;
-        return Ok(Response::new());
+        return _send_rewards(deps, sender, rewards, Response::new());
     }
 
     let pending_eclipastro_rewards =
         query_eclipastro_pending_rewards(deps.as_ref(), cfg.voter.to_string())?;
 
     let mut response = Response::new().add_attribute("action", "claim rewards");
-    let mut msgs = vec![];
 
     if !pending_eclipastro_rewards.is_zero() {
-        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        response = response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: cfg.voter.to_string(),
             msg: to_json_binary(&VoterExecuteMsg::ClaimAstroRewards {})?,
             funds: vec![],
@@ -573,6 +599,31 @@ pub fn _claim(
             .add_attribute("amount", pending_eclipastro_rewards.to_string());
     }
 
+    _send_rewards(deps, sender, rewards, response)
+}
+
+/// add reward messages to response, rewards of blacklisted user are added to blacklist rewards
+fn _send_rewards(
+    deps: DepsMut,
+    sender: String,
+    rewards: UserReward,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    let is_allowed_user = ALLOWED_USERS
+        .load(deps.storage, &sender)
+        .unwrap_or_default();
+
+    if blacklist.contains(&sender) {
+        let mut blacklist_rewards = BLACK_LIST_REWARDS.load(deps.storage).unwrap_or_default();
+        blacklist_rewards.add(&rewards);
+        BLACK_LIST_REWARDS.save(deps.storage, &blacklist_rewards)?;
+        return Ok(response);
+    }
+
+    let mut msgs = vec![];
+
     if !rewards.eclipastro.is_zero() {
         msgs.push(CosmosMsg::Bank(BankMsg::Send {
             to_address: sender.clone(),
@@ -586,7 +637,7 @@ pub fn _claim(
     if is_allowed_user || sender == cfg.treasury {
         if !(rewards.beclip + rewards.eclip).is_zero() {
             msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: sender,
+                to_address: sender.clone(),
                 amount: coins((rewards.eclip + rewards.beclip).u128(), cfg.eclip),
             }));
             response = response
@@ -609,7 +660,7 @@ pub fn _claim(
 
         if !rewards.eclip.is_zero() {
             msgs.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: sender,
+                to_address: sender.clone(),
                 amount: coins(rewards.eclip.u128(), cfg.eclip),
             }));
             response = response
@@ -618,9 +669,56 @@ pub fn _claim(
         }
     }
 
+    for reward in rewards.assets {
+        if reward.amount.is_zero() {
+            continue;
+        }
+        response = response
+            .add_attribute("asset", reward.info.to_string())
+            .add_attribute("amount", reward.amount.to_string());
+        msgs.push(
+            reward
+                .info
+                .with_balance(reward.amount)
+                .into_msg(sender.clone())?,
+        );
+    }
+
     Ok(response.add_messages(msgs))
 }
 
+/// check if user restakes eclipASTRO rewards of duration
+pub fn is_auto_compound(
+    storage: &dyn Storage,
+    user: &String,
+    duration: u64,
+) -> Result<bool, ContractError> {
+    let blacklist = BLACK_LIST.load(storage).unwrap_or_default();
+    Ok(!blacklist.contains(user)
+        && AUTO_COMPOUND
+            .load(storage, (user, duration))
+            .unwrap_or_default())
+}
+
+/// restake eclipASTRO rewards into the same position
+/// lock end time isn't changed, so rewards of ended lock are restaked with flexible multiplier
+pub fn _compound(
+    storage: &mut dyn Storage,
+    user: &String,
+    duration: u64,
+    locked_at: u64,
+    user_staking: &mut UserStaked,
+    amount: Uint128,
+    block_time: u64,
+) -> Result<(), ContractError> {
+    user_staking.staked += amount;
+    USER_STAKED.save(storage, (user, duration, locked_at), user_staking)?;
+    TOTAL_STAKING.update(storage, |t| -> StdResult<_> { Ok(t + amount) })?;
+    TotalStakingByDuration::add_at(storage, amount, duration, locked_at, block_time)?;
+    sync_user_total_staked(storage, user, block_time)?;
+    Ok(())
+}
+
 pub fn _claim_all(
     mut deps: DepsMut,
     env: Env,
@@ -642,46 +740,91 @@ pub fn _claim_all(
     );
 
     let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
+    let eclipastro = AssetInfo::NativeToken {
+        denom: cfg.token.clone(),
+    };
+    let claims_eclipastro = assets
+        .as_ref()
+        .map_or(true, |a| a.iter().any(|asset| asset.equal(&eclipastro)));
 
     let total_user_reward = calculate_total_user_reward(deps.as_ref(), sender.clone(), block_time)?;
     let mut total_eclipastro_reward = Uint128::zero();
     let mut total_beclip_reward = Uint128::zero();
     let mut total_eclip_reward = Uint128::zero();
+    let mut total_asset_reward = UserReward::default();
 
     for reward_duration in total_user_reward {
         if reward_duration.duration == 0 && !with_flexible {
             continue;
         }
+        let auto_compound = is_auto_compound(deps.storage, &sender, reward_duration.duration)?;
         for reward_locked_at in reward_duration.rewards {
             let locked_at = reward_locked_at.locked_at;
             let mut user_staking = USER_STAKED
                 .load(deps.storage, (&sender, reward_duration.duration, locked_at))
                 .unwrap_or_default();
-            if let Some(asset_list) = assets.clone() {
-                for asset in asset_list {
-                    if asset.equal(&AssetInfo::NativeToken {
-                        denom: cfg.token.clone(),
-                    }) {
-                        user_staking
-                            .reward_weights
-                            .eclipastro
-                            .clone_from(&reward_weights.eclipastro);
-                        total_eclipastro_reward += reward_locked_at.rewards.eclipastro;
-                    }
-                    if asset.to_string() == cfg.eclip {
-                        user_staking.reward_weights.eclip = reward_weights.eclip;
-                        total_eclip_reward += reward_locked_at.rewards.eclip;
-                    }
-                    if asset.to_string() == cfg.beclip {
-                        user_staking.reward_weights.beclip = reward_weights.beclip;
-                        total_beclip_reward += reward_locked_at.rewards.beclip;
+            let mut eclipastro_reward = Uint128::zero();
+            // compounding changes staked amount, so all rewards are settled with the previous amount
+            let compounding = auto_compound
+                && claims_eclipastro
+                && !reward_locked_at.rewards.eclipastro.is_zero();
+            match assets.clone() {
+                Some(asset_list) if !compounding => {
+                    for asset in asset_list {
+                        if asset.equal(&eclipastro) {
+                            user_staking
+                                .reward_weights
+                                .eclipastro
+                                .clone_from(&reward_weights.eclipastro);
+                            eclipastro_reward = reward_locked_at.rewards.eclipastro;
+                        }
+                        if asset.to_string() == cfg.eclip {
+                            user_staking.reward_weights.eclip = reward_weights.eclip;
+                            total_eclip_reward += reward_locked_at.rewards.eclip;
+                        }
+                        if asset.to_string() == cfg.beclip {
+                            user_staking.reward_weights.beclip = reward_weights.beclip;
+                            total_beclip_reward += reward_locked_at.rewards.beclip;
+                        }
+                        if let Some(reward) = reward_locked_at
+                            .rewards
+                            .assets
+                            .iter()
+                            .find(|r| r.info.equal(&asset))
+                        {
+                            user_staking
+                                .reward_weights
+                                .set_asset(&asset, reward_weights.asset(&asset));
+                            total_asset_reward.add(&UserReward {
+                                assets: vec![reward.clone()],
+                                ..Default::default()
+                            });
+                        }
                     }
                 }
+                _ => {
+                    eclipastro_reward = reward_locked_at.rewards.eclipastro;
+                    total_beclip_reward += reward_locked_at.rewards.beclip;
+                    total_eclip_reward += reward_locked_at.rewards.eclip;
+                    total_asset_reward.add(&UserReward {
+                        assets: reward_locked_at.rewards.assets,
+                        ..Default::default()
+                    });
+                    user_staking.reward_weights = reward_weights.clone();
+                }
+            }
+            if compounding {
+                _compound(
+                    deps.storage,
+                    &sender,
+                    reward_duration.duration,
+                    locked_at,
+                    &mut user_staking,
+                    eclipastro_reward,
+                    block_time,
+                )?;
             } else {
-                total_eclipastro_reward += reward_locked_at.rewards.eclipastro;
-                total_beclip_reward += reward_locked_at.rewards.beclip;
-                total_eclip_reward += reward_locked_at.rewards.eclip;
-                user_staking.reward_weights = reward_weights.clone();
+                total_eclipastro_reward += eclipastro_reward;
             }
             USER_STAKED.save(
                 deps.storage,
@@ -701,10 +844,127 @@ pub fn _claim_all(
             eclipastro: total_eclipastro_reward,
             eclip: total_eclip_reward,
             beclip: total_beclip_reward,
+            assets: total_asset_reward.assets,
         },
     )
 }
 
+/// Enable or disable restaking eclipASTRO rewards of duration
+pub fn set_auto_compound(
+    deps: DepsMut,
+    info: MessageInfo,
+    duration: u64,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.to_string();
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(
+        config
+            .timelock_config
+            .into_iter()
+            .any(|i| i.duration == duration),
+        ContractError::NoLockingPeriodFound(duration)
+    );
+    if enabled {
+        AUTO_COMPOUND.save(deps.storage, (&sender, duration), &true)?;
+    } else {
+        AUTO_COMPOUND.remove(deps.storage, (&sender, duration));
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set auto compound")
+        .add_attribute("user", sender)
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+/// Restake eclipASTRO rewards of users who enabled auto compound
+/// Anyone can call
+pub fn compound(
+    mut deps: DepsMut,
+    env: Env,
+    users: Vec<String>,
+) -> Result<Response, ContractError> {
+    let block_time = env.block.time.seconds();
+    ensure!(
+        has_unique_elements(users.clone()),
+        ContractError::DuplicatedAddress(users.join(","))
+    );
+    let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
+    let mut compounded = Uint128::zero();
+    let mut response = Response::new();
+    for user in users {
+        let mut user_rewards = UserReward::default();
+        let positions = USER_STAKED
+            .sub_prefix(&user)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (duration, locked_at) in positions {
+            if !is_auto_compound(deps.storage, &user, duration)? {
+                continue;
+            }
+            let reward = calculate_user_reward(
+                deps.as_ref(),
+                user.clone(),
+                duration,
+                locked_at,
+                block_time,
+            )?;
+            // all rewards are settled before staked amount changes
+            let mut user_staking = USER_STAKED.load(deps.storage, (&user, duration, locked_at))?;
+            user_staking.reward_weights = reward_weights.clone();
+            USER_STAKED.save(deps.storage, (&user, duration, locked_at), &user_staking)?;
+            user_rewards.add(&UserReward {
+                eclipastro: Uint128::zero(),
+                ..reward.clone()
+            });
+            if !reward.eclipastro.is_zero() {
+                _compound(
+                    deps.storage,
+                    &user,
+                    duration,
+                    locked_at,
+                    &mut user_staking,
+                    reward.eclipastro,
+                    block_time,
+                )?;
+                compounded += reward.eclipastro;
+            }
+        }
+        response = _send_rewards(deps.branch(), user, user_rewards, response)?;
+    }
+    LAST_CLAIM_TIME.save(deps.storage, &block_time)?;
+    // only claim pending eclipASTRO rewards from voter
+    let claim_response = _claim(
+        deps,
+        env.clone(),
+        env.contract.address.to_string(),
+        UserReward::default(),
+    )?;
+    Ok(response
+        .add_submessages(claim_response.messages)
+        .add_attributes(claim_response.attributes)
+        .add_attribute("action", "compound")
+        .add_attribute("amount", compounded.to_string()))
+}
+
+/// Process pending days of reward weights and total staking by duration up to max_days
+/// Anyone can call, so that user actions don't pay for catching up a long idle period
+pub fn checkpoint(mut deps: DepsMut, env: Env, max_days: u64) -> Result<Response, ContractError> {
+    ensure!(max_days > 0, ContractError::ZeroMaxDays {});
+    let block_time = env.block.time.seconds();
+    let last_claim_time = match LAST_CLAIM_TIME.may_load(deps.storage)? {
+        Some(last_claim_time) => last_claim_time,
+        None => return Ok(Response::new().add_attribute("action", "checkpoint")),
+    };
+    let checkpoint_time = min((last_claim_time / ONE_DAY + max_days) * ONE_DAY, block_time);
+    update_reward_weights_until(deps.branch(), env, checkpoint_time)?;
+    LAST_CLAIM_TIME.save(deps.storage, &checkpoint_time)?;
+    Ok(Response::new()
+        .add_attribute("action", "checkpoint")
+        .add_attribute("from", last_claim_time.to_string())
+        .add_attribute("to", checkpoint_time.to_string()))
+}
+
 /// Claim user rewards
 pub fn claim(
     mut deps: DepsMut,
@@ -770,13 +1030,18 @@ pub fn unbond(
     let sender = &info.sender;
     let block_time = env.block.time.seconds();
     let lock_ended = check_lock_ended(env.clone(), duration, locked_at)?;
-    check_unbonding_period(period, ContractError::IncorrectUnbondingPeriod)?;
+    let unbonding_tier = get_unbonding_tier(
+        &UNBONDING_TIERS
+            .may_load(deps.storage)?
+            .unwrap_or_else(default_unbonding_tiers),
+        period,
+        ContractError::IncorrectUnbondingPeriod,
+    )?;
 
     if !lock_ended {
         Err(ContractError::EarlyUnlockDisabled {})?;
     }
 
-    let mut total_staking = TOTAL_STAKING.load(deps.storage)?;
     let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
     let (user_staking, response) = _claim_single(
         deps.branch(),
@@ -787,6 +1052,7 @@ pub fn unbond(
         reward_weights,
         None,
     )?;
+    let mut total_staking = TOTAL_STAKING.load(deps.storage)?;
     let unlock_amount = user_staking.staked;
 
     if unlock_amount.is_zero() {
@@ -795,22 +1061,21 @@ pub fn unbond(
 
     USER_UNBONDED.update(deps.storage, sender, |x| -> StdResult<_> {
         let mut unbonded = x.unwrap_or_default();
-        let fee = if period == UNBONDING_PERIOD_0 {
-            (str_to_dec(UNBONDING_FEE_RATE) * u128_to_dec(unlock_amount)).to_uint_floor()
-        } else {
-            Uint128::zero()
-        };
+        let fee = (unbonding_tier.fee_rate * u128_to_dec(unlock_amount)).to_uint_floor();
 
         unbonded.push(UnbondedItem {
             amount: unlock_amount - fee,
             fee,
             release_date: block_time + period,
+            duration,
+            locked_at,
         });
 
         Ok(unbonded)
     })?;
 
     USER_STAKED.remove(deps.storage, (&sender.to_string(), duration, locked_at));
+    sync_user_total_staked(deps.storage, &sender.to_string(), block_time)?;
 
     total_staking = total_staking.checked_sub(unlock_amount).unwrap();
     TOTAL_STAKING.save(deps.storage, &total_staking)?;
@@ -819,12 +1084,69 @@ pub fn unbond(
     Ok(response.add_attribute("action", "unbond"))
 }
 
+/// stake unbonded item back to the position it was unbonded from, unbonding fee is refunded
+/// items unbonded before positions were recorded are restored as flexible staking
+pub fn cancel_unbond(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    index: u32,
+) -> Result<Response, ContractError> {
+    let sender = &info.sender;
+    let block_time = env.block.time.seconds();
+    let mut unbonded = USER_UNBONDED.load(deps.storage, sender).unwrap_or_default();
+    ensure!(
+        (index as usize) < unbonded.len(),
+        ContractError::UnbondedItemNotFound(index)
+    );
+    let item = unbonded.remove(index as usize);
+    if unbonded.is_empty() {
+        USER_UNBONDED.remove(deps.storage, sender);
+    } else {
+        USER_UNBONDED.save(deps.storage, sender, &unbonded)?;
+    }
+
+    let amount = item.amount + item.fee;
+    let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
+    let (mut user_staking, response) = _claim_single(
+        deps.branch(),
+        env,
+        sender.to_string(),
+        item.duration,
+        item.locked_at,
+        reward_weights,
+        None,
+    )?;
+    user_staking.staked += amount;
+    USER_STAKED.save(
+        deps.storage,
+        (&sender.to_string(), item.duration, item.locked_at),
+        &user_staking,
+    )?;
+    sync_user_total_staked(deps.storage, &sender.to_string(), block_time)?;
+    TOTAL_STAKING.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+    TotalStakingByDuration::add_at(
+        deps.storage,
+        amount,
+        item.duration,
+        item.locked_at,
+        block_time,
+    )?;
+    Ok(response
+        .add_attribute("action", "cancel unbond")
+        .add_attribute("duration", item.duration.to_string())
+        .add_attribute("locked_at", item.locked_at.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("fee", item.fee.to_string()))
+}
+
 /// withdraw all unbonded positions
 pub fn withdraw(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     recipient: Option<String>,
+    min_astro_out: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let sender = &info.sender;
     let recipient = recipient
@@ -860,7 +1182,10 @@ pub fn withdraw(
         USER_UNBONDED.save(deps.storage, sender, &items_to_preserve)?;
     }
 
-    WITHDRAW_TEMP_DATA.save(deps.storage, &(recipient, amount_to_send, fee_to_send))?;
+    WITHDRAW_TEMP_DATA.save(
+        deps.storage,
+        &(recipient, amount_to_send, fee_to_send, min_astro_out),
+    )?;
 
     // get astro
     let msg = SubMsg {
@@ -898,7 +1223,8 @@ pub fn handle_swap_to_astro_reply(
         }
     }
 
-    let (recipient, mut amount_to_send, mut fee_to_send) = WITHDRAW_TEMP_DATA.load(deps.storage)?;
+    let (recipient, mut amount_to_send, mut fee_to_send, min_astro_out) =
+        WITHDRAW_TEMP_DATA.load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
     let voter_config: VoterTokenConfig = deps
         .querier
@@ -908,6 +1234,15 @@ pub fn handle_swap_to_astro_reply(
     amount_to_send = min(amount_to_send, astro_amount);
     fee_to_send = min(fee_to_send, astro_amount - amount_to_send);
 
+    let min_astro_out = min_astro_out.unwrap_or_default();
+    ensure!(
+        amount_to_send >= min_astro_out,
+        ContractError::ReceivedAmountTooLow {
+            got: amount_to_send.u128(),
+            expected: min_astro_out.u128()
+        }
+    );
+
     // return astro to user
     response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
         to_address: recipient.to_string(),
@@ -955,7 +1290,6 @@ pub fn unstake(
     //     Err(ContractError::MessageIsDisabled)?;
     // }
 
-    let mut total_staking = TOTAL_STAKING.load(deps.storage)?;
     let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
     let (mut user_staking, mut response) = _claim_single(
         deps.branch(),
@@ -966,6 +1300,7 @@ pub fn unstake(
         reward_weights,
         None,
     )?;
+    let mut total_staking = TOTAL_STAKING.load(deps.storage)?;
 
     if amount.is_some() && duration > 0 {
         ensure!(is_allowed_user, ContractError::NotAllowed(sender));
@@ -989,6 +1324,7 @@ pub fn unstake(
         user_staking.staked -= unlock_amount;
         USER_STAKED.save(deps.storage, (&sender, duration, locked_at), &user_staking)?;
     }
+    sync_user_total_staked(deps.storage, &sender, block_time)?;
     TOTAL_STAKING.save(deps.storage, &total_staking)?;
     TotalStakingByDuration::sub(deps.storage, unlock_amount, duration, locked_at, block_time)?;
 
@@ -1007,6 +1343,286 @@ pub fn unstake(
     Ok(response.add_messages(msgs))
 }
 
+/// Unlock timelocked amount before lock end, claim rewards of user and send penalty to treasury
+pub fn early_unlock(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+    locked_at: u64,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.to_string();
+    let block_time = env.block.time.seconds();
+    ensure!(
+        duration > 0u64,
+        ContractError::NoLockingPeriodFound(duration)
+    );
+    ensure!(
+        !check_lock_ended(env.clone(), duration, locked_at)?,
+        ContractError::LockEnded {}
+    );
+    // partial unlock of timelocked position is only for allowed users as in unstake
+    if amount.is_some() {
+        let is_allowed_user = ALLOWED_USERS
+            .load(deps.storage, &sender)
+            .unwrap_or_default();
+        ensure!(is_allowed_user, ContractError::NotAllowed(sender));
+    }
+    let config = CONFIG.load(deps.storage)?;
+
+    let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
+    let (mut user_staking, response) = _claim_single(
+        deps.branch(),
+        env.clone(),
+        sender.clone(),
+        duration,
+        locked_at,
+        reward_weights,
+        None,
+    )?;
+    let mut total_staking = TOTAL_STAKING.load(deps.storage)?;
+
+    let unlock_amount = amount.unwrap_or(user_staking.staked);
+    ensure!(
+        user_staking.staked >= unlock_amount,
+        ContractError::ExceedAmount {}
+    );
+    ensure!(
+        unlock_amount.gt(&Uint128::zero()),
+        ContractError::NoLockedAmount {}
+    );
+    let penalty = calculate_penalty(deps.as_ref(), env, unlock_amount, duration, locked_at)?;
+
+    if unlock_amount == user_staking.staked {
+        USER_STAKED.remove(deps.storage, (&sender, duration, locked_at));
+    } else {
+        user_staking.staked -= unlock_amount;
+        USER_STAKED.save(deps.storage, (&sender, duration, locked_at), &user_staking)?;
+    }
+    sync_user_total_staked(deps.storage, &sender, block_time)?;
+    total_staking = total_staking.checked_sub(unlock_amount).unwrap();
+    TOTAL_STAKING.save(deps.storage, &total_staking)?;
+    TotalStakingByDuration::sub(deps.storage, unlock_amount, duration, locked_at, block_time)?;
+
+    let mut msgs = vec![];
+    if unlock_amount > penalty {
+        msgs.push(BankMsg::Send {
+            to_address: sender,
+            amount: coins((unlock_amount - penalty).u128(), config.token.clone()),
+        });
+    }
+    if !penalty.is_zero() {
+        msgs.push(BankMsg::Send {
+            to_address: config.treasury.to_string(),
+            amount: coins(penalty.u128(), config.token),
+        });
+    }
+
+    Ok(response
+        .add_messages(msgs)
+        .add_attribute("action", "early unlock")
+        .add_attribute("amount", unlock_amount.to_string())
+        .add_attribute("penalty", penalty.to_string())
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("locked_at", locked_at.to_string()))
+}
+
+/// Move staking position to recipient, claim rewards of both users
+pub fn transfer_position(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+    locked_at: u64,
+    amount: Option<Uint128>,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.to_string();
+    let recipient = deps.api.addr_validate(&recipient)?.to_string();
+    let block_time = env.block.time.seconds();
+    let blacklist = BLACK_LIST.load(deps.storage).unwrap_or_default();
+    ensure!(
+        !blacklist.contains(&sender) && !blacklist.contains(&recipient),
+        ContractError::Blacklisted {}
+    );
+    ensure!(sender != recipient, ContractError::SameRecipient {});
+
+    let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
+    let (mut user_staking_from, response) = _claim_single(
+        deps.branch(),
+        env.clone(),
+        sender.clone(),
+        duration,
+        locked_at,
+        reward_weights.clone(),
+        None,
+    )?;
+    let (mut user_staking_to, recipient_response) = _claim_single(
+        deps.branch(),
+        env,
+        recipient.clone(),
+        duration,
+        locked_at,
+        reward_weights,
+        None,
+    )?;
+
+    let transfer_amount = amount.unwrap_or(user_staking_from.staked);
+    ensure!(
+        user_staking_from.staked >= transfer_amount,
+        ContractError::ExceedAmount {}
+    );
+    ensure!(
+        transfer_amount.gt(&Uint128::zero()),
+        ContractError::NoLockedAmount {}
+    );
+
+    // position keeps duration and locked_at, so total staking by duration and end time don't change
+    if transfer_amount == user_staking_from.staked {
+        USER_STAKED.remove(deps.storage, (&sender, duration, locked_at));
+    } else {
+        user_staking_from.staked -= transfer_amount;
+        USER_STAKED.save(
+            deps.storage,
+            (&sender, duration, locked_at),
+            &user_staking_from,
+        )?;
+    }
+    user_staking_to.staked += transfer_amount;
+    USER_STAKED.save(
+        deps.storage,
+        (&recipient, duration, locked_at),
+        &user_staking_to,
+    )?;
+    sync_user_total_staked(deps.storage, &sender, block_time)?;
+    sync_user_total_staked(deps.storage, &recipient, block_time)?;
+
+    Ok(response
+        .add_submessages(recipient_response.messages)
+        .add_attributes(recipient_response.attributes)
+        .add_attribute("action", "transfer position")
+        .add_attribute("from", sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", transfer_amount.to_string())
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("locked_at", locked_at.to_string()))
+}
+
+/// Merge user positions of same duration into one, claim rewards of those positions
+pub fn aggregate_positions(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+    locked_at_list: Vec<u64>,
+    target_duration: u64,
+) -> Result<Response, ContractError> {
+    let sender = info.sender.to_string();
+    let block_time = env.block.time.seconds();
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(!locked_at_list.is_empty(), ContractError::NoLockedAmount {});
+    ensure!(
+        has_unique_elements(locked_at_list.clone()),
+        ContractError::DuplicatedPositions {}
+    );
+    // target duration must be longer than duration
+    ensure!(
+        duration <= target_duration,
+        ContractError::ExtendDurationErr(duration, target_duration)
+    );
+    ensure!(
+        config
+            .timelock_config
+            .into_iter()
+            .any(|i| i.duration == target_duration),
+        ContractError::NoLockingPeriodFound(target_duration)
+    );
+    // keep the latest end time, if duration is extended lock starts from now like restake
+    let target_locked_at = if target_duration == duration {
+        locked_at_list.iter().max().cloned().unwrap()
+    } else {
+        block_time
+    };
+
+    let reward_weights = update_reward_weights(deps.branch(), env.clone())?;
+    let mut rewards = UserReward::default();
+    let mut aggregated_amount = Uint128::zero();
+    let mut positions = locked_at_list
+        .iter()
+        .map(|locked_at| (duration, *locked_at))
+        .collect::<Vec<_>>();
+    if !positions.contains(&(target_duration, target_locked_at)) {
+        positions.push((target_duration, target_locked_at));
+    }
+    for (position_duration, locked_at) in positions {
+        let user_staking = USER_STAKED
+            .load(deps.storage, (&sender, position_duration, locked_at))
+            .unwrap_or_default();
+        // listed positions must exist, target position is optional
+        if position_duration == duration {
+            ensure!(
+                !user_staking.staked.is_zero(),
+                ContractError::NoLockedAmount {}
+            );
+        }
+        if user_staking.staked.is_zero() {
+            continue;
+        }
+        let reward = calculate_user_reward(
+            deps.as_ref(),
+            sender.clone(),
+            position_duration,
+            locked_at,
+            block_time,
+        )?;
+        rewards.add(&reward);
+        aggregated_amount += user_staking.staked;
+        TotalStakingByDuration::sub(
+            deps.storage,
+            user_staking.staked,
+            position_duration,
+            locked_at,
+            block_time,
+        )?;
+        USER_STAKED.remove(deps.storage, (&sender, position_duration, locked_at));
+    }
+
+    // restake eclipASTRO rewards into merged position if user enabled auto compound
+    if !rewards.eclipastro.is_zero() && is_auto_compound(deps.storage, &sender, target_duration)? {
+        aggregated_amount += rewards.eclipastro;
+        TOTAL_STAKING.update(deps.storage, |t| -> StdResult<_> {
+            Ok(t + rewards.eclipastro)
+        })?;
+        rewards.eclipastro = Uint128::zero();
+    }
+    TotalStakingByDuration::add_at(
+        deps.storage,
+        aggregated_amount,
+        target_duration,
+        target_locked_at,
+        block_time,
+    )?;
+    USER_STAKED.save(
+        deps.storage,
+        (&sender, target_duration, target_locked_at),
+        &UserStaked {
+            staked: aggregated_amount,
+            reward_weights,
+        },
+    )?;
+    sync_user_total_staked(deps.storage, &sender, block_time)?;
+    LAST_CLAIM_TIME.save(deps.storage, &block_time)?;
+
+    let response = _claim(deps, env, sender, rewards)?;
+    Ok(response
+        .add_attribute("action", "aggregate positions")
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("target_duration", target_duration.to_string())
+        .add_attribute("locked_at", target_locked_at.to_string())
+        .add_attribute("amount", aggregated_amount.to_string()))
+}
+
 // add reweards
 pub fn add_rewards(
     deps: DepsMut,
@@ -1029,34 +1645,7 @@ pub fn add_rewards(
         }
     );
     let block_time = env.block.time.seconds();
-    // get reward start time
-    let reward_start_time = if let Some(from) = from {
-        ensure!(
-            from >= block_time,
-            ContractError::InvalidStartTime {
-                got: from,
-                expect: block_time
-            }
-        );
-        from
-    } else {
-        // fetch reward data which end time is bigger than current time
-        let last_rewards = REWARD
-            .range(
-                deps.storage,
-                Some(Bound::exclusive((block_time, 0u64))),
-                None,
-                Order::Descending,
-            )
-            .take(1)
-            .collect::<StdResult<Vec<_>>>()
-            .unwrap();
-        if last_rewards.is_empty() {
-            block_time
-        } else {
-            last_rewards[0].0 .0
-        }
-    };
+    let reward_start_time = get_reward_start_time(deps.storage, block_time, from)?;
     // get reward duration
     let duration = duration.unwrap_or(DEFAULT_REWARD_DISTRIBUTION_PERIOD);
     let mut reward = REWARD
@@ -1067,6 +1656,7 @@ pub fn add_rewards(
         .unwrap_or(Reward {
             eclip: Uint128::zero(),
             beclip: Uint128::zero(),
+            assets: vec![],
         });
     reward.eclip += eclip;
     reward.beclip += beclip;
@@ -1082,6 +1672,120 @@ pub fn add_rewards(
         .add_attribute("eclip", eclip.to_string())
         .add_attribute("beclip", beclip.to_string()))
 }
+/// add reward schedule of other asset, ECLIP and eclipASTRO have their own reward flows
+pub fn add_asset_rewards(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    from: Option<u64>,
+    duration: Option<u64>,
+    asset: Asset,
+) -> Result<Response, ContractError> {
+    OWNER.assert_admin(deps.as_ref(), &sender)?;
+    let cfg = CONFIG.load(deps.storage)?;
+    ensure!(!asset.amount.is_zero(), ContractError::ZeroAmount {});
+    ensure!(
+        !asset
+            .info
+            .equal(&AssetInfo::NativeToken { denom: cfg.eclip })
+            && !asset
+                .info
+                .equal(&AssetInfo::NativeToken { denom: cfg.token })
+            && !asset.info.equal(&AssetInfo::Token {
+                contract_addr: cfg.beclip
+            }),
+        ContractError::InvalidAsset {}
+    );
+    let block_time = env.block.time.seconds();
+    let reward_start_time = get_reward_start_time(deps.storage, block_time, from)?;
+    let duration = duration.unwrap_or(DEFAULT_REWARD_DISTRIBUTION_PERIOD);
+    let mut reward = REWARD
+        .load(
+            deps.storage,
+            (reward_start_time + duration, reward_start_time),
+        )
+        .unwrap_or(Reward {
+            eclip: Uint128::zero(),
+            beclip: Uint128::zero(),
+            assets: vec![],
+        });
+    add_asset(
+        &mut reward.assets,
+        RewardAmount {
+            info: asset.info.clone(),
+            amount: asset.amount,
+        },
+    );
+    REWARD.save(
+        deps.storage,
+        (reward_start_time + duration, reward_start_time),
+        &reward,
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "add_asset_rewards")
+        .add_attribute("from", reward_start_time.to_string())
+        .add_attribute("duration", duration.to_string())
+        .add_attribute("asset", asset.info.to_string())
+        .add_attribute("amount", asset.amount.to_string()))
+}
+
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+    match from_json(&cw20_msg.msg)? {
+        Cw20HookMsg::AddAssetRewards { from, duration } => add_asset_rewards(
+            deps,
+            env,
+            sender,
+            from,
+            duration,
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: info.sender,
+                },
+                amount: cw20_msg.amount,
+            },
+        ),
+    }
+}
+
+/// get reward start time, reward starts after the last reward if from isn't set
+fn get_reward_start_time(
+    storage: &dyn Storage,
+    block_time: u64,
+    from: Option<u64>,
+) -> Result<u64, ContractError> {
+    if let Some(from) = from {
+        ensure!(
+            from >= block_time,
+            ContractError::InvalidStartTime {
+                got: from,
+                expect: block_time
+            }
+        );
+        return Ok(from);
+    }
+    // fetch reward data which end time is bigger than current time
+    let last_rewards = REWARD
+        .range(
+            storage,
+            Some(Bound::exclusive((block_time, 0u64))),
+            None,
+            Order::Descending,
+        )
+        .take(1)
+        .collect::<StdResult<Vec<_>>>()?;
+    if last_rewards.is_empty() {
+        Ok(block_time)
+    } else {
+        Ok(last_rewards[0].0 .0)
+    }
+}
+
 // update each total_staking_by_duration by now
 pub fn update_duration_total_staking(
     storage: &mut dyn Storage,
@@ -1113,8 +1817,17 @@ pub fn update_duration_total_staking(
 }
 /// update all total_staking_by_duration and reward_weights by now
 pub fn update_reward_weights(deps: DepsMut, env: Env) -> Result<RewardWeights, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
     let block_time = env.block.time.seconds();
+    update_reward_weights_until(deps, env, block_time)
+}
+/// update all total_staking_by_duration and reward_weights by block_time
+/// block_time can be earlier than now to process pending days in chunks
+pub fn update_reward_weights_until(
+    deps: DepsMut,
+    env: Env,
+    block_time: u64,
+) -> Result<RewardWeights, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
     // update all total_staking_by_duration by current time
     for tl_cfg in config.timelock_config.clone().into_iter() {
         update_duration_total_staking(deps.storage, tl_cfg.duration, block_time)?;
@@ -1144,6 +1857,15 @@ pub fn update_reward_weights(deps: DepsMut, env: Env) -> Result<RewardWeights, C
                     calculate_eclip_beclip_reward(deps.as_ref(), start_time, end_time)?;
                 reward_weights.eclip += Decimal256::from_ratio(eclip_reward, boost_sum);
                 reward_weights.beclip += Decimal256::from_ratio(beclip_reward, boost_sum);
+                for reward in calculate_asset_rewards(deps.as_ref(), start_time, end_time)? {
+                    add_asset(
+                        &mut reward_weights.assets,
+                        RewardWeight {
+                            info: reward.info,
+                            reward_weight: Decimal256::from_ratio(reward.amount, boost_sum),
+                        },
+                    );
+                }
                 let pending_eclipastro_reward =
                     calculate_eclipastro_reward(deps.as_ref(), env.clone(), start_time, end_time)?;
                 reward_weights.eclipastro +=
@@ -7,19 +7,23 @@ use std::{
 
 use crate::{
     config::{
-        BPS_DENOMINATOR, DEFAULT_LAST_EARLY_UNLOCK_PENALTY, ECLIPASTRO_REWARD_DISTRIBUTION_PERIOD,
-        ONE_DAY,
+        BPS_DENOMINATOR, DEFAULT_LAST_EARLY_UNLOCK_PENALTY, DEFAULT_PAGE_LIMIT,
+        ECLIPASTRO_REWARD_DISTRIBUTION_PERIOD, MAX_PAGE_LIMIT, MAX_SCAN_LIMIT, ONE_DAY,
     },
     state::{
-        RewardWeights, TotalStakingByDuration, BLACK_LIST, BLACK_LIST_REWARDS, CONFIG,
-        LAST_CLAIM_TIME, OWNER, PENDING_ECLIPASTRO_REWARDS, REWARD, STAKING_DURATION_BY_END_TIME,
-        TOTAL_STAKING, USER_STAKED, USER_UNBONDED,
+        load_user_total_staked_at_ts, RewardWeights, TotalStakingByDuration, BLACK_LIST,
+        BLACK_LIST_REWARDS, CONFIG, LAST_CLAIM_TIME, OWNER, PENDING_ECLIPASTRO_REWARDS, REWARD,
+        STAKING_DURATION_BY_END_TIME, TOTAL_STAKING, UNBONDING_TIERS, USER_STAKED, USER_UNBONDED,
     },
 };
 use eclipse_base::voter::msg::{AstroStakingRewardResponse, QueryMsg as VoterQueryMsg};
-use equinox_msg::single_sided_staking::{
-    Config, Reward, StakingWithDuration, UnbondedItem, UserReward, UserRewardByDuration,
-    UserRewardByLockedAt, UserStaking, UserStakingByDuration,
+use equinox_msg::{
+    single_sided_staking::{
+        AllPositionsResponse, Config, Reward, RewardAmount, RewardWeight, StakingPosition,
+        StakingWithDuration, UnbondedItem, UserReward, UserRewardByDuration, UserRewardByLockedAt,
+        UserStaking, UserStakingByDuration,
+    },
+    utils::{add_asset, default_unbonding_tiers, paginate_addresses, UnbondingTier},
 };
 
 /// query owner
@@ -111,37 +115,150 @@ pub fn total_staking_by_duration(
 }
 
 /// query user staking
-pub fn query_staking(deps: Deps, _env: Env, user: String) -> StdResult<Vec<UserStaking>> {
-    let config = CONFIG.load(deps.storage)?;
-    let durations = config
-        .timelock_config
-        .into_iter()
-        .map(|c| c.duration)
-        .collect::<Vec<u64>>();
-    let mut staking_lists = vec![];
-    for duration in durations {
-        let staking = USER_STAKED
-            .prefix((&user, duration))
-            .range(deps.storage, None, None, Order::Ascending)
-            .map(|s| {
-                let (locked_at, staking) = s?;
-                Ok(UserStakingByDuration {
-                    locked_at,
-                    amount: staking.staked,
-                })
-            })
-            .collect::<StdResult<Vec<UserStakingByDuration>>>()
-            .unwrap_or(vec![]);
-        if !staking.is_empty() {
-            staking_lists.push(UserStaking { duration, staking });
+pub fn query_staking(
+    deps: Deps,
+    _env: Env,
+    user: String,
+    start_after: Option<(u64, u64)>,
+    limit: Option<u32>,
+) -> StdResult<Vec<UserStaking>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let mut staking_lists: Vec<UserStaking> = vec![];
+    for s in USER_STAKED
+        .sub_prefix(&user)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+    {
+        let ((duration, locked_at), staking) = s?;
+        let staking = UserStakingByDuration {
+            locked_at,
+            amount: staking.staked,
+        };
+        // positions are sorted by duration, so group them by the last one
+        match staking_lists.last_mut() {
+            Some(last) if last.duration == duration => last.staking.push(staking),
+            _ => staking_lists.push(UserStaking {
+                duration,
+                staking: vec![staking],
+            }),
         }
     }
     Ok(staking_lists)
 }
 
-pub fn query_unbonded(deps: Deps, _env: Env, user: String) -> StdResult<Vec<UnbondedItem>> {
+pub fn query_unbonded(
+    deps: Deps,
+    _env: Env,
+    user: String,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> StdResult<Vec<UnbondedItem>> {
     let user = deps.api.addr_validate(&user)?;
-    Ok(USER_UNBONDED.load(deps.storage, &user).unwrap_or_default())
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let start = start_after.map(|i| i as usize + 1).unwrap_or_default();
+    Ok(USER_UNBONDED
+        .load(deps.storage, &user)
+        .unwrap_or_default()
+        .into_iter()
+        .skip(start)
+        .take(limit)
+        .collect())
+}
+
+/// query total staked amount of user at timestamp
+pub fn query_staking_at(
+    deps: Deps,
+    env: Env,
+    user: String,
+    timestamp: Option<u64>,
+) -> StdResult<Uint128> {
+    load_user_total_staked_at_ts(deps.storage, &user, env.block.time.seconds(), timestamp)
+}
+
+/// query boosted total staking at timestamp
+pub fn query_total_boosted_stake_at(
+    deps: Deps,
+    env: Env,
+    timestamp: Option<u64>,
+) -> StdResult<Uint256> {
+    let block_time = env.block.time.seconds();
+    TotalStakingByDuration::load_boost_sum_at_ts(
+        deps.storage,
+        block_time,
+        Some(timestamp.unwrap_or(block_time).min(block_time)),
+    )
+}
+
+/// query all addresses which have staking positions
+pub fn query_all_stakers(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|user| Bound::exclusive((user, u64::MAX, u64::MAX)));
+    let mut stakers: Vec<String> = vec![];
+    for s in USER_STAKED.range(deps.storage, start, None, Order::Ascending) {
+        let ((user, _, _), staking) = s?;
+        if staking.staked.is_zero() {
+            continue;
+        }
+        if stakers.last() != Some(&user) {
+            if stakers.len() == limit {
+                break;
+            }
+            stakers.push(user);
+        }
+    }
+    Ok(stakers)
+}
+
+/// query all staking positions, filtered by duration
+/// at most MAX_SCAN_LIMIT entries are read, last_key is the cursor for the next page if there are more entries
+pub fn query_all_positions(
+    deps: Deps,
+    duration: Option<u64>,
+    start_after: Option<(String, u64, u64)>,
+    limit: Option<u32>,
+) -> StdResult<AllPositionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(user, duration, locked_at)| Bound::exclusive((user, *duration, *locked_at)));
+    let mut positions = vec![];
+    let mut scanned_key = None;
+    let mut has_more = false;
+    for (scanned, s) in USER_STAKED
+        .range(deps.storage, start, None, Order::Ascending)
+        .enumerate()
+    {
+        if positions.len() == limit || scanned == MAX_SCAN_LIMIT {
+            has_more = true;
+            break;
+        }
+        let ((user, d, locked_at), staking) = s?;
+        scanned_key = Some((user.clone(), d, locked_at));
+        if staking.staked.is_zero() || duration.is_some_and(|duration| duration != d) {
+            continue;
+        }
+        positions.push(StakingPosition {
+            user,
+            duration: d,
+            locked_at,
+            amount: staking.staked,
+        });
+    }
+    Ok(AllPositionsResponse {
+        positions,
+        last_key: scanned_key.filter(|_| has_more),
+    })
 }
 
 /// query user reward
@@ -163,17 +280,19 @@ pub fn query_reward_schedule(
     deps: Deps,
     env: Env,
     from: Option<u64>,
+    start_after: Option<(u64, u64)>,
+    limit: Option<u32>,
 ) -> StdResult<Vec<((u64, u64), Reward)>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    let start = start_after.unwrap_or((from.unwrap_or(env.block.time.seconds()), 0u64));
     REWARD
         .range(
             deps.storage,
-            Some(Bound::exclusive((
-                from.unwrap_or(env.block.time.seconds()),
-                0u64,
-            ))),
+            Some(Bound::exclusive(start)),
             None,
             Order::Ascending,
         )
+        .take(limit)
         .collect::<StdResult<Vec<_>>>()
 }
 
@@ -181,10 +300,19 @@ pub fn query_reward_list(
     deps: Deps,
     env: Env,
     user: String,
+    start_after: Option<(u64, u64)>,
+    limit: Option<u32>,
 ) -> StdResult<Vec<(u64, u64, UserReward)>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
     USER_STAKED
         .sub_prefix(&user)
-        .range(deps.storage, None, None, Order::Ascending)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
         .map(|s| {
             let ((duration, locked_at), _) = s.unwrap();
             let user_reward =
@@ -242,11 +370,9 @@ pub fn _user_reward(
             calculate_reward_weights(deps, env.clone(), block_time, block_time)?,
             user_staking.staked,
         )?;
-        Ok(UserReward {
-            eclipastro: reward_during_lock.eclipastro + reward_after_lock.eclipastro,
-            beclip: reward_during_lock.beclip + reward_after_lock.beclip,
-            eclip: reward_during_lock.eclip + reward_after_lock.eclip,
-        })
+        let mut reward = reward_during_lock;
+        reward.add(&reward_after_lock);
+        Ok(reward)
     }
 }
 
@@ -295,11 +421,9 @@ pub fn query_calculate_reward(
             calculate_reward_weights(deps, env.clone(), block_time, to)?,
             amount,
         )?;
-        Ok(UserReward {
-            eclipastro: reward_during_lock.eclipastro + reward_after_lock.eclipastro,
-            beclip: reward_during_lock.beclip + reward_after_lock.beclip,
-            eclip: reward_during_lock.eclip + reward_after_lock.eclip,
-        })
+        let mut reward = reward_during_lock;
+        reward.add(&reward_after_lock);
+        Ok(reward)
     }
 }
 
@@ -369,6 +493,15 @@ pub fn calculate_reward_weights(
             calculate_eclip_beclip_reward(deps, start_time, end_time)?;
         reward_weights.eclip += Decimal256::from_ratio(eclip_reward, boost_sum);
         reward_weights.beclip += Decimal256::from_ratio(beclip_reward, boost_sum);
+        for reward in calculate_asset_rewards(deps, start_time, end_time)? {
+            add_asset(
+                &mut reward_weights.assets,
+                RewardWeight {
+                    info: reward.info,
+                    reward_weight: Decimal256::from_ratio(reward.amount, boost_sum),
+                },
+            );
+        }
         let pending_eclipastro_reward =
             calculate_eclipastro_reward(deps, env.clone(), start_time, end_time)?;
         reward_weights.eclipastro +=
@@ -502,11 +635,9 @@ pub fn calculate_user_reward(
             next_reward_weights,
             user_staking.staked,
         )?;
-        Ok(UserReward {
-            eclipastro: reward_during_lock.eclipastro + reward_after_lock.eclipastro,
-            beclip: reward_during_lock.beclip + reward_after_lock.beclip,
-            eclip: reward_during_lock.eclip + reward_after_lock.eclip,
-        })
+        let mut reward = reward_during_lock;
+        reward.add(&reward_after_lock);
+        Ok(reward)
     }
 }
 
@@ -516,6 +647,26 @@ pub fn calculate_reward_with_multiplier(
     end_reward_weights: RewardWeights,
     staked: Uint128,
 ) -> StdResult<UserReward> {
+    let assets = end_reward_weights
+        .assets
+        .iter()
+        .map(|w| {
+            Ok(RewardAmount {
+                info: w.info.clone(),
+                amount: w
+                    .reward_weight
+                    .checked_sub(start_reward_weights.asset(&w.info))
+                    .unwrap_or_default()
+                    .checked_mul(Decimal256::from_ratio(staked, 1u128))
+                    .unwrap()
+                    .checked_mul(Decimal256::from_ratio(multiplier, BPS_DENOMINATOR))
+                    .unwrap()
+                    .to_uint_floor()
+                    .try_into()?,
+            })
+        })
+        .filter(|r| !matches!(r, Ok(r) if r.amount.is_zero()))
+        .collect::<StdResult<Vec<_>>>()?;
     Ok(UserReward {
         eclipastro: end_reward_weights
             .eclipastro
@@ -545,6 +696,7 @@ pub fn calculate_reward_with_multiplier(
             .unwrap()
             .to_uint_floor()
             .try_into()?,
+        assets,
     })
 }
 
@@ -609,8 +761,31 @@ pub fn query_eclipastro_rewards(deps: Deps, env: Env) -> StdResult<Vec<(u64, Uin
     Ok(pending_rewards)
 }
 
-pub fn query_blacklist(deps: Deps) -> StdResult<Vec<String>> {
-    Ok(BLACK_LIST.load(deps.storage).unwrap_or_default())
+/// query unbonding periods and fee rates
+pub fn query_unbonding_tiers(deps: Deps) -> StdResult<Vec<UnbondingTier>> {
+    Ok(UNBONDING_TIERS
+        .may_load(deps.storage)?
+        .unwrap_or_else(default_unbonding_tiers))
+}
+
+/// days to be processed by checkpoint
+pub fn query_pending_days(deps: Deps, env: Env) -> StdResult<u64> {
+    let block_time = env.block.time.seconds();
+    let last_claim_time = LAST_CLAIM_TIME.load(deps.storage).unwrap_or(block_time);
+    Ok(block_time / ONE_DAY - last_claim_time / ONE_DAY)
+}
+
+pub fn query_blacklist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+    Ok(paginate_addresses(
+        BLACK_LIST.load(deps.storage).unwrap_or_default(),
+        start_after,
+        limit,
+    ))
 }
 
 pub fn query_blacklist_rewards(deps: Deps, env: Env) -> StdResult<UserReward> {
@@ -628,9 +803,7 @@ pub fn query_blacklist_rewards(deps: Deps, env: Env) -> StdResult<UserReward> {
             ) {
                 let (locked_at, _) = s.unwrap();
                 let rewards = _user_reward(deps, env.clone(), user.clone(), duration, locked_at)?;
-                blacklist_rewards.eclip += rewards.eclip;
-                blacklist_rewards.beclip += rewards.beclip;
-                blacklist_rewards.eclipastro += rewards.eclipastro;
+                blacklist_rewards.add(&rewards);
             }
         }
     }
@@ -671,3 +844,39 @@ pub fn calculate_eclip_beclip_reward(
     }
     Ok((eclip_reward, beclip_reward))
 }
+
+/// calculate rewards of other assets during period
+pub fn calculate_asset_rewards(
+    deps: Deps,
+    start_time: u64,
+    end_time: u64,
+) -> StdResult<Vec<RewardAmount>> {
+    let rewards = REWARD
+        .range(
+            deps.storage,
+            Some(Bound::exclusive((start_time, 0u64))),
+            None,
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut asset_rewards: Vec<RewardAmount> = vec![];
+    for ((end, start), reward) in rewards {
+        let duration = end - start;
+        if start >= end_time {
+            continue;
+        }
+        for asset in reward.assets {
+            let amount = asset
+                .amount
+                .multiply_ratio(min(end_time, end) - max(start_time, start), duration);
+            add_asset(
+                &mut asset_rewards,
+                RewardAmount {
+                    info: asset.info,
+                    amount,
+                },
+            );
+        }
+    }
+    Ok(asset_rewards)
+}
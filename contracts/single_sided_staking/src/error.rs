@@ -1,5 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_utils::PaymentError;
+use equinox_msg::ownership::OwnershipError;
 use thiserror::Error;
 
 use cw_controllers::AdminError;
@@ -12,9 +13,15 @@ pub enum ContractError {
     #[error("Amount {got} doesn't match with arguments {expected}")]
     AmountNotMatch { got: u128, expected: u128 },
 
+    #[error("User is already in blacklist: {0}")]
+    AlreadyBlacklisted(String),
+
     #[error("User is in blacklist")]
     Blacklisted {},
 
+    #[error("User is not in blacklist: {0}")]
+    NotBlacklisted(String),
+
     #[error("Contract name must be same: {0}")]
     ContractNameErr(String),
 
@@ -29,9 +36,15 @@ pub enum ContractError {
     #[error("Ensure list contains unique assets")]
     DuplicatedAssets {},
 
+    #[error("Ensure list contains unique positions")]
+    DuplicatedPositions {},
+
     #[error("Early Unlock is not allowed")]
     EarlyUnlockDisabled {},
 
+    #[error("Lock is already ended, use unstake or unbond instead")]
+    LockEnded {},
+
     #[error("Amount {got} exceeds your staking {expected}")]
     ExeedingUnstakeAmount { got: u128, expected: u128 },
 
@@ -41,9 +54,6 @@ pub enum ContractError {
     #[error("Exceed locked amount")]
     ExceedAmount {},
 
-    #[error("Parameter expires_in cannot be higher than {0}")]
-    ExpiresInErr(u64),
-
     #[error("Invalid asset")]
     InvalidAsset {},
 
@@ -65,14 +75,14 @@ pub enum ContractError {
     #[error("Not allowed address: {0}")]
     NotAllowed(String),
 
-    #[error("Ownership proposal expired")]
-    OwnershipProposalExpired {},
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
 
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
-    #[error("New owner cannot be same")]
-    SameOwner {},
+    #[error("Recipient cannot be same as sender")]
+    SameRecipient {},
 
     #[error("Semver parsing error: {0}")]
     SemVer(String),
@@ -86,12 +96,21 @@ pub enum ContractError {
     #[error("Token amount must not be zero")]
     ZeroAmount {},
 
+    #[error("Max days must not be zero")]
+    ZeroMaxDays {},
+
     #[error("Incorrect unbonding period")]
     IncorrectUnbondingPeriod,
 
     #[error("Early withdraw isn't allowed")]
     EarlyWithdraw,
 
+    #[error("Unbonded item not found: {0}")]
+    UnbondedItemNotFound(u32),
+
+    #[error("Received amount {got} is less than minimum {expected}")]
+    ReceivedAmountTooLow { got: u128, expected: u128 },
+
     #[error("Swap to ASTRO error")]
     SwapToAstroError,
 
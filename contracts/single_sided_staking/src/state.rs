@@ -1,10 +1,12 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal256, StdResult, Storage, Uint128, Uint256};
+use cosmwasm_std::{Addr, Decimal256, Order, StdResult, Storage, Uint128, Uint256};
 use cw_controllers::Admin;
-use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Bound, Item, Map, SnapshotItem, SnapshotMap, Strategy};
 
-use equinox_msg::single_sided_staking::{
-    Config, OwnershipProposal, Reward, UnbondedItem, UserReward,
+use astroport::asset::AssetInfo;
+use equinox_msg::{
+    single_sided_staking::{Config, Reward, RewardWeight, UnbondedItem, UserReward},
+    utils::UnbondingTier,
 };
 
 use crate::{
@@ -17,17 +19,28 @@ pub const CONTRACT_NAME: &str = "single sided staking contract";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const SWAP_TO_ASTRO_REPLY_ID: u64 = 0;
-/// (recipient, amount_to_send, fee_to_send)
-pub const WITHDRAW_TEMP_DATA: Item<(Addr, Uint128, Uint128)> = Item::new("withdraw_temp_data");
+/// (recipient, amount_to_send, fee_to_send, min_astro_out)
+pub const WITHDRAW_TEMP_DATA: Item<(Addr, Uint128, Uint128, Option<Uint128>)> =
+    Item::new("withdraw_temp_data");
 
 pub const OWNER: Admin = Admin::new("owner");
 pub const CONFIG: Item<Config> = Item::new("config");
 
 /// unbonded items by user_address
 pub const USER_UNBONDED: Map<&Addr, Vec<UnbondedItem>> = Map::new("user_unbonded");
+/// unbonding periods and fees, default tiers are used if it's not set
+pub const UNBONDING_TIERS: Item<Vec<UnbondingTier>> = Item::new("unbonding_tiers");
 // user staking info (address, duration, start_time)
 pub const USER_STAKED: Map<(&String, u64, u64), UserStaked> = Map::new("user_staking");
 
+// total staked amount of user by block time
+pub const USER_TOTAL_STAKED: SnapshotMap<&String, Uint128> = SnapshotMap::new(
+    "user_total_staked",
+    "user_total_staked__checkpoints",
+    "user_total_staked__changelog",
+    Strategy::EveryBlock,
+);
+
 pub const TOTAL_STAKING: Item<Uint128> = Item::new("total_staking");
 pub const TOTAL_STAKING_BY_DURATION: SnapshotMap<u64, TotalStakingByDuration> = SnapshotMap::new(
     "total_staking_by_duration",
@@ -51,11 +64,11 @@ pub const REWARD_WEIGHTS: SnapshotItem<RewardWeights> = SnapshotItem::new(
 pub const PENDING_ECLIPASTRO_REWARDS: Map<u64, Uint128> = Map::new("pending_eclipastro_rewards");
 // end time, start time, reward
 pub const REWARD: Map<(u64, u64), Reward> = Map::new("reward");
-/// Stores the latest contract ownership transfer proposal
-pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
 /// List of users who can't claim rewards
 pub const BLACK_LIST: Item<Vec<String>> = Item::new("blacklist");
 pub const BLACK_LIST_REWARDS: Item<UserReward> = Item::new("blacklist_rewards");
+/// users who restake eclipASTRO rewards (address, duration)
+pub const AUTO_COMPOUND: Map<(&String, u64), bool> = Map::new("auto_compound");
 
 #[cw_serde]
 pub struct TotalStakingByDuration {
@@ -133,15 +146,28 @@ impl TotalStakingByDuration {
         amount: Uint128,
         duration: u64,
         block_time: u64,
+    ) -> Result<(), ContractError> {
+        Self::add_at(storage, amount, duration, block_time, block_time)
+    }
+
+    /// add amount to position which is locked before current time
+    pub fn add_at(
+        storage: &mut dyn Storage,
+        amount: Uint128,
+        duration: u64,
+        locked_at: u64,
+        block_time: u64,
     ) -> Result<(), ContractError> {
         let mut staking = TOTAL_STAKING_BY_DURATION
             .load(storage, duration)
             .unwrap_or_default();
         staking.staked += amount;
-        staking.valid_staked += amount;
+        let end_time = (locked_at + duration) / ONE_DAY * ONE_DAY + ONE_DAY;
+        if duration == 0 || end_time > block_time {
+            staking.valid_staked += amount;
+        }
         TOTAL_STAKING_BY_DURATION.save(storage, duration, &staking, block_time)?;
         if duration != 0u64 {
-            let end_time = (block_time + duration) / ONE_DAY * ONE_DAY + ONE_DAY;
             STAKING_DURATION_BY_END_TIME
                 .update(storage, (duration, end_time), |s| {
                     Ok(s.unwrap_or_default() + amount)
@@ -178,11 +204,79 @@ impl TotalStakingByDuration {
     }
 }
 
+fn sum_user_staked(storage: &dyn Storage, user: &String) -> StdResult<Uint128> {
+    USER_STAKED
+        .sub_prefix(user)
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(Uint128::zero(), |acc, s| {
+            let (_, staking) = s?;
+            Ok(acc + staking.staked)
+        })
+}
+
+/// save sum of user positions to snapshot store, must be called after user positions change
+pub fn sync_user_total_staked(
+    storage: &mut dyn Storage,
+    user: &String,
+    block_time: u64,
+) -> StdResult<()> {
+    let total_staked = sum_user_staked(storage, user)?;
+    USER_TOTAL_STAKED.save(storage, user, &total_staked, block_time)
+}
+
+/// save snapshots of users staked before snapshot store was added
+/// returns the last processed user if there can be more users
+pub fn backfill_user_total_staked(
+    storage: &mut dyn Storage,
+    block_time: u64,
+    start_after: Option<String>,
+    limit: usize,
+) -> StdResult<Option<String>> {
+    let mut cursor = start_after;
+    for _ in 0..limit {
+        // skip remaining positions of the previous user
+        let start = cursor
+            .as_ref()
+            .map(|user| Bound::exclusive((user, u64::MAX, u64::MAX)));
+        let Some(key) = USER_STAKED
+            .keys(storage, start, None, Order::Ascending)
+            .next()
+        else {
+            return Ok(None);
+        };
+        let (user, _, _) = key?;
+        if USER_TOTAL_STAKED.may_load(storage, &user)?.is_none() {
+            sync_user_total_staked(storage, &user, block_time)?;
+        }
+        cursor = Some(user);
+    }
+    Ok(cursor)
+}
+
+/// load total staked amount of user at certain time, zero before the first snapshot
+pub fn load_user_total_staked_at_ts(
+    storage: &dyn Storage,
+    user: &String,
+    block_time: u64,
+    timestamp: Option<u64>,
+) -> StdResult<Uint128> {
+    let staked = match timestamp.unwrap_or(block_time) {
+        timestamp if timestamp >= block_time => USER_TOTAL_STAKED.may_load(storage, user),
+        timestamp => USER_TOTAL_STAKED.may_load_at_height(storage, user, timestamp + 1),
+    }?
+    .unwrap_or_default();
+
+    Ok(staked)
+}
+
 #[cw_serde]
 pub struct RewardWeights {
     pub eclipastro: Decimal256,
     pub beclip: Decimal256,
     pub eclip: Decimal256,
+    /// reward weights of other assets
+    #[serde(default)]
+    pub assets: Vec<RewardWeight>,
 }
 
 impl Default for RewardWeights {
@@ -191,11 +285,31 @@ impl Default for RewardWeights {
             eclip: Decimal256::zero(),
             eclipastro: Decimal256::zero(),
             beclip: Decimal256::zero(),
+            assets: vec![],
         }
     }
 }
 
 impl RewardWeights {
+    /// reward weight of asset, zero if asset wasn't rewarded yet
+    pub fn asset(&self, info: &AssetInfo) -> Decimal256 {
+        self.assets
+            .iter()
+            .find(|w| w.info.equal(info))
+            .map(|w| w.reward_weight)
+            .unwrap_or_default()
+    }
+
+    pub fn set_asset(&mut self, info: &AssetInfo, reward_weight: Decimal256) {
+        match self.assets.iter_mut().find(|w| w.info.equal(info)) {
+            Some(w) => w.reward_weight = reward_weight,
+            None => self.assets.push(RewardWeight {
+                info: info.clone(),
+                reward_weight,
+            }),
+        }
+    }
+
     pub fn load_at_ts(
         storage: &dyn Storage,
         block_time: u64,
@@ -1,5 +1,6 @@
 pub mod lockdrop;
 pub mod lp_depositor;
 pub mod lp_staking;
+pub mod ownership;
 pub mod single_sided_staking;
 pub mod utils;
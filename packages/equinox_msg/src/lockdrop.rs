@@ -5,7 +5,7 @@ use cosmwasm_std::{
 };
 use cw20::Cw20ReceiveMsg;
 
-use crate::single_sided_staking::UserReward;
+use crate::{ownership::OwnershipProposal, single_sided_staking::UserReward};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -42,10 +42,14 @@ pub enum ExecuteMsg {
     UpdateConfig {
         new_config: UpdateConfigMsg,
     },
-    // ADMIN Function ::: To update owner
-    UpdateOwner {
-        new_owner: Addr,
+    // ADMIN Function ::: To propose new owner, it must be claimed by the new owner
+    ProposeNewOwner {
+        owner: String,
+        expires_in: u64,
     },
+    // ADMIN Function ::: To drop ownership proposal
+    DropOwnershipProposal {},
+    ClaimOwnership {},
     UpdateRewardDistributionConfig {
         new_config: RewardDistributionConfig,
     },
@@ -53,6 +57,10 @@ pub enum ExecuteMsg {
     IncreaseLockup {
         stake_type: StakeType,
         duration: u64,
+        /// account credited with the lockup, sender by default
+        recipient: Option<String>,
+        /// account which referred the recipient, can be set only once
+        referrer: Option<String>,
     },
     // Function to increase lockup duration while deposit window
     ExtendLock {
@@ -65,6 +73,11 @@ pub enum ExecuteMsg {
         duration: u64,
         amount: Option<Uint128>,
     },
+    // Move user position out of lockdrop to user's own staking vault position
+    MigrateToVault {
+        stake_type: StakeType,
+        duration: u64,
+    },
     // Receive hook used to accept ASTRO/xASTRO Token deposits
     Receive(Cw20ReceiveMsg),
     // ADMIN Function ::: To deposit ASTRO/xASTRO to Eclipse Equinox vxASTRO holder contract
@@ -86,6 +99,16 @@ pub enum ExecuteMsg {
         rewards: Vec<IncentiveRewards>,
     },
     ClaimBlacklistRewards {},
+    // Claim vested lockdrop incentives earned by referrals
+    ClaimReferralRewards {},
+    // ADMIN Function ::: To settle user rewards and add user to blacklist
+    AddToBlacklist {
+        user: String,
+    },
+    // ADMIN Function ::: To settle rewards to blacklist and remove user from blacklist
+    RemoveFromBlacklist {
+        user: String,
+    },
     UpdateLockdropPeriods {
         deposit: Option<u64>,
         withdraw: Option<u64>,
@@ -116,6 +139,9 @@ pub enum QueryMsg {
     /// query owner
     #[returns(Addr)]
     Owner {},
+    /// query pending ownership transfer proposal
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
     /// query lockup info
     #[returns(SingleLockupInfoResponse)]
     SingleLockupInfo {},
@@ -135,18 +161,38 @@ pub enum QueryMsg {
     Incentives { stake_type: StakeType },
     // list of users who can't receive rewards
     #[returns(Vec<String>)]
-    Blacklist {},
+    Blacklist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     // rewards of blacklist
     #[returns(BlacklistRewards)]
     BlacklistRewards,
     // calculate penalty amount
     #[returns(Uint128)]
     CalculatePenaltyAmount { amount: Uint128, duration: u64 },
+    // referrer of user
+    #[returns(Option<String>)]
+    Referrer { user: String },
+    // referral weights and incentives of referrer
+    #[returns(Vec<ReferralInfoResponse>)]
+    ReferralInfo { referrer: String },
 }
 
 #[cw_serde]
 pub enum Cw20HookMsg {
-    IncreaseIncentives { rewards: Vec<IncentiveRewards> },
+    IncreaseIncentives {
+        rewards: Vec<IncentiveRewards>,
+    },
+    /// Stake cw20 ASTRO from accepted ASTRO assets during deposit phase
+    IncreaseLockup {
+        stake_type: StakeType,
+        duration: u64,
+        /// account credited with the lockup, sender by default
+        recipient: Option<String>,
+        /// account which referred the recipient, can be set only once
+        referrer: Option<String>,
+    },
 }
 
 #[cw_serde]
@@ -234,6 +280,15 @@ pub struct Config {
     pub claims_allowed: bool,
     pub countdown_start_at: u64,
     pub init_early_unlock_penalty: Decimal,
+    /// share of lockdrop incentives weight credited to referrers, basis points
+    #[serde(default)]
+    pub referral_bonus: u64,
+    /// ASTRO converter contract, converts accepted ASTRO assets to native ASTRO
+    #[serde(default)]
+    pub astro_converter: Option<Addr>,
+    /// IBC denoms and cw20 tokens of ASTRO which are accepted as deposits
+    #[serde(default)]
+    pub accepted_astro_assets: Vec<AssetInfo>,
 }
 
 #[cw_serde]
@@ -246,6 +301,9 @@ pub struct UpdateConfigMsg {
     pub eclip_staking: Option<String>,
     pub dao_treasury_address: Option<String>,
     pub init_early_unlock_penalty: Option<Decimal>,
+    pub referral_bonus: Option<u64>,
+    pub astro_converter: Option<String>,
+    pub accepted_astro_assets: Option<Vec<AssetInfo>>,
 }
 
 #[cw_serde]
@@ -297,6 +355,9 @@ pub struct UnclaimedRewards {
     pub eclip: Uint128,
     pub beclip: Uint128,
     pub eclipastro: Uint128,
+    /// partner asset rewards of single sided staking
+    #[serde(default)]
+    pub assets: Vec<Asset>,
 }
 
 impl Default for UnclaimedRewards {
@@ -305,6 +366,7 @@ impl Default for UnclaimedRewards {
             eclip: Uint128::zero(),
             beclip: Uint128::zero(),
             eclipastro: Uint128::zero(),
+            assets: vec![],
         }
     }
 }
@@ -323,6 +385,9 @@ pub struct BlacklistRewards {
     pub beclip: Uint128,
     pub eclipastro: Uint128,
     pub astro: Uint128,
+    /// partner asset rewards of single sided staking
+    #[serde(default)]
+    pub assets: Vec<Asset>,
 }
 
 #[cw_serde]
@@ -339,6 +404,34 @@ pub struct LockdropIncentive {
     pub claimed: Uint128,
 }
 
+#[cw_serde]
+#[derive(Default)]
+pub struct LockdropIncentivesVesting {
+    pub beclip: LockdropIncentiveVesting,
+    pub eclip: LockdropIncentiveVesting,
+}
+
+/// incentives vest linearly from countdown_start_at after the instant part
+#[cw_serde]
+#[derive(Default)]
+pub struct LockdropIncentiveVesting {
+    /// vested amount which isn't claimed yet
+    pub claimable: Uint128,
+    /// total vested amount, claimed amount included
+    pub vested: Uint128,
+    /// amount which isn't vested yet
+    pub remaining: Uint128,
+}
+
+// change when referred users deposit/withdraw during lockdrop
+#[cw_serde]
+#[derive(Default)]
+pub struct ReferralInfo {
+    /// weighted xASTRO locked by referred users
+    pub weighted_xastro: Uint128,
+    pub lockdrop_incentives: LockdropIncentives,
+}
+
 impl Default for SingleUserLockupInfo {
     fn default() -> Self {
         SingleUserLockupInfo {
@@ -554,6 +647,7 @@ pub struct UserSingleLockupInfoResponse {
     pub eclipastro_withdrawed: Uint128,
     pub withdrawal_flag: bool,
     pub lockdrop_incentives: LockdropIncentives,
+    pub lockdrop_incentives_vesting: LockdropIncentivesVesting,
     pub staking_rewards: Vec<Asset>,
     pub countdown_start_at: u64,
 }
@@ -566,11 +660,20 @@ pub struct UserLpLockupInfoResponse {
     pub lp_token_withdrawed: Uint128,
     pub withdrawal_flag: bool,
     pub lockdrop_incentives: LockdropIncentives,
+    pub lockdrop_incentives_vesting: LockdropIncentivesVesting,
     pub staking_rewards: Vec<Asset>,
     pub countdown_start_at: u64,
     pub reward_weights: LpStakingRewardWeights,
 }
 
+#[cw_serde]
+pub struct ReferralInfoResponse {
+    pub stake_type: StakeType,
+    pub weighted_xastro: Uint128,
+    pub lockdrop_incentives: LockdropIncentives,
+    pub claimable: IncentiveAmounts,
+}
+
 #[cw_serde]
 pub struct RewardDistributionConfig {
     pub instant: u64,        // bps
@@ -1,6 +1,8 @@
 use astroport::asset::Asset;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Env, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Decimal, Env, StdResult, Uint128, WasmMsg};
+
+use crate::ownership::OwnershipProposal;
 
 #[cw_serde]
 pub struct Config {
@@ -18,6 +20,9 @@ pub struct Config {
     pub lp_contract: Addr,
     /// lp_token
     pub lp_token: String,
+    /// lp staking vault to stake converted lp tokens
+    #[serde(default)]
+    pub lp_staking: Option<Addr>,
 }
 
 #[cw_serde]
@@ -36,6 +41,10 @@ pub struct InstantiateMsg {
     pub lp_contract: String,
     /// lp_token
     pub lp_token: String,
+    /// lp staking vault to stake converted lp tokens
+    pub lp_staking: Option<String>,
+    /// contract owner, sender is used if it's not set
+    pub owner: Option<String>,
 }
 
 #[cw_serde]
@@ -43,12 +52,50 @@ pub enum ExecuteMsg {
     /// convert and deposit astro, xastro to lp by user
     Convert {
         recipient: Option<String>,
+        /// minimum lp token amount to receive
+        min_lp_out: Option<Uint128>,
+        /// slippage tolerance of providing liquidity
+        max_spread: Option<Decimal>,
+    },
+    /// convert and deposit astro, xastro to lp and stake received lp tokens to lp staking vault
+    ConvertAndStake {
+        recipient: Option<String>,
+        /// minimum lp token amount to receive
+        min_lp_out: Option<Uint128>,
+        /// slippage tolerance of providing liquidity
+        max_spread: Option<Decimal>,
+    },
+    /// update config, only owner
+    UpdateConfig {
+        config: UpdateConfigMsg,
     },
+    /// propose new owner, it must be claimed by the new owner
+    ProposeNewOwner {
+        owner: String,
+        expires_in: u64,
+    },
+    /// drop ownership proposal
+    DropOwnershipProposal {},
+    /// claim ownership by proposed owner
+    ClaimOwnership {},
     Callback(CallbackMsg),
 }
 
 #[cw_serde]
-pub enum MigrateMsg {}
+pub struct UpdateConfigMsg {
+    /// eclipASTRO converter contract
+    pub voter: Option<String>,
+    /// lp staking vault to stake converted lp tokens
+    pub lp_staking: Option<String>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// lp staking vault to stake converted lp tokens
+    pub lp_staking: Option<String>,
+    /// contract owner, required for contracts deployed before ownership was added
+    pub owner: Option<String>,
+}
 
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -56,19 +103,44 @@ pub enum QueryMsg {
     /// query config
     #[returns(Config)]
     Config {},
+    /// query owner
+    #[returns(Addr)]
+    Owner {},
+    /// query pending ownership transfer proposal
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 
-    #[returns(Uint128)]
-    Simulate { asset: Asset },
+    /// expected lp token amount and assets which can't be deposited
+    #[returns(SimulateResponse)]
+    Simulate { assets: Vec<Asset> },
 }
+
 #[cw_serde]
-pub enum Cw20HookMsg {
-    /// convert and deposit eclipastro to lp by user
-    Convert { recipient: Option<String> },
+pub struct SimulateResponse {
+    /// lp token amount to be minted
+    pub lp_amount: Uint128,
+    /// assets which can't be deposited
+    pub leftovers: Vec<Asset>,
+    /// relative loss of lp amount compared to deposit at price_scale
+    pub price_impact: Decimal,
+    /// lp token amount charged by pool for imbalanced deposit
+    pub fee: Uint128,
 }
 
 #[cw_serde]
 pub enum CallbackMsg {
-    DepositIntoPool { recipient: String },
+    DepositIntoPool {
+        recipient: String,
+        /// user who receives remaining ASTRO dust
+        sender: String,
+        min_lp_out: Option<Uint128>,
+        max_spread: Option<Decimal>,
+    },
+    /// stake lp tokens received since prev_lp_balance was queried
+    StakeLpToken {
+        recipient: String,
+        prev_lp_balance: Uint128,
+    },
 }
 
 impl CallbackMsg {
@@ -1,8 +1,17 @@
-use astroport::asset::{Asset, AssetInfo};
+use astroport::{
+    asset::{Asset, AssetInfo},
+    router::SwapOperation,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Decimal256, Env, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Decimal, Decimal256, Env, StdResult, Uint128, WasmMsg,
+};
 
-use crate::single_sided_staking::UnbondedItem;
+use crate::{
+    ownership::OwnershipProposal,
+    single_sided_staking::{TimeLockConfig, UnbondedItem},
+    utils::{AssetAmount, UnbondingTier},
+};
 
 // #[cw_serde]
 // pub struct MigrateMsg {
@@ -73,16 +82,52 @@ pub enum ExecuteMsg {
         amount: Uint128,
         recipient: Option<String>,
     },
+    /// stake lp token, flexible staking if duration isn't set
     Stake {
+        duration: Option<u64>,
         recipient: Option<String>,
     },
+    /// stake lp token of lockdrop position keeping its lock time, only lockdrop
+    StakeLockdropPosition {
+        duration: u64,
+        locked_at: u64,
+        recipient: String,
+    },
+    /// lock flexible staking or locked position for the same or longer duration
+    Restake {
+        from_duration: u64,
+        locked_at: Option<u64>,
+        amount: Option<Uint128>,
+        to_duration: u64,
+    },
+    /// move locked position to flexible staking after lock end
+    Unlock {
+        duration: u64,
+        locked_at: u64,
+        amount: Option<Uint128>,
+    },
 
     Unbond {
         amount: Option<Uint128>,
         period: u64,
     },
+    /// stake unbonded item back with fee refunded
+    CancelUnbond {
+        index: u32,
+    },
     Withdraw {
         recipient: Option<String>,
+        /// minimum ASTRO amount to receive
+        min_astro_out: Option<Uint128>,
+    },
+    /// withdraw unbonded lp tokens as requested output
+    WithdrawAs {
+        recipient: Option<String>,
+        output: WithdrawOutput,
+        /// minimum ASTRO or xASTRO amount to receive, not allowed for pair assets
+        min_receive: Option<Uint128>,
+        /// minimum pair assets to receive on withdrawing liquidity from pair
+        min_assets_to_receive: Option<Vec<Asset>>,
     },
 
     AddRewards {
@@ -99,9 +144,19 @@ pub enum ExecuteMsg {
         users: Vec<String>,
     },
 
+    /// settle user rewards and add user to blacklist, only owner
+    AddToBlacklist {
+        user: String,
+    },
+    /// settle rewards to blacklist and remove user from blacklist, only owner
     RemoveFromBlacklist {
         user: String,
     },
+    /// swap treasury and funding dao rewards pending on swap routes, anyone can call
+    /// only rewards of given assets are swapped if set, so a failing route can be skipped
+    SwapRewards {
+        assets: Option<Vec<AssetInfo>>,
+    },
 }
 
 #[cw_serde]
@@ -111,17 +166,32 @@ pub enum QueryMsg {
     #[returns(Config)]
     Config {},
     /// query reward config
-    #[returns(Reward)]
+    #[returns(RewardDistribution)]
     RewardDistribution {},
+    /// query treasury and funding dao rewards accumulated from incentive assets
+    #[returns(Vec<Asset>)]
+    ProtocolFees {},
+    /// query treasury and funding dao rewards waiting to be swapped
+    #[returns(Vec<PendingSwapReward>)]
+    PendingSwapRewards {},
     /// query owner
     #[returns(Addr)]
     Owner {},
+    /// query pending ownership transfer proposal
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
     /// query total_staking
     #[returns(Uint128)]
     TotalStaking {},
     /// query user_staking
     #[returns(UserStaking)]
     Staking { user: String },
+    /// query locked user positions
+    #[returns(Vec<LockedPosition>)]
+    LockedStaking { user: String },
+    /// query lock durations and reward multipliers
+    #[returns(Vec<TimeLockConfig>)]
+    TimelockConfig {},
     /// query unbonded user positions
     #[returns(Vec<UnbondedItem>)]
     Unbonded { user: String },
@@ -136,7 +206,10 @@ pub enum QueryMsg {
     UserRewardWeights { user: String },
 
     #[returns(Vec<String>)]
-    Blacklist {},
+    Blacklist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     // rewards of blacklist users which goes to the Equinox treasury
     #[returns(Vec<RewardAmount>)]
     BlacklistRewards,
@@ -146,6 +219,10 @@ pub enum QueryMsg {
 
     #[returns(Vec<((u64, u64), Reward)>)]
     RewardSchedule { from: Option<u64> },
+
+    /// query unbonding periods and fee rates
+    #[returns(Vec<UnbondingTier>)]
+    UnbondingTiers {},
 }
 
 #[cw_serde]
@@ -169,6 +246,31 @@ impl CallbackMsg {
     }
 }
 
+#[cw_serde]
+pub enum WithdrawOutput {
+    /// lp tokens as is
+    LpToken,
+    /// eclipASTRO and xASTRO withdrawn from pair
+    PairAssets,
+    /// ASTRO swapped from pair assets
+    Astro,
+    /// xASTRO, eclipASTRO is swapped to ASTRO and staked
+    Xastro,
+}
+
+/// data of withdrawal which is processed in replies
+#[cw_serde]
+pub struct WithdrawTempData {
+    pub recipient: Addr,
+    pub output: WithdrawOutput,
+    pub min_receive: Option<Uint128>,
+    pub fee_rate: Decimal,
+    /// ASTRO received from the first swap
+    pub astro_amount: Uint128,
+    /// xASTRO received from pair
+    pub xastro_amount: Uint128,
+}
+
 #[cw_serde]
 pub struct UpdateConfigMsg {
     pub lp_token: Option<AssetInfo>,
@@ -179,6 +281,8 @@ pub struct UpdateConfigMsg {
     pub funding_dao: Option<String>,
     pub eclip: Option<String>,
     pub beclip: Option<String>,
+    pub unbonding_tiers: Option<Vec<UnbondingTier>>,
+    pub timelock_config: Option<Vec<TimeLockConfig>>,
 }
 
 #[cw_serde]
@@ -238,6 +342,53 @@ pub struct RewardDistribution {
     pub treasury: u32,
     /// funding dao
     pub funding_dao: u32,
+    /// routes of treasury and funding dao rewards by incentive asset,
+    /// ASTRO is staked to xASTRO and other assets are sent as is if route isn't set
+    #[serde(default)]
+    pub routes: Vec<RewardRoute>,
+}
+
+#[cw_serde]
+pub struct RewardRoute {
+    pub info: AssetInfo,
+    pub route: RouteType,
+}
+
+#[cw_serde]
+pub enum RouteType {
+    /// send asset as is
+    Send,
+    /// stake ASTRO to get xASTRO
+    StakeToXastro,
+    /// swap asset via Astroport router
+    Swap {
+        router: String,
+        operations: Vec<SwapOperation>,
+        max_spread: Option<Decimal>,
+    },
+}
+
+#[cw_serde]
+pub struct PendingSwapReward {
+    pub receiver: Addr,
+    pub asset: Asset,
+}
+
+impl AssetAmount for PendingSwapReward {
+    type Amount = Uint128;
+
+    fn info(&self) -> &AssetInfo {
+        &self.asset.info
+    }
+
+    fn amount_mut(&mut self) -> &mut Uint128 {
+        &mut self.asset.amount
+    }
+
+    /// swaps of the same asset are merged per receiver
+    fn same_key(&self, other: &Self) -> bool {
+        self.receiver == other.receiver && self.info().equal(other.info())
+    }
 }
 
 #[cw_serde]
@@ -270,18 +421,62 @@ pub struct VaultRewards {
     pub beclip: Uint128,
 }
 
+/// ECLIP, bECLIP reward weights per boosted lp token from last claim time
+#[cw_serde]
+pub struct VaultRewardWeights {
+    pub eclip: Decimal256,
+    pub beclip: Decimal256,
+    /// lock end times reached since last claim time
+    pub expired: Vec<BoostCheckpoint>,
+}
+
+#[cw_serde]
+pub struct BoostCheckpoint {
+    pub end_time: u64,
+    /// ECLIP reward weight from last claim time to end time
+    pub eclip: Decimal256,
+    /// bECLIP reward weight from last claim time to end time
+    pub beclip: Decimal256,
+    /// boost of positions which lock is ended
+    pub boost: Uint128,
+}
+
 #[cw_serde]
 pub struct RewardWeight {
     pub info: AssetInfo,
     pub reward_weight: Decimal256,
 }
 
+impl AssetAmount for RewardWeight {
+    type Amount = Decimal256;
+
+    fn info(&self) -> &AssetInfo {
+        &self.info
+    }
+
+    fn amount_mut(&mut self) -> &mut Decimal256 {
+        &mut self.reward_weight
+    }
+}
+
 #[cw_serde]
 pub struct RewardAmount {
     pub info: AssetInfo,
     pub amount: Uint128,
 }
 
+impl AssetAmount for RewardAmount {
+    type Amount = Uint128;
+
+    fn info(&self) -> &AssetInfo {
+        &self.info
+    }
+
+    fn amount_mut(&mut self) -> &mut Uint128 {
+        &mut self.amount
+    }
+}
+
 #[cw_serde]
 pub struct UserStaking {
     pub staked: Uint128,
@@ -298,17 +493,37 @@ impl Default for UserStaking {
 }
 
 #[cw_serde]
-pub struct UserAstroportReward {
-    pub asset: AssetInfo,
+pub struct UserLockedStaking {
+    pub staked: Uint128,
+    /// additional amount counted for ECLIP, bECLIP rewards by lock reward multiplier
+    pub boost: Uint128,
+    pub reward_weights: Vec<RewardWeight>,
+    pub boost_reward_weights: Vec<RewardWeight>,
+}
+
+impl Default for UserLockedStaking {
+    fn default() -> Self {
+        UserLockedStaking {
+            staked: Uint128::zero(),
+            boost: Uint128::zero(),
+            reward_weights: vec![],
+            boost_reward_weights: vec![],
+        }
+    }
+}
+
+#[cw_serde]
+pub struct LockedPosition {
+    pub duration: u64,
+    pub locked_at: u64,
+    pub end_time: u64,
     pub amount: Uint128,
-    pub reward_weight: Decimal256,
+    pub boost: Uint128,
 }
 
-/// This structure describes the parameters used for creating a request for a change of contract ownership.
 #[cw_serde]
-pub struct OwnershipProposal {
-    /// The newly proposed contract owner
-    pub owner: Addr,
-    /// Time until the proposal to change ownership expires
-    pub ttl: u64,
+pub struct UserAstroportReward {
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+    pub reward_weight: Decimal256,
 }
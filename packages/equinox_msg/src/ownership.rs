@@ -0,0 +1,260 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{attr, Addr, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use cw_controllers::{Admin, AdminError};
+use cw_storage_plus::Item;
+use thiserror::Error;
+
+/// Max time during which ownership proposal can be claimed
+pub const MAX_PROPOSAL_TTL: u64 = 1209600;
+
+/// Stores the latest contract ownership transfer proposal
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+
+/// This structure describes the parameters used for creating a request for a change of contract ownership.
+#[cw_serde]
+pub struct OwnershipProposal {
+    /// The newly proposed contract owner
+    pub owner: Addr,
+    /// Time until the proposal to change ownership expires
+    pub ttl: u64,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum OwnershipError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Admin(#[from] AdminError),
+
+    #[error("New owner cannot be same")]
+    SameOwner {},
+
+    #[error("Parameter expires_in cannot be higher than {0}")]
+    ExpiresInErr(u64),
+
+    #[error("Ownership proposal not found")]
+    ProposalNotFound {},
+
+    #[error("Ownership proposal expired")]
+    OwnershipProposalExpired {},
+
+    #[error("Only proposed owner can claim ownership")]
+    NotProposedOwner {},
+}
+
+/// Creates a new request to change contract ownership, replacing the previous one.
+/// Only the current owner can execute this
+pub fn propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: &Admin,
+    new_owner: String,
+    expires_in: u64,
+) -> Result<Response, OwnershipError> {
+    owner.assert_admin(deps.as_ref(), &info.sender)?;
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+
+    if owner.is_admin(deps.as_ref(), &new_owner)? {
+        Err(OwnershipError::SameOwner {})?;
+    }
+
+    if MAX_PROPOSAL_TTL < expires_in {
+        Err(OwnershipError::ExpiresInErr(MAX_PROPOSAL_TTL))?;
+    }
+
+    let ttl = env.block.time.seconds() + expires_in;
+    OWNERSHIP_PROPOSAL.save(
+        deps.storage,
+        &OwnershipProposal {
+            owner: new_owner.clone(),
+            ttl,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "propose_new_owner"),
+        attr("new_owner", new_owner),
+        attr("ttl", ttl.to_string()),
+    ]))
+}
+
+/// Removes a request to change contract ownership.
+/// Only the current owner can execute this
+pub fn drop_ownership_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+    owner: &Admin,
+) -> Result<Response, OwnershipError> {
+    owner.assert_admin(deps.as_ref(), &info.sender)?;
+
+    OWNERSHIP_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new().add_attributes(vec![attr("action", "drop_ownership_proposal")]))
+}
+
+/// Transfers ownership to the proposed owner.
+/// Only the proposed owner can execute this before the proposal expires
+pub fn claim_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: &Admin,
+) -> Result<Response, OwnershipError> {
+    let proposal = OWNERSHIP_PROPOSAL
+        .may_load(deps.storage)?
+        .ok_or(OwnershipError::ProposalNotFound {})?;
+
+    if info.sender != proposal.owner {
+        Err(OwnershipError::NotProposedOwner {})?;
+    }
+
+    if env.block.time.seconds() > proposal.ttl {
+        Err(OwnershipError::OwnershipProposalExpired {})?;
+    }
+
+    let previous_owner = owner.get(deps.as_ref())?;
+    owner.set(deps.branch(), Some(proposal.owner.clone()))?;
+    OWNERSHIP_PROPOSAL.remove(deps.storage);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "claim_ownership"),
+        attr(
+            "previous_owner",
+            previous_owner.map(|x| x.to_string()).unwrap_or_default(),
+        ),
+        attr("new_owner", proposal.owner),
+    ]))
+}
+
+/// Returns the pending ownership proposal if it exists
+pub fn query_ownership_proposal(deps: Deps) -> StdResult<Option<OwnershipProposal>> {
+    OWNERSHIP_PROPOSAL.may_load(deps.storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    const OWNER: Admin = Admin::new("owner");
+
+    #[test]
+    fn transfer_ownership() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        OWNER
+            .set(deps.as_mut(), Some(Addr::unchecked("owner")))
+            .unwrap();
+
+        let err = propose_new_owner(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            &OWNER,
+            "bob".to_string(),
+            100,
+        )
+        .unwrap_err();
+        assert_eq!(err, OwnershipError::Admin(AdminError::NotAdmin {}));
+
+        let err = propose_new_owner(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            &OWNER,
+            "owner".to_string(),
+            100,
+        )
+        .unwrap_err();
+        assert_eq!(err, OwnershipError::SameOwner {});
+
+        let err = propose_new_owner(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            &OWNER,
+            "bob".to_string(),
+            MAX_PROPOSAL_TTL + 1,
+        )
+        .unwrap_err();
+        assert_eq!(err, OwnershipError::ExpiresInErr(MAX_PROPOSAL_TTL));
+
+        propose_new_owner(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            &OWNER,
+            "bob".to_string(),
+            100,
+        )
+        .unwrap();
+
+        let err = claim_ownership(deps.as_mut(), env.clone(), mock_info("alice", &[]), &OWNER)
+            .unwrap_err();
+        assert_eq!(err, OwnershipError::NotProposedOwner {});
+
+        env.block.time = env.block.time.plus_seconds(100);
+        claim_ownership(deps.as_mut(), env, mock_info("bob", &[]), &OWNER).unwrap();
+        assert!(OWNER
+            .is_admin(deps.as_ref(), &Addr::unchecked("bob"))
+            .unwrap());
+        assert_eq!(query_ownership_proposal(deps.as_ref()).unwrap(), None);
+    }
+
+    #[test]
+    fn expired_proposal() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        OWNER
+            .set(deps.as_mut(), Some(Addr::unchecked("owner")))
+            .unwrap();
+
+        propose_new_owner(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            &OWNER,
+            "bob".to_string(),
+            100,
+        )
+        .unwrap();
+
+        env.block.time = env.block.time.plus_seconds(101);
+        let err = claim_ownership(deps.as_mut(), env, mock_info("bob", &[]), &OWNER).unwrap_err();
+        assert_eq!(err, OwnershipError::OwnershipProposalExpired {});
+        assert!(OWNER
+            .is_admin(deps.as_ref(), &Addr::unchecked("owner"))
+            .unwrap());
+    }
+
+    #[test]
+    fn drop_proposal() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        OWNER
+            .set(deps.as_mut(), Some(Addr::unchecked("owner")))
+            .unwrap();
+
+        propose_new_owner(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            &OWNER,
+            "bob".to_string(),
+            100,
+        )
+        .unwrap();
+
+        let err =
+            drop_ownership_proposal(deps.as_mut(), mock_info("bob", &[]), &OWNER).unwrap_err();
+        assert_eq!(err, OwnershipError::Admin(AdminError::NotAdmin {}));
+
+        drop_ownership_proposal(deps.as_mut(), mock_info("owner", &[]), &OWNER).unwrap();
+        assert_eq!(query_ownership_proposal(deps.as_ref()).unwrap(), None);
+
+        let err = claim_ownership(deps.as_mut(), env, mock_info("bob", &[]), &OWNER).unwrap_err();
+        assert_eq!(err, OwnershipError::ProposalNotFound {});
+    }
+}
@@ -1,6 +1,14 @@
 use astroport::asset::AssetInfo;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Decimal, Env, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Decimal, Decimal256, Env, StdResult, Uint128, Uint256, WasmMsg,
+};
+use cw20::Cw20ReceiveMsg;
+
+use crate::{
+    ownership::OwnershipProposal,
+    utils::{add_asset, AssetAmount, UnbondingTier},
+};
 
 /// we need fee here not to calculate it on the query
 #[cw_serde]
@@ -8,6 +16,12 @@ pub struct UnbondedItem {
     pub amount: Uint128,
     pub fee: Uint128,
     pub release_date: u64,
+    /// duration of unbonded position, restored by cancel unbond
+    #[serde(default)]
+    pub duration: u64,
+    /// lock time of unbonded position, restored by cancel unbond
+    #[serde(default)]
+    pub locked_at: u64,
 }
 
 // #[cw_serde]
@@ -83,8 +97,47 @@ pub enum ExecuteMsg {
         locked_at: u64,
         period: u64,
     },
+    /// stake unbonded item back to the position it was unbonded from with fee refunded
+    CancelUnbond {
+        index: u32,
+    },
     Withdraw {
         recipient: Option<String>,
+        /// minimum ASTRO amount to receive
+        min_astro_out: Option<Uint128>,
+    },
+    /// unlock timelocked position before lock end paying early unlock penalty
+    EarlyUnlock {
+        duration: u64,
+        locked_at: u64,
+        /// partial amount is only for allowed users, whole position if not set
+        amount: Option<Uint128>,
+    },
+    /// move staking position to another address keeping lock time
+    TransferPosition {
+        duration: u64,
+        locked_at: u64,
+        amount: Option<Uint128>,
+        recipient: String,
+    },
+    /// merge positions of same duration into one with the latest end time
+    AggregatePositions {
+        duration: u64,
+        locked_at_list: Vec<u64>,
+        target_duration: u64,
+    },
+    /// process pending days of reward weights and total staking in chunks, anyone can call
+    Checkpoint {
+        max_days: u64,
+    },
+    /// restake eclipASTRO rewards of duration into positions instead of sending them
+    SetAutoCompound {
+        duration: u64,
+        enabled: bool,
+    },
+    /// restake pending eclipASTRO rewards of users who enabled auto compound, anyone can call
+    Compound {
+        users: Vec<String>,
     },
     /// update locking period from short one to long one
     Restake {
@@ -106,8 +159,36 @@ pub enum ExecuteMsg {
         eclip: Uint128,
         beclip: Uint128,
     },
+    /// add reward schedule of native asset sent with message
+    AddAssetRewards {
+        from: Option<u64>,
+        duration: Option<u64>,
+    },
+    /// Receive hook used to add reward schedule of cw20 asset
+    Receive(Cw20ReceiveMsg),
     // claim blacklist rewards
     ClaimBlacklistRewards {},
+    /// settle user rewards and add user to blacklist, only owner
+    AddToBlacklist {
+        user: String,
+    },
+    /// settle rewards to blacklist and remove user from blacklist, only owner
+    RemoveFromBlacklist {
+        user: String,
+    },
+    /// save staking snapshots of users staked before snapshots were added, paginated by user, only owner
+    BackfillSnapshots {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    AddAssetRewards {
+        from: Option<u64>,
+        duration: Option<u64>,
+    },
 }
 
 #[cw_serde]
@@ -119,18 +200,29 @@ pub enum QueryMsg {
     /// query owner
     #[returns(Addr)]
     Owner {},
+    /// query pending ownership transfer proposal
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
     /// query total_staking
     #[returns(Uint128)]
     TotalStaking {},
     /// query total_staking_by_duration
     #[returns(Vec<StakingWithDuration>)]
     TotalStakingByDuration { timestamp: Option<u64> },
-    /// query user_staking
+    /// query user_staking, paginated by (duration, locked_at)
     #[returns(Vec<UserStaking>)]
-    Staking { user: String },
-    /// query unbonded user positions
+    Staking {
+        user: String,
+        start_after: Option<(u64, u64)>,
+        limit: Option<u32>,
+    },
+    /// query unbonded user positions, paginated by item index
     #[returns(Vec<UnbondedItem>)]
-    Unbonded { user: String },
+    Unbonded {
+        user: String,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
     /// query pending_rewards
     #[returns(UserReward)]
     Reward {
@@ -157,20 +249,37 @@ pub enum QueryMsg {
     #[returns(bool)]
     IsAllowed { user: String },
 
+    /// check if user restakes eclipASTRO rewards of duration
+    #[returns(bool)]
+    AutoCompound { user: String, duration: u64 },
+
     #[returns(Vec<(u64, Uint128)>)]
     EclipastroRewards {},
 
     #[returns(Vec<String>)]
-    Blacklist {},
+    Blacklist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     // rewards of blacklist
     #[returns(UserReward)]
     BlacklistRewards,
 
+    /// query reward schedule, paginated by (end_time, start_time)
     #[returns(Vec<((u64, u64), Reward)>)]
-    RewardSchedule { from: Option<u64> },
+    RewardSchedule {
+        from: Option<u64>,
+        start_after: Option<(u64, u64)>,
+        limit: Option<u32>,
+    },
 
+    /// query user rewards, paginated by (duration, locked_at)
     #[returns(Vec<(u64, u64, UserReward)>)]
-    RewardList { user: String },
+    RewardList {
+        user: String,
+        start_after: Option<(u64, u64)>,
+        limit: Option<u32>,
+    },
 
     #[returns(Uint128)]
     CalculatePenaltyAmount {
@@ -178,6 +287,41 @@ pub enum QueryMsg {
         duration: u64,
         locked_at: Option<u64>,
     },
+
+    /// query total staked amount of user at timestamp
+    /// zero for timestamps before the user's snapshot was backfilled
+    #[returns(Uint128)]
+    StakingAt {
+        user: String,
+        timestamp: Option<u64>,
+    },
+
+    /// query total staking with reward multipliers at timestamp
+    #[returns(Uint256)]
+    TotalBoostedStakeAt { timestamp: Option<u64> },
+
+    /// query all addresses which have staking positions
+    #[returns(Vec<String>)]
+    AllStakers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// query unbonding periods and fee rates
+    #[returns(Vec<UnbondingTier>)]
+    UnbondingTiers {},
+
+    /// number of days which aren't processed by reward weights yet
+    #[returns(u64)]
+    PendingDays {},
+
+    /// query all staking positions, paginated by (user, duration, locked_at)
+    #[returns(AllPositionsResponse)]
+    AllPositions {
+        duration: Option<u64>,
+        start_after: Option<(String, u64, u64)>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -190,6 +334,7 @@ pub struct UpdateConfigMsg {
     pub eclip_staking: Option<String>,
     pub lockdrop: Option<String>,
     pub init_early_unlock_penalty: Option<Decimal>,
+    pub unbonding_tiers: Option<Vec<UnbondingTier>>,
 }
 
 #[cw_serde]
@@ -247,6 +392,45 @@ pub struct Config {
 pub struct Reward {
     pub eclip: Uint128,
     pub beclip: Uint128,
+    /// rewards of other assets
+    #[serde(default)]
+    pub assets: Vec<RewardAmount>,
+}
+
+#[cw_serde]
+pub struct RewardWeight {
+    pub info: AssetInfo,
+    pub reward_weight: Decimal256,
+}
+
+impl AssetAmount for RewardWeight {
+    type Amount = Decimal256;
+
+    fn info(&self) -> &AssetInfo {
+        &self.info
+    }
+
+    fn amount_mut(&mut self) -> &mut Decimal256 {
+        &mut self.reward_weight
+    }
+}
+
+#[cw_serde]
+pub struct RewardAmount {
+    pub info: AssetInfo,
+    pub amount: Uint128,
+}
+
+impl AssetAmount for RewardAmount {
+    type Amount = Uint128;
+
+    fn info(&self) -> &AssetInfo {
+        &self.info
+    }
+
+    fn amount_mut(&mut self) -> &mut Uint128 {
+        &mut self.amount
+    }
 }
 
 #[cw_serde]
@@ -278,6 +462,20 @@ pub struct UserReward {
     pub eclipastro: Uint128,
     pub beclip: Uint128,
     pub eclip: Uint128,
+    /// rewards of other assets
+    #[serde(default)]
+    pub assets: Vec<RewardAmount>,
+}
+
+impl UserReward {
+    pub fn add(&mut self, other: &UserReward) {
+        self.eclipastro += other.eclipastro;
+        self.beclip += other.beclip;
+        self.eclip += other.eclip;
+        for reward in other.assets.iter() {
+            add_asset(&mut self.assets, reward.clone());
+        }
+    }
 }
 
 #[cw_serde]
@@ -304,6 +502,21 @@ pub struct UserStakingByDuration {
     pub locked_at: u64,
 }
 
+#[cw_serde]
+pub struct StakingPosition {
+    pub user: String,
+    pub duration: u64,
+    pub locked_at: u64,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct AllPositionsResponse {
+    pub positions: Vec<StakingPosition>,
+    /// last scanned key, start_after of the next page
+    pub last_key: Option<(String, u64, u64)>,
+}
+
 #[cw_serde]
 pub struct RestakeData {
     pub from_duration: u64,
@@ -326,12 +539,3 @@ pub struct AstroStaking {
     pub total_shares: Uint128,
     pub total_deposit: Uint128,
 }
-
-/// This structure describes the parameters used for creating a request for a change of contract ownership.
-#[cw_serde]
-pub struct OwnershipProposal {
-    /// The newly proposed contract owner
-    pub owner: Addr,
-    /// Time until the proposal to change ownership expires
-    pub ttl: u64,
-}
@@ -1,20 +1,95 @@
-use std::{collections::HashSet, hash::Hash};
+use std::{collections::HashSet, hash::Hash, ops::AddAssign};
 
-use cosmwasm_std::{StdError, StdResult};
+use astroport::asset::{Asset, AssetInfo};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, StdError, StdResult, Uint128};
 
 pub const SECONDS_PER_DAY: u64 = 24 * 3_600;
+/// default unbonding periods, used until owner updates unbonding tiers
 pub const UNBONDING_PERIOD_0: u64 = 14 * SECONDS_PER_DAY;
 pub const UNBONDING_PERIOD_1: u64 = 28 * SECONDS_PER_DAY;
 pub const UNBONDING_FEE_RATE: &str = "0.05";
 
-pub fn check_unbonding_period(unbonding_period: u64, err: impl ToString) -> StdResult<()> {
-    if ![UNBONDING_PERIOD_0, UNBONDING_PERIOD_1].contains(&unbonding_period) {
+/// unbonding period and fee rate charged on unbonded amount
+#[cw_serde]
+pub struct UnbondingTier {
+    pub period: u64,
+    pub fee_rate: Decimal,
+}
+
+pub fn default_unbonding_tiers() -> Vec<UnbondingTier> {
+    vec![
+        UnbondingTier {
+            period: UNBONDING_PERIOD_0,
+            fee_rate: UNBONDING_FEE_RATE.parse().unwrap(),
+        },
+        UnbondingTier {
+            period: UNBONDING_PERIOD_1,
+            fee_rate: Decimal::zero(),
+        },
+    ]
+}
+
+/// find tier of unbonding period
+pub fn get_unbonding_tier(
+    tiers: &[UnbondingTier],
+    unbonding_period: u64,
+    err: impl ToString,
+) -> StdResult<UnbondingTier> {
+    tiers
+        .iter()
+        .find(|t| t.period == unbonding_period)
+        .cloned()
+        .ok_or(StdError::generic_err(err.to_string()))
+}
+
+/// tiers must have unique periods and fee rates lower than 100%
+pub fn check_unbonding_tiers(tiers: &[UnbondingTier], err: impl ToString) -> StdResult<()> {
+    if tiers.is_empty()
+        || !has_unique_elements(tiers.iter().map(|t| t.period))
+        || tiers.iter().any(|t| t.fee_rate >= Decimal::one())
+    {
         Err(StdError::generic_err(err.to_string()))?;
     }
 
     Ok(())
 }
 
+/// amount of asset kept in lists of rewards, reward weights and fees
+pub trait AssetAmount {
+    type Amount: AddAssign + Copy;
+
+    fn info(&self) -> &AssetInfo;
+
+    fn amount_mut(&mut self) -> &mut Self::Amount;
+
+    /// items of the same key are merged, asset is the key by default
+    fn same_key(&self, other: &Self) -> bool {
+        self.info().equal(other.info())
+    }
+}
+
+impl AssetAmount for Asset {
+    type Amount = Uint128;
+
+    fn info(&self) -> &AssetInfo {
+        &self.info
+    }
+
+    fn amount_mut(&mut self) -> &mut Uint128 {
+        &mut self.amount
+    }
+}
+
+/// add amount to the item of the same key in the list, push the item if there is no such item
+pub fn add_asset<T: AssetAmount>(list: &mut Vec<T>, mut item: T) {
+    let amount = *item.amount_mut();
+    match list.iter_mut().find(|x| x.same_key(&item)) {
+        Some(x) => *x.amount_mut() += amount,
+        None => list.push(item),
+    }
+}
+
 pub fn has_unique_elements<T>(iter: T) -> bool
 where
     T: IntoIterator,
@@ -23,3 +98,17 @@ where
     let mut uniq = HashSet::new();
     iter.into_iter().all(move |x| uniq.insert(x))
 }
+
+/// page of address list sorted in ascending order, starting after `start_after`
+pub fn paginate_addresses(
+    mut addresses: Vec<String>,
+    start_after: Option<String>,
+    limit: usize,
+) -> Vec<String> {
+    addresses.sort();
+    addresses
+        .into_iter()
+        .filter(|x| start_after.as_ref().map_or(true, |s| x > s))
+        .take(limit)
+        .collect()
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 pub mod lockdrop;
 #[cfg(test)]
+pub mod lp_depositor;
+#[cfg(test)]
 pub mod lp_stake;
 #[cfg(test)]
 pub mod single_sided_stake;
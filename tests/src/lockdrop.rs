@@ -2,7 +2,7 @@ use astroport::{
     asset::{Asset, AssetInfo},
     vesting::{VestingAccount, VestingSchedule, VestingSchedulePoint},
 };
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{coin, Addr, Uint128};
 use cw_controllers::AdminError;
 use eclipse_base::converters::str_to_dec;
 use equinox_msg::lockdrop::{
@@ -12,7 +12,7 @@ use equinox_msg::lockdrop::{
 use lockdrop::error::ContractError;
 // use single_sided_staking::error::ContractError as SingleSidedStakingError;
 
-use crate::suite::{Suite, SuiteBuilder, ALICE, CAROL, TREASURY};
+use crate::suite::{Suite, SuiteBuilder, ALICE, BOB, CAROL, TREASURY};
 
 const ONE_MONTH: u64 = 86400 * 30;
 const THREE_MONTH: u64 = 86400 * 30 * 3;
@@ -946,6 +946,9 @@ fn stake_assets_to_vaults() {
                 dao_treasury_address: Some(suite.treasury()),
                 eclip_staking: None,
                 init_early_unlock_penalty: Some(str_to_dec("0.8")),
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
             },
         )
         .unwrap();
@@ -1277,6 +1280,9 @@ fn single_sided_incentives_distribution() {
                 dao_treasury_address: Some(suite.treasury()),
                 eclip_staking: None,
                 init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
             },
         )
         .unwrap();
@@ -1469,6 +1475,9 @@ fn lp_incentives_distribution() {
                 dao_treasury_address: Some(suite.treasury()),
                 eclip_staking: None,
                 init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
             },
         )
         .unwrap();
@@ -1749,6 +1758,9 @@ fn restake_and_unlock() {
                 dao_treasury_address: Some(suite.treasury()),
                 eclip_staking: None,
                 init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
             },
         )
         .unwrap();
@@ -1970,6 +1982,8 @@ fn restake_and_unlock() {
                 funding_dao: None,
                 eclip: None,
                 beclip: None,
+                unbonding_tiers: None,
+                timelock_config: None,
             },
         )
         .unwrap();
@@ -2124,6 +2138,9 @@ fn blacklist() {
                 dao_treasury_address: Some(suite.treasury()),
                 eclip_staking: None,
                 init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
             },
         )
         .unwrap();
@@ -2166,6 +2183,7 @@ fn blacklist() {
             eclip: Uint128::from(3412358016u128),
             astro: Uint128::from(11059196u128),
             beclip: Uint128::from(2293006164u128),
+            assets: vec![],
         }
     );
     suite.lockdrop_blacklist_rewards_claim().unwrap();
@@ -2182,3 +2200,1040 @@ fn blacklist() {
         0
     );
 }
+
+#[test]
+fn update_blacklist() {
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+    let admin = suite.admin();
+
+    suite
+        .single_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+    suite
+        .lp_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+
+    let err = suite.lockdrop_add_to_blacklist(ALICE, BOB).unwrap_err();
+    assert_eq!(
+        ContractError::Admin(AdminError::NotAdmin {}),
+        err.downcast().unwrap()
+    );
+    let err = suite.lockdrop_add_to_blacklist(&admin, CAROL).unwrap_err();
+    assert_eq!(
+        ContractError::AlreadyBlacklisted(CAROL.to_string()),
+        err.downcast().unwrap()
+    );
+    let err = suite
+        .lockdrop_remove_from_blacklist(&admin, BOB)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NotBlacklisted(BOB.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // withdraw window finished
+    suite.update_time(86400u64 * 7);
+    suite
+        .update_lockdrop_config(
+            &admin,
+            LockdropUpdateConfigMsg {
+                single_sided_staking: Some(suite.single_staking_contract()),
+                lp_staking: Some(suite.lp_staking_contract()),
+                liquidity_pool: Some(suite.eclipastro_xastro_lp_contract()),
+                eclipastro_token: Some(suite.eclipastro()),
+                voter: Some(suite.voter_contract()),
+                dao_treasury_address: Some(suite.treasury()),
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+    suite.lockdrop_stake_to_vaults(&admin).unwrap();
+    suite
+        .add_single_sided_vault_reward(&admin, None, None, 12_800_000_000u128, 8_600_000_000u128)
+        .unwrap();
+    suite
+        .add_lp_vault_reward(&admin, None, None, 12_800_000_000u128, 8_600_000_000u128)
+        .unwrap();
+    suite.update_time(86400u64);
+
+    // rewards accrued before blacklisting are sent to user
+    let blacklist_rewards_before = suite.query_lockdrop_blacklist_rewards().unwrap();
+    let alice_eclip_before = suite.query_balance_native(ALICE, suite.eclip()).unwrap();
+    suite.lockdrop_add_to_blacklist(&admin, ALICE).unwrap();
+    let alice_eclip = suite.query_balance_native(ALICE, suite.eclip()).unwrap();
+    assert!(alice_eclip > alice_eclip_before);
+    assert_eq!(
+        suite.query_lockdrop_blacklist_rewards().unwrap(),
+        blacklist_rewards_before
+    );
+
+    // rewards accrued while blacklisted go to blacklist rewards
+    suite.update_time(86400u64);
+    let err = suite
+        .single_lockdrop_claim_rewards(ALICE, 0, None)
+        .unwrap_err();
+    assert_eq!(ContractError::Blacklisted {}, err.downcast().unwrap());
+    let err = suite.lp_lockdrop_claim_rewards(ALICE, 0, None).unwrap_err();
+    assert_eq!(ContractError::Blacklisted {}, err.downcast().unwrap());
+    let blacklist_rewards = suite.query_lockdrop_blacklist_rewards().unwrap();
+    assert!(blacklist_rewards.eclip > blacklist_rewards_before.eclip);
+
+    suite.lockdrop_remove_from_blacklist(&admin, ALICE).unwrap();
+    assert_eq!(
+        suite.query_balance_native(ALICE, suite.eclip()).unwrap(),
+        alice_eclip
+    );
+    let blacklist_rewards = suite.query_lockdrop_blacklist_rewards().unwrap();
+    assert!(blacklist_rewards.eclip > blacklist_rewards_before.eclip);
+
+    // rewards accrued after removal are sent to user again
+    suite.update_time(86400u64);
+    assert_eq!(
+        suite.query_lockdrop_blacklist_rewards().unwrap(),
+        blacklist_rewards
+    );
+    suite.single_lockdrop_claim_rewards(ALICE, 0, None).unwrap();
+    suite.lp_lockdrop_claim_rewards(ALICE, 0, None).unwrap();
+    assert!(suite.query_balance_native(ALICE, suite.eclip()).unwrap() > alice_eclip);
+}
+
+#[test]
+fn referral_incentives() {
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: Some(10_001),
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidReferralBonus(10_001),
+        err.downcast().unwrap()
+    );
+    // referrers get 10% weight of referred users
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: Some(1_000),
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+
+    let err = suite
+        .lockdrop_increase_lockup(
+            ALICE,
+            StakeType::SingleStaking,
+            0,
+            None,
+            Some(ALICE),
+            coin(1_000, suite.astro()),
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidReferrer {}, err.downcast().unwrap());
+
+    suite
+        .lockdrop_increase_lockup(
+            ALICE,
+            StakeType::SingleStaking,
+            0,
+            None,
+            Some(BOB),
+            coin(1_000, suite.astro()),
+        )
+        .unwrap();
+    suite
+        .lockdrop_increase_lockup(
+            ALICE,
+            StakeType::LpStaking,
+            0,
+            None,
+            None,
+            coin(1_000, suite.astro()),
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_lockdrop_referrer(ALICE).unwrap(),
+        Some(BOB.to_string())
+    );
+    let err = suite
+        .lockdrop_increase_lockup(
+            ALICE,
+            StakeType::SingleStaking,
+            0,
+            None,
+            Some(CAROL),
+            coin(1_000, suite.astro()),
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ReferrerAlreadySet(BOB.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // deposit on behalf of bob
+    suite
+        .lockdrop_increase_lockup(
+            ALICE,
+            StakeType::SingleStaking,
+            0,
+            Some(BOB),
+            None,
+            coin(1_000, suite.astro()),
+        )
+        .unwrap();
+    // depositor can't link recipient to a referrer
+    let err = suite
+        .lockdrop_increase_lockup(
+            ALICE,
+            StakeType::SingleStaking,
+            0,
+            Some(BOB),
+            Some(ALICE),
+            coin(1, suite.astro()),
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ReferrerNotAllowed {},
+        err.downcast().unwrap()
+    );
+    assert_eq!(suite.query_lockdrop_referrer(BOB).unwrap(), None);
+    assert!(suite
+        .query_lockdrop_referral_info(ALICE)
+        .unwrap()
+        .iter()
+        .all(|i| i.weighted_xastro.is_zero()));
+    let alice_lockup = suite.query_user_single_lockup_info(ALICE).unwrap()[0].clone();
+    let bob_lockup = suite.query_user_single_lockup_info(BOB).unwrap()[0].clone();
+    assert!(!bob_lockup.xastro_amount_in_lockups.is_zero());
+    let referral_info = suite.query_lockdrop_referral_info(BOB).unwrap();
+    assert_eq!(
+        referral_info[0].weighted_xastro,
+        alice_lockup.xastro_amount_in_lockups
+    );
+    assert_eq!(referral_info[1].weighted_xastro, Uint128::zero());
+
+    suite
+        .fund_eclip(
+            &suite.admin(),
+            2_000_000u128,
+            vec![
+                IncentiveRewards {
+                    stake_type: StakeType::SingleStaking,
+                    beclip: Uint128::from(300_000u128),
+                    eclip: Uint128::from(300_000u128),
+                },
+                IncentiveRewards {
+                    stake_type: StakeType::LpStaking,
+                    beclip: Uint128::from(700_000u128),
+                    eclip: Uint128::from(700_000u128),
+                },
+            ],
+        )
+        .unwrap();
+    // withdraw window finished
+    suite.update_time(86400u64 * 7);
+
+    let err = suite.lockdrop_claim_referral_rewards(BOB).unwrap_err();
+    assert_eq!(
+        ContractError::ClaimRewardNotAllowed {},
+        err.downcast().unwrap()
+    );
+
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: Some(suite.single_staking_contract()),
+                lp_staking: Some(suite.lp_staking_contract()),
+                liquidity_pool: Some(suite.eclipastro_xastro_lp_contract()),
+                eclipastro_token: Some(suite.eclipastro()),
+                voter: Some(suite.voter_contract()),
+                dao_treasury_address: Some(suite.treasury()),
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+    suite.lockdrop_stake_to_vaults(&suite.admin()).unwrap();
+
+    // referral weight is 10% of alice weight
+    let weight = alice_lockup.xastro_amount_in_lockups;
+    let referral_weight = weight.multiply_ratio(1_000u128, 10_000u128);
+    let expected_incentive = referral_weight.multiply_ratio(
+        300_000u128,
+        weight + bob_lockup.xastro_amount_in_lockups + referral_weight,
+    );
+    let referral_info = suite.query_lockdrop_referral_info(BOB).unwrap();
+    assert_eq!(
+        referral_info[0].lockdrop_incentives.eclip.allocated,
+        expected_incentive
+    );
+    assert_eq!(referral_info[0].claimable.eclip, expected_incentive);
+
+    let prev_bob_beclip_balance = suite.query_beclip_balance(BOB).unwrap();
+    let prev_bob_eclip_balance = suite
+        .query_balance_native(BOB.to_string(), suite.eclip())
+        .unwrap();
+    suite.lockdrop_claim_referral_rewards(BOB).unwrap();
+    let bob_beclip_balance = suite.query_beclip_balance(BOB).unwrap();
+    let bob_eclip_balance = suite
+        .query_balance_native(BOB.to_string(), suite.eclip())
+        .unwrap();
+    assert_eq!(
+        bob_beclip_balance - prev_bob_beclip_balance,
+        expected_incentive.u128()
+    );
+    assert_eq!(
+        bob_eclip_balance - prev_bob_eclip_balance,
+        expected_incentive.u128()
+    );
+    let referral_info = suite.query_lockdrop_referral_info(BOB).unwrap();
+    assert_eq!(
+        referral_info[0].lockdrop_incentives.eclip.claimed,
+        expected_incentive
+    );
+    assert_eq!(referral_info[0].claimable.eclip, Uint128::zero());
+
+    // referral bonus is fixed after claims are allowed
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: Some(0),
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::LockdropEnded {}, err.downcast().unwrap());
+}
+
+#[test]
+fn lockdrop_incentives_vesting() {
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+
+    let err = suite
+        .update_lockdrop_reward_distribution_config(&suite.admin(), 10_001, 86400 * 10)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidInstantBps(10_001),
+        err.downcast().unwrap()
+    );
+
+    // 20% instant, rest vests linearly during 10 days
+    suite
+        .update_lockdrop_reward_distribution_config(&suite.admin(), 2_000, 86400 * 10)
+        .unwrap();
+
+    suite
+        .single_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+    suite
+        .lp_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+    suite
+        .fund_eclip(
+            &suite.admin(),
+            2_000_000u128,
+            vec![
+                IncentiveRewards {
+                    stake_type: StakeType::SingleStaking,
+                    beclip: Uint128::from(300_000u128),
+                    eclip: Uint128::from(300_000u128),
+                },
+                IncentiveRewards {
+                    stake_type: StakeType::LpStaking,
+                    beclip: Uint128::from(700_000u128),
+                    eclip: Uint128::from(700_000u128),
+                },
+            ],
+        )
+        .unwrap();
+
+    // nothing is vested during lockdrop
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    let vesting = user_info[0].lockdrop_incentives_vesting.eclip.clone();
+    assert_eq!(vesting.vested, Uint128::zero());
+    assert_eq!(
+        vesting.remaining,
+        user_info[0].lockdrop_incentives.eclip.allocated
+    );
+
+    // withdraw window finished
+    suite.update_time(86400u64 * 7);
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: Some(suite.single_staking_contract()),
+                lp_staking: Some(suite.lp_staking_contract()),
+                liquidity_pool: Some(suite.eclipastro_xastro_lp_contract()),
+                eclipastro_token: Some(suite.eclipastro()),
+                voter: Some(suite.voter_contract()),
+                dao_treasury_address: Some(suite.treasury()),
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+    suite.lockdrop_stake_to_vaults(&suite.admin()).unwrap();
+
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    let allocated = user_info[0].lockdrop_incentives.eclip.allocated;
+    let instant = allocated.multiply_ratio(2_000u128, 10_000u128);
+    let vesting = user_info[0].lockdrop_incentives_vesting.eclip.clone();
+    assert_eq!(vesting.vested, instant);
+    assert_eq!(vesting.claimable, instant);
+    assert_eq!(vesting.remaining, allocated - instant);
+
+    // half of vesting period
+    suite.update_time(86400u64 * 5);
+    let vested = instant + (allocated - instant).multiply_ratio(1u128, 2u128);
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    let vesting = user_info[0].lockdrop_incentives_vesting.eclip.clone();
+    assert_eq!(vesting.vested, vested);
+    assert_eq!(vesting.claimable, vested);
+    assert_eq!(vesting.remaining, allocated - vested);
+
+    suite.single_lockdrop_claim_rewards(ALICE, 0, None).unwrap();
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    assert_eq!(user_info[0].lockdrop_incentives.eclip.claimed, vested);
+    let vesting = user_info[0].lockdrop_incentives_vesting.eclip.clone();
+    assert_eq!(vesting.claimable, Uint128::zero());
+    assert_eq!(vesting.remaining, allocated - vested);
+
+    // vesting period finished
+    suite.update_time(86400u64 * 5);
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    let vesting = user_info[0].lockdrop_incentives_vesting.eclip.clone();
+    assert_eq!(vesting.vested, allocated);
+    assert_eq!(vesting.claimable, allocated - vested);
+    assert_eq!(vesting.remaining, Uint128::zero());
+}
+
+#[test]
+fn migrate_to_vault() {
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+
+    suite
+        .single_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, ONE_MONTH)
+        .unwrap();
+    suite
+        .lp_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+    suite
+        .lp_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, ONE_MONTH)
+        .unwrap();
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 1_000)
+        .unwrap();
+    suite
+        .lp_staking_increase_lockdrop(BOB, suite.astro(), 1_000u128, ONE_MONTH)
+        .unwrap();
+
+    // withdraw window finished
+    suite.update_time(86400u64 * 7);
+    let err = suite
+        .lockdrop_migrate_to_vault(ALICE, StakeType::SingleStaking, ONE_MONTH)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ClaimRewardNotAllowed {},
+        err.downcast().unwrap()
+    );
+
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: Some(suite.single_staking_contract()),
+                lp_staking: Some(suite.lp_staking_contract()),
+                liquidity_pool: Some(suite.eclipastro_xastro_lp_contract()),
+                eclipastro_token: Some(suite.eclipastro()),
+                voter: Some(suite.voter_contract()),
+                dao_treasury_address: Some(suite.treasury()),
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+    suite.lockdrop_stake_to_vaults(&suite.admin()).unwrap();
+    let cfg = suite.query_lockdrop_config().unwrap();
+
+    // single sided position keeps lock time
+    suite
+        .lockdrop_migrate_to_vault(ALICE, StakeType::SingleStaking, ONE_MONTH)
+        .unwrap();
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    assert_eq!(
+        user_info[0].eclipastro_withdrawed,
+        user_info[0].eclipastro_staked
+    );
+    let user_staking = suite.query_single_sided_staking(ALICE).unwrap();
+    assert_eq!(user_staking[0].duration, ONE_MONTH);
+    assert_eq!(user_staking[0].staking[0].locked_at, cfg.countdown_start_at);
+    assert_eq!(
+        user_staking[0].staking[0].amount,
+        user_info[0].eclipastro_staked
+    );
+    let err = suite
+        .lockdrop_migrate_to_vault(ALICE, StakeType::SingleStaking, ONE_MONTH)
+        .unwrap_err();
+    assert_eq!(ContractError::NotStaked {}, err.downcast().unwrap());
+
+    // timelocked lp position keeps duration and lock time
+    suite
+        .lockdrop_migrate_to_vault(ALICE, StakeType::LpStaking, ONE_MONTH)
+        .unwrap();
+    let lp_user_info = suite.query_user_lp_lockup_info(ALICE).unwrap();
+    let locked_info = lp_user_info
+        .iter()
+        .find(|i| i.duration == ONE_MONTH)
+        .unwrap();
+    assert_eq!(locked_info.lp_token_withdrawed, locked_info.lp_token_staked);
+    let locked_staking = suite.query_user_lp_token_locked_staking(ALICE).unwrap();
+    assert_eq!(locked_staking.len(), 1);
+    assert_eq!(locked_staking[0].duration, ONE_MONTH);
+    assert_eq!(locked_staking[0].locked_at, cfg.countdown_start_at);
+    assert_eq!(locked_staking[0].amount, locked_info.lp_token_staked);
+    assert!(!locked_staking[0].boost.is_zero());
+
+    suite
+        .lockdrop_migrate_to_vault(ALICE, StakeType::LpStaking, 0)
+        .unwrap();
+    let lp_user_info = suite.query_user_lp_lockup_info(ALICE).unwrap();
+    let flexible_info = lp_user_info.iter().find(|i| i.duration == 0).unwrap();
+    assert_eq!(
+        flexible_info.lp_token_withdrawed,
+        flexible_info.lp_token_staked
+    );
+    let lp_staking = suite.query_user_lp_token_staking(ALICE).unwrap();
+    assert_eq!(lp_staking.staked, flexible_info.lp_token_staked);
+
+    // lp position is moved to flexible staking after lock end
+    suite.update_time(ONE_MONTH + 86400u64);
+    suite
+        .lockdrop_migrate_to_vault(BOB, StakeType::LpStaking, ONE_MONTH)
+        .unwrap();
+    let lp_user_info = suite.query_user_lp_lockup_info(BOB).unwrap();
+    assert_eq!(
+        lp_user_info[0].lp_token_withdrawed,
+        lp_user_info[0].lp_token_staked
+    );
+    assert!(suite
+        .query_user_lp_token_locked_staking(BOB)
+        .unwrap()
+        .is_empty());
+    let lp_staking = suite.query_user_lp_token_staking(BOB).unwrap();
+    assert_eq!(lp_staking.staked, lp_user_info[0].lp_token_staked);
+}
+
+#[test]
+fn extend_lp_lockup_after_lockdrop() {
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+
+    suite
+        .lp_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+
+    suite.update_time(86400u64 * 7);
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: Some(suite.single_staking_contract()),
+                lp_staking: Some(suite.lp_staking_contract()),
+                liquidity_pool: Some(suite.eclipastro_xastro_lp_contract()),
+                eclipastro_token: Some(suite.eclipastro()),
+                voter: Some(suite.voter_contract()),
+                dao_treasury_address: Some(suite.treasury()),
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+    suite.lockdrop_stake_to_vaults(&suite.admin()).unwrap();
+    suite
+        .lp_staking_update_config(
+            &suite.admin(),
+            equinox_msg::lp_staking::UpdateConfigMsg {
+                lp_token: None,
+                lp_contract: None,
+                lockdrop: None,
+                astroport_incentives: None,
+                treasury: None,
+                funding_dao: None,
+                eclip: None,
+                beclip: None,
+                unbonding_tiers: None,
+                timelock_config: Some(vec![equinox_msg::single_sided_staking::TimeLockConfig {
+                    duration: THREE_MONTH,
+                    reward_multiplier: 20_000,
+                }]),
+            },
+        )
+        .unwrap();
+
+    // only lp token can be added after lockdrop
+    let err = suite
+        .lp_lockup_extend_duration_with_deposit(ALICE, suite.astro(), 500u128, 0, THREE_MONTH)
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidAsset {}, err.downcast().unwrap());
+
+    // lockdrop duration which isn't a timelock of lp vault
+    let err = suite
+        .lp_lockup_extend_duration_without_deposit(ALICE, 0, SIX_MONTH)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidVaultDuration(SIX_MONTH),
+        err.downcast().unwrap()
+    );
+
+    suite
+        .lp_lockup_extend_duration_without_deposit(ALICE, 0, THREE_MONTH)
+        .unwrap();
+    let user_info = suite.query_user_lp_lockup_info(ALICE).unwrap();
+    let flexible_info = user_info.iter().find(|i| i.duration == 0).unwrap();
+    assert_eq!(
+        flexible_info.lp_token_withdrawed,
+        flexible_info.lp_token_staked
+    );
+    let locked_staking = suite.query_user_lp_token_locked_staking(ALICE).unwrap();
+    assert_eq!(locked_staking.len(), 1);
+    assert_eq!(locked_staking[0].duration, THREE_MONTH);
+    assert_eq!(locked_staking[0].locked_at, suite.get_time());
+    assert_eq!(locked_staking[0].amount, flexible_info.lp_token_staked);
+    let lp_lockup_info = suite.query_lp_lockup_info().unwrap();
+    let flexible_lockup = lp_lockup_info
+        .lp_lockups
+        .iter()
+        .find(|l| l.duration == 0)
+        .unwrap();
+    assert_eq!(
+        flexible_lockup.total_lp_withdrawed,
+        flexible_lockup.total_lp_staked
+    );
+
+    let err = suite
+        .lp_lockup_extend_duration_without_deposit(ALICE, 0, THREE_MONTH)
+        .unwrap_err();
+    assert_eq!(ContractError::NotStaked {}, err.downcast().unwrap());
+}
+
+#[test]
+fn accepted_astro_assets() {
+    const IBC_ASTRO: &str = "ibc/astro";
+
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+    suite
+        .mint_native(ALICE.to_string(), IBC_ASTRO.to_string(), 1_000)
+        .unwrap();
+
+    let err = suite
+        .single_staking_increase_lockdrop(ALICE, IBC_ASTRO.to_string(), 1_000u128, 0)
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidAsset {}, err.downcast().unwrap());
+
+    // native ASTRO and xASTRO are always accepted
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: Some(vec![AssetInfo::NativeToken {
+                    denom: suite.astro(),
+                }]),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidAsset {}, err.downcast().unwrap());
+
+    // accepted ASTRO can't be converted without ASTRO converter
+    let ibc_astro_info = AssetInfo::NativeToken {
+        denom: IBC_ASTRO.to_string(),
+    };
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: Some(vec![ibc_astro_info.clone()]),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::AstroConverterNotSet {},
+        err.downcast().unwrap()
+    );
+
+    // ASTRO converter converts only its own old ASTRO asset
+    let cw20_astro = suite
+        .instantiate_cw20_token("ASTRO", vec![(BOB, 1_000)])
+        .unwrap();
+    let cw20_astro_info = AssetInfo::Token {
+        contract_addr: cw20_astro.clone(),
+    };
+    let cw20_astro_converter = suite
+        .instantiate_astro_converter(cw20_astro_info.clone(), 1_000)
+        .unwrap();
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: Some(cw20_astro_converter.to_string()),
+                accepted_astro_assets: Some(vec![ibc_astro_info.clone()]),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::AstroConverterMismatch {},
+        err.downcast().unwrap()
+    );
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: Some(cw20_astro_converter.to_string()),
+                accepted_astro_assets: Some(vec![ibc_astro_info.clone(), cw20_astro_info.clone()]),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::AstroConverterMismatch {},
+        err.downcast().unwrap()
+    );
+
+    let ibc_astro_converter = suite
+        .instantiate_astro_converter(ibc_astro_info.clone(), 1_000)
+        .unwrap();
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: Some(ibc_astro_converter.to_string()),
+                accepted_astro_assets: Some(vec![ibc_astro_info.clone()]),
+            },
+        )
+        .unwrap();
+    let cfg = suite.query_lockdrop_config().unwrap();
+    assert_eq!(cfg.astro_converter, Some(ibc_astro_converter.clone()));
+    assert_eq!(cfg.accepted_astro_assets, vec![ibc_astro_info]);
+
+    // IBC ASTRO is converted to native ASTRO and staked
+    suite
+        .single_staking_increase_lockdrop(ALICE, IBC_ASTRO.to_string(), 1_000u128, 0)
+        .unwrap();
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    assert_eq!(user_info[0].xastro_amount_in_lockups.u128(), 909);
+    assert_eq!(
+        suite
+            .query_balance_native(ibc_astro_converter.to_string(), IBC_ASTRO.to_string())
+            .unwrap(),
+        1_000
+    );
+    assert_eq!(
+        suite
+            .query_balance_native(ibc_astro_converter.to_string(), suite.astro())
+            .unwrap(),
+        0
+    );
+
+    // changing ASTRO converter requires matching accepted ASTRO assets
+    let err = suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: Some(cw20_astro_converter.to_string()),
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::AstroConverterMismatch {},
+        err.downcast().unwrap()
+    );
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: None,
+                lp_staking: None,
+                liquidity_pool: None,
+                eclipastro_token: None,
+                voter: None,
+                dao_treasury_address: None,
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: Some(cw20_astro_converter.to_string()),
+                accepted_astro_assets: Some(vec![cw20_astro_info]),
+            },
+        )
+        .unwrap();
+
+    // IBC ASTRO isn't accepted anymore
+    suite
+        .mint_native(ALICE.to_string(), IBC_ASTRO.to_string(), 1_000)
+        .unwrap();
+    let err = suite
+        .single_staking_increase_lockdrop(ALICE, IBC_ASTRO.to_string(), 1_000u128, 0)
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidAsset {}, err.downcast().unwrap());
+
+    // cw20 ASTRO is converted to native ASTRO and staked
+    suite
+        .increase_lockdrop_cw20(
+            BOB,
+            cw20_astro.clone(),
+            1_000u128,
+            StakeType::SingleStaking,
+            0,
+        )
+        .unwrap();
+    let user_info = suite.query_user_single_lockup_info(BOB).unwrap();
+    assert_eq!(user_info[0].xastro_amount_in_lockups.u128(), 909);
+    assert_eq!(
+        suite
+            .query_cw20_balance(&cw20_astro, cw20_astro_converter.as_str())
+            .unwrap(),
+        1_000
+    );
+    assert_eq!(
+        suite
+            .query_balance_native(cw20_astro_converter.to_string(), suite.astro())
+            .unwrap(),
+        0
+    );
+
+    // native ASTRO deposit is unchanged
+    suite
+        .single_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    assert_eq!(user_info[0].xastro_amount_in_lockups.u128(), 1_818);
+}
+
+#[test]
+fn single_sided_asset_rewards() {
+    let mut suite = instantiate();
+    suite.update_time(86400u64 * 2);
+    let partner_denom = "upartner";
+    let partner_info = AssetInfo::NativeToken {
+        denom: partner_denom.to_string(),
+    };
+
+    suite
+        .single_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+    suite
+        .lp_staking_increase_lockdrop(ALICE, suite.astro(), 1_000u128, 0)
+        .unwrap();
+
+    // withdraw window finished
+    suite.update_time(86400u64 * 7);
+    suite
+        .update_lockdrop_config(
+            &suite.admin(),
+            LockdropUpdateConfigMsg {
+                single_sided_staking: Some(suite.single_staking_contract()),
+                lp_staking: Some(suite.lp_staking_contract()),
+                liquidity_pool: Some(suite.eclipastro_xastro_lp_contract()),
+                eclipastro_token: Some(suite.eclipastro()),
+                voter: Some(suite.voter_contract()),
+                dao_treasury_address: Some(suite.treasury()),
+                eclip_staking: None,
+                init_early_unlock_penalty: None,
+                referral_bonus: None,
+                astro_converter: None,
+                accepted_astro_assets: None,
+            },
+        )
+        .unwrap();
+    suite.lockdrop_stake_to_vaults(&suite.admin()).unwrap();
+
+    suite
+        .mint_native(suite.admin(), partner_denom.to_string(), 1_000_000)
+        .unwrap();
+    suite
+        .add_single_sided_vault_asset_reward(
+            &suite.admin(),
+            None,
+            Some(86400u64 * 10),
+            1_000_000,
+            partner_denom,
+        )
+        .unwrap();
+
+    suite.update_time(86400u64 * 5);
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    let partner_reward = user_info[0]
+        .staking_rewards
+        .iter()
+        .find(|r| r.info == partner_info)
+        .unwrap()
+        .amount
+        .u128();
+    assert!(partner_reward > 0);
+    assert!(partner_reward <= 500_000);
+
+    // claiming other assets keeps partner rewards unclaimed
+    suite
+        .single_lockdrop_claim_rewards(
+            ALICE,
+            0,
+            Some(vec![AssetInfo::NativeToken {
+                denom: suite.eclip(),
+            }]),
+        )
+        .unwrap();
+    assert_eq!(
+        suite
+            .query_balance_native(ALICE.to_string(), partner_denom.to_string())
+            .unwrap(),
+        0
+    );
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    assert_eq!(
+        user_info[0]
+            .staking_rewards
+            .iter()
+            .find(|r| r.info == partner_info)
+            .unwrap()
+            .amount
+            .u128(),
+        partner_reward
+    );
+
+    suite
+        .single_lockdrop_claim_rewards(ALICE, 0, Some(vec![partner_info.clone()]))
+        .unwrap();
+    assert_eq!(
+        suite
+            .query_balance_native(ALICE.to_string(), partner_denom.to_string())
+            .unwrap(),
+        partner_reward
+    );
+    let user_info = suite.query_user_single_lockup_info(ALICE).unwrap();
+    assert!(!user_info[0]
+        .staking_rewards
+        .iter()
+        .any(|r| r.info == partner_info));
+
+    // rest of schedule is paid through claim all
+    suite.update_time(86400u64 * 10);
+    suite
+        .single_lockdrop_claim_all_rewards(ALICE, None)
+        .unwrap();
+    let balance = suite
+        .query_balance_native(ALICE.to_string(), partner_denom.to_string())
+        .unwrap();
+    assert!(balance > partner_reward);
+    assert!(balance <= 1_000_000);
+}
@@ -0,0 +1,292 @@
+use astroport::{
+    asset::{Asset, AssetInfo},
+    vesting::{VestingAccount, VestingSchedule, VestingSchedulePoint},
+};
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_controllers::AdminError;
+use equinox_msg::lp_depositor::UpdateConfigMsg as LpDepositorUpdateConfigMsg;
+use lp_depositor::ContractError;
+
+use crate::suite::{Suite, SuiteBuilder, ALICE, BOB};
+
+fn instantiate() -> Suite {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    suite
+        .mint_native(ALICE.to_string(), suite.astro(), 10_000_000)
+        .unwrap();
+
+    // ready ASTRO staking pool
+    suite.stake_astro(ALICE, 1_000_000).unwrap();
+
+    // change ASTRO/xASTRO rate 1.1:1
+    suite
+        .mint_native(suite.astro_staking_contract(), suite.astro(), 100_000)
+        .unwrap();
+
+    // ready ASTRO staking pool
+    suite.stake_astro(ALICE, 100_000).unwrap();
+
+    suite.convert_astro(ALICE, 1_100_000).unwrap();
+
+    // provide liquidity
+    suite
+        .provide_liquidity(
+            ALICE,
+            Addr::unchecked(suite.eclipastro_xastro_lp_contract()),
+            vec![
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.eclipastro(),
+                    },
+                    amount: Uint128::from(1_100_000u128),
+                },
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.xastro(),
+                    },
+                    amount: Uint128::from(1_000_000u128),
+                },
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+    // setup ASTRO incentives
+    suite
+        .setup_pools(
+            &suite.admin(),
+            vec![(suite.eclipastro_xastro_lp_token(), Uint128::from(100u128))],
+        )
+        .unwrap();
+
+    suite
+        .incentives_set_tokens_per_second(&suite.admin(), 10u128)
+        .unwrap();
+
+    let start_time = suite.get_time();
+    let end_time = suite.get_time() + 86400 * 1000;
+    suite
+        .register_vesting_accounts(
+            &suite.admin(),
+            vec![VestingAccount {
+                address: suite.astroport_incentives(),
+                schedules: vec![VestingSchedule {
+                    start_point: VestingSchedulePoint {
+                        time: start_time,
+                        amount: Uint128::zero(),
+                    },
+                    end_point: Some(VestingSchedulePoint {
+                        time: end_time,
+                        amount: Uint128::from(1_000_000_000u128),
+                    }),
+                }],
+            }],
+            1_000_000_000u128,
+        )
+        .unwrap();
+    suite
+}
+
+/// lp depositor keeps no assets after a zap
+fn assert_no_depositor_balance(suite: &mut Suite) {
+    let depositor = suite.lp_depositor_contract();
+    for denom in [
+        suite.astro(),
+        suite.xastro(),
+        suite.eclipastro(),
+        suite.eclipastro_xastro_lp_token(),
+    ] {
+        assert_eq!(
+            suite
+                .query_balance_native(depositor.clone(), denom)
+                .unwrap(),
+            0
+        );
+    }
+}
+
+#[test]
+fn convert_and_stake() {
+    let mut suite = instantiate();
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 20_000)
+        .unwrap();
+
+    let simulation = suite
+        .query_lp_depositor_simulate(vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: suite.astro(),
+            },
+            amount: Uint128::new(10_000),
+        }])
+        .unwrap();
+    assert!(!simulation.lp_amount.is_zero());
+
+    // provide fails if pool mints less lp tokens than min_lp_out
+    let min_lp_out = simulation.lp_amount.u128() * 2;
+    for stake in [true, false] {
+        suite
+            .lp_depositor_convert(
+                BOB,
+                &[coin(10_000, suite.astro())],
+                stake,
+                Some(min_lp_out),
+                None,
+            )
+            .unwrap_err();
+    }
+    assert_eq!(
+        suite.query_balance_native(BOB, suite.astro()).unwrap(),
+        20_000
+    );
+    assert_eq!(suite.query_lp_token_balance(BOB).unwrap().u128(), 0);
+
+    // lp tokens are staked on behalf of user
+    suite
+        .lp_depositor_convert(BOB, &[coin(10_000, suite.astro())], true, None, None)
+        .unwrap();
+    assert_eq!(suite.query_lp_token_balance(BOB).unwrap().u128(), 0);
+    let staked = suite.query_user_lp_token_staking(BOB).unwrap().staked;
+    assert!(
+        staked.u128().abs_diff(simulation.lp_amount.u128()) * 100 <= simulation.lp_amount.u128()
+    );
+    assert_no_depositor_balance(&mut suite);
+
+    // lp tokens are sent to user without staking
+    suite
+        .lp_depositor_convert(BOB, &[coin(10_000, suite.astro())], false, None, None)
+        .unwrap();
+    assert!(!suite.query_lp_token_balance(BOB).unwrap().is_zero());
+    assert_eq!(
+        suite.query_user_lp_token_staking(BOB).unwrap().staked,
+        staked
+    );
+    assert_no_depositor_balance(&mut suite);
+}
+
+#[test]
+fn convert_multiple_assets() {
+    let mut suite = instantiate();
+    let unknown_denom = "uunknown";
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 40_000)
+        .unwrap();
+    suite
+        .mint_native(BOB.to_string(), unknown_denom.to_string(), 100)
+        .unwrap();
+    suite.convert_astro(BOB, 1_000).unwrap();
+    suite.stake_astro(BOB, 11_000).unwrap();
+    let eclipastro = suite
+        .query_balance_native(BOB.to_string(), suite.eclipastro())
+        .unwrap();
+    let xastro = suite
+        .query_balance_native(BOB.to_string(), suite.xastro())
+        .unwrap();
+    let native_asset = |denom: String, amount: u128| Asset {
+        info: AssetInfo::NativeToken { denom },
+        amount: Uint128::new(amount),
+    };
+
+    // unknown assets are returned as leftovers
+    let simulation = suite
+        .query_lp_depositor_simulate(vec![
+            native_asset(suite.astro(), 10_000),
+            native_asset(suite.eclipastro(), eclipastro),
+            native_asset(suite.xastro(), xastro),
+            native_asset(unknown_denom.to_string(), 100),
+        ])
+        .unwrap();
+    assert_eq!(
+        simulation.leftovers,
+        vec![native_asset(unknown_denom.to_string(), 100)]
+    );
+    assert!(!simulation.lp_amount.is_zero());
+
+    let err = suite
+        .query_lp_depositor_simulate(vec![
+            native_asset(suite.astro(), 5_000),
+            native_asset(suite.astro(), 5_000),
+        ])
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Ensure list contains unique assets"));
+
+    let err = suite
+        .lp_depositor_convert(
+            BOB,
+            &[coin(10_000, suite.astro()), coin(100, unknown_denom)],
+            false,
+            None,
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidCoinAsset(unknown_denom.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // all assets are split to match the pool and deposited in one zap
+    let mut funds = vec![
+        coin(10_000, suite.astro()),
+        coin(eclipastro, suite.eclipastro()),
+        coin(xastro, suite.xastro()),
+    ];
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+    suite
+        .lp_depositor_convert(BOB, &funds, false, None, None)
+        .unwrap();
+    let lp_amount = suite.query_lp_token_balance(BOB).unwrap().u128();
+    assert!(lp_amount.abs_diff(simulation.lp_amount.u128()) * 100 <= simulation.lp_amount.u128());
+    assert_eq!(
+        suite
+            .query_balance_native(BOB.to_string(), suite.eclipastro())
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        suite
+            .query_balance_native(BOB.to_string(), suite.xastro())
+            .unwrap(),
+        0
+    );
+    assert_no_depositor_balance(&mut suite);
+
+    // single xASTRO deposit is split at pool price
+    suite.stake_astro(BOB, 11_000).unwrap();
+    let xastro = suite
+        .query_balance_native(BOB.to_string(), suite.xastro())
+        .unwrap();
+    suite
+        .lp_depositor_convert(BOB, &[coin(xastro, suite.xastro())], false, None, None)
+        .unwrap();
+    assert!(suite.query_lp_token_balance(BOB).unwrap().u128() > lp_amount);
+    assert_no_depositor_balance(&mut suite);
+}
+
+#[test]
+fn update_config() {
+    let mut suite = instantiate();
+    let config = LpDepositorUpdateConfigMsg {
+        voter: None,
+        lp_staking: Some(BOB.to_string()),
+    };
+
+    let err = suite
+        .lp_depositor_update_config(ALICE, config.clone())
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Admin(AdminError::NotAdmin {}),
+        err.downcast().unwrap()
+    );
+
+    let admin = suite.admin();
+    suite.lp_depositor_update_config(&admin, config).unwrap();
+    assert_eq!(
+        suite.query_lp_depositor_config().unwrap().lp_staking,
+        Some(Addr::unchecked(BOB))
+    );
+}
@@ -2,19 +2,25 @@ use std::str::FromStr;
 
 use astroport::{
     asset::{Asset, AssetInfo, Decimal256Ext},
+    router::SwapOperation,
     vesting::{VestingAccount, VestingSchedule, VestingSchedulePoint},
 };
 use cosmwasm_std::{Addr, Decimal256, Uint128};
+use cw_controllers::AdminError;
 use equinox_msg::{
-    lp_staking::{RewardAmount, RewardWeight},
-    single_sided_staking::UnbondedItem,
+    lp_staking::{
+        PendingSwapReward, RewardAmount, RewardDistribution, RewardRoute, RewardWeight, RouteType,
+        UpdateConfigMsg, WithdrawOutput,
+    },
+    ownership::{OwnershipError, OwnershipProposal},
+    single_sided_staking::{TimeLockConfig, UnbondedItem},
     utils::{UNBONDING_PERIOD_0, UNBONDING_PERIOD_1},
 };
 use lp_staking::error::ContractError;
 
 use pretty_assertions::assert_eq;
 
-use crate::suite::{Suite, SuiteBuilder, ALICE, BOB, CAROL, TREASURY};
+use crate::suite::{Suite, SuiteBuilder, ALICE, BOB, CAROL, FUNDING_DAO, TREASURY};
 
 fn instantiate() -> Suite {
     let mut suite = SuiteBuilder::new().build();
@@ -531,6 +537,92 @@ fn blacklist() {
     );
 }
 
+#[test]
+fn update_blacklist() {
+    let mut suite = instantiate();
+    suite
+        .add_lp_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+    suite.stake_lp_token(ALICE, 1_000).unwrap();
+    suite.update_time(43200);
+
+    let err = suite.lp_add_to_blacklist(CAROL).unwrap_err();
+    assert_eq!(
+        ContractError::AlreadyBlacklisted(CAROL.to_string()),
+        err.downcast().unwrap()
+    );
+    let err = suite.lp_remove_from_blacklist(BOB).unwrap_err();
+    assert_eq!(
+        ContractError::NotBlacklisted(BOB.to_string()),
+        err.downcast().unwrap()
+    );
+
+    let eclip_info = AssetInfo::NativeToken {
+        denom: suite.eclip(),
+    };
+    let eclip_amount = |rewards: &[RewardAmount]| {
+        rewards
+            .iter()
+            .find(|r| r.info.equal(&eclip_info))
+            .map(|r| r.amount)
+            .unwrap_or_default()
+    };
+
+    // rewards accrued before blacklisting are sent to user
+    let alice_reward = eclip_amount(&suite.query_user_lp_staking_reward(ALICE).unwrap());
+    assert!(!alice_reward.is_zero());
+    let alice_eclip_before = suite.query_balance_native(ALICE, suite.eclip()).unwrap();
+    let blacklist_rewards_before = suite.query_lp_blacklisted_reward().unwrap();
+    suite.lp_add_to_blacklist(ALICE).unwrap();
+    assert_eq!(
+        suite.query_balance_native(ALICE, suite.eclip()).unwrap(),
+        alice_eclip_before + alice_reward.u128()
+    );
+    assert_eq!(
+        eclip_amount(&suite.query_lp_blacklisted_reward().unwrap()),
+        eclip_amount(&blacklist_rewards_before)
+    );
+
+    // rewards accrued while blacklisted go to blacklist rewards
+    suite.update_time(43200);
+    let err = suite.lp_staking_claim_rewards(ALICE).unwrap_err();
+    assert_eq!(ContractError::Blacklisted {}, err.downcast().unwrap());
+    let blacklist_rewards = suite.query_lp_blacklisted_reward().unwrap();
+    assert!(eclip_amount(&blacklist_rewards) > eclip_amount(&blacklist_rewards_before));
+
+    let alice_eclip_before = suite.query_balance_native(ALICE, suite.eclip()).unwrap();
+    suite.lp_remove_from_blacklist(ALICE).unwrap();
+    assert_eq!(
+        suite.query_balance_native(ALICE, suite.eclip()).unwrap(),
+        alice_eclip_before
+    );
+    assert_eq!(
+        eclip_amount(&suite.query_lp_blacklisted_reward().unwrap()),
+        eclip_amount(&blacklist_rewards)
+    );
+    assert!(eclip_amount(&suite.query_user_lp_staking_reward(ALICE).unwrap()).is_zero());
+
+    // rewards accrued after removal are sent to user again
+    suite.update_time(43200);
+    let alice_reward = eclip_amount(&suite.query_user_lp_staking_reward(ALICE).unwrap());
+    assert!(!alice_reward.is_zero());
+    suite.lp_staking_claim_rewards(ALICE).unwrap();
+    assert_eq!(
+        suite.query_balance_native(ALICE, suite.eclip()).unwrap(),
+        alice_eclip_before + alice_reward.u128()
+    );
+    assert_eq!(
+        eclip_amount(&suite.query_lp_blacklisted_reward().unwrap()),
+        eclip_amount(&blacklist_rewards)
+    );
+}
+
 #[test]
 fn unbond_half_period() {
     let mut suite = instantiate();
@@ -876,12 +968,16 @@ fn unbond_multiple_positions() {
             UnbondedItem {
                 amount: Uint128::new(23_836),
                 fee: Uint128::zero(),
-                release_date: 1699229205
+                release_date: 1699229205,
+                duration: 0,
+                locked_at: 0,
             },
             UnbondedItem {
                 amount: Uint128::new(23_836),
                 fee: Uint128::zero(),
-                release_date: 1699229206
+                release_date: 1699229206,
+                duration: 0,
+                locked_at: 0,
             }
         ]
     );
@@ -913,7 +1009,9 @@ fn unbond_multiple_positions() {
         vec![UnbondedItem {
             amount: Uint128::new(47_672),
             fee: Uint128::zero(),
-            release_date: 1701648406
+            release_date: 1701648406,
+            duration: 0,
+            locked_at: 0,
         },]
     );
 
@@ -1111,3 +1209,580 @@ fn unbond_twice() {
     let res = suite.withdraw_lp_token(BOB, None).unwrap_err();
     assert_eq!(ContractError::EarlyWithdraw, res.downcast().unwrap());
 }
+
+#[test]
+fn cancel_unbond() {
+    let mut suite = instantiate();
+    suite.stake_lp_token(ALICE, 1_000).unwrap();
+
+    suite
+        .unbond_lp_token(ALICE, Some(400), UNBONDING_PERIOD_0)
+        .unwrap();
+    suite
+        .unbond_lp_token(ALICE, Some(600), UNBONDING_PERIOD_1)
+        .unwrap();
+    assert_eq!(suite.query_total_lp_token_staking().unwrap().u128(), 0);
+    let unbonded = suite.query_user_lp_token_unbonded(ALICE).unwrap();
+    assert_eq!(unbonded.len(), 2);
+    assert_eq!(unbonded[0].amount, Uint128::new(380));
+    assert_eq!(unbonded[0].fee, Uint128::new(20));
+
+    let err = suite.cancel_unbond_lp_token(ALICE, 2).unwrap_err();
+    assert_eq!(
+        ContractError::UnbondedItemNotFound(2),
+        err.downcast().unwrap()
+    );
+
+    // unbonding fee is refunded and full amount is staked again
+    suite.cancel_unbond_lp_token(ALICE, 0).unwrap();
+    assert_eq!(
+        suite.query_user_lp_token_unbonded(ALICE).unwrap(),
+        vec![unbonded[1].clone()]
+    );
+    assert_eq!(suite.query_total_lp_token_staking().unwrap().u128(), 400);
+    assert_eq!(
+        suite.query_user_lp_token_staking(ALICE).unwrap().staked,
+        Uint128::new(400)
+    );
+    assert_eq!(
+        suite
+            .query_incentive_deposit(
+                &suite.eclipastro_xastro_lp_token(),
+                &suite.lp_staking_contract(),
+            )
+            .unwrap()
+            .u128(),
+        400
+    );
+
+    suite.cancel_unbond_lp_token(ALICE, 0).unwrap();
+    assert_eq!(suite.query_user_lp_token_unbonded(ALICE).unwrap(), vec![]);
+    assert_eq!(suite.query_total_lp_token_staking().unwrap().u128(), 1_000);
+    assert_eq!(
+        suite.query_user_lp_token_staking(ALICE).unwrap().staked,
+        Uint128::new(1_000)
+    );
+}
+
+#[test]
+fn locked_staking() {
+    const ONE_DAY: u64 = 86_400;
+    const ONE_MONTH: u64 = 30 * ONE_DAY;
+
+    let mut suite = instantiate();
+    suite
+        .lp_staking_update_config(
+            &suite.admin(),
+            UpdateConfigMsg {
+                lp_token: None,
+                lp_contract: None,
+                lockdrop: None,
+                astroport_incentives: None,
+                treasury: None,
+                funding_dao: None,
+                eclip: None,
+                beclip: None,
+                unbonding_tiers: None,
+                timelock_config: Some(vec![TimeLockConfig {
+                    duration: ONE_MONTH,
+                    reward_multiplier: 20_000,
+                }]),
+            },
+        )
+        .unwrap();
+    suite
+        .add_lp_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+    for user in [BOB, CAROL] {
+        suite
+            .mint_native(user, suite.eclipastro_xastro_lp_token(), 1_000)
+            .unwrap();
+    }
+
+    let res = suite.lock_lp_token(CAROL, 1_000, ONE_DAY).unwrap_err();
+    assert_eq!(
+        ContractError::NoLockingPeriodFound(ONE_DAY),
+        res.downcast().unwrap()
+    );
+
+    let locked_at = suite.get_time();
+    suite.stake_lp_token(BOB, 1_000).unwrap();
+    suite.lock_lp_token(CAROL, 1_000, ONE_MONTH).unwrap();
+    assert_eq!(suite.query_total_lp_token_staking().unwrap().u128(), 2_000);
+    let carol_locked_staking = suite.query_user_lp_token_locked_staking(CAROL).unwrap();
+    assert_eq!(carol_locked_staking.len(), 1);
+    assert_eq!(carol_locked_staking[0].amount.u128(), 1_000);
+    assert_eq!(carol_locked_staking[0].boost.u128(), 1_000);
+
+    suite.update_time(ONE_DAY);
+
+    // locked position gets double ECLIP, bECLIP rewards and the same ASTRO rewards
+    let bob_rewards = suite.query_user_lp_token_rewards(BOB).unwrap();
+    let carol_rewards = suite.query_user_lp_token_rewards(CAROL).unwrap();
+    let reward_of = |rewards: &[RewardAmount], denom: String| {
+        rewards
+            .iter()
+            .find(|r| r.info.to_string() == denom)
+            .unwrap()
+            .amount
+            .u128()
+    };
+    for denom in [suite.eclip(), suite.beclip()] {
+        let bob_reward = reward_of(&bob_rewards, denom.clone());
+        let carol_reward = reward_of(&carol_rewards, denom);
+        assert!(bob_reward > 0);
+        assert!(carol_reward.abs_diff(bob_reward * 2) <= 2);
+    }
+    assert_eq!(
+        reward_of(&bob_rewards, suite.astro()),
+        reward_of(&carol_rewards, suite.astro())
+    );
+
+    let res = suite
+        .unlock_lp_token(CAROL, ONE_MONTH, locked_at, None)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::LockNotEnded((locked_at + ONE_MONTH) / ONE_DAY * ONE_DAY + ONE_DAY),
+        res.downcast().unwrap()
+    );
+
+    // lock flexible staking
+    suite
+        .restake_lp_token(BOB, 0, None, Some(400), ONE_MONTH)
+        .unwrap();
+    assert_eq!(
+        suite
+            .query_user_lp_token_staking(BOB)
+            .unwrap()
+            .staked
+            .u128(),
+        600
+    );
+    assert_eq!(
+        suite.query_user_lp_token_locked_staking(BOB).unwrap()[0]
+            .amount
+            .u128(),
+        400
+    );
+
+    suite.update_time(ONE_MONTH);
+    suite
+        .unlock_lp_token(CAROL, ONE_MONTH, locked_at, None)
+        .unwrap();
+    assert_eq!(
+        suite.query_user_lp_token_locked_staking(CAROL).unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        suite
+            .query_user_lp_token_staking(CAROL)
+            .unwrap()
+            .staked
+            .u128(),
+        1_000
+    );
+    assert_eq!(suite.query_total_lp_token_staking().unwrap().u128(), 2_000);
+}
+
+#[test]
+fn withdraw_as() {
+    let mut suite = instantiate();
+    for user in [BOB, CAROL] {
+        suite
+            .mint_native(user, suite.eclipastro_xastro_lp_token(), 1_000)
+            .unwrap();
+        suite.stake_lp_token(user, 1_000).unwrap();
+        suite
+            .unbond_lp_token(user, None, UNBONDING_PERIOD_1)
+            .unwrap();
+    }
+    suite.update_time(UNBONDING_PERIOD_1);
+
+    // lp tokens are returned as is
+    suite
+        .withdraw_lp_token_as(BOB, WithdrawOutput::LpToken, None, None)
+        .unwrap();
+    assert_eq!(suite.query_lp_token_balance(BOB).unwrap().u128(), 1_000);
+
+    let res = suite
+        .withdraw_lp_token_as(CAROL, WithdrawOutput::Astro, Some(1_000_000), None)
+        .unwrap_err();
+    assert!(matches!(
+        res.downcast().unwrap(),
+        ContractError::ReceivedAmountTooLow { .. }
+    ));
+
+    // pair assets minimums are set by min_assets_to_receive only
+    let res = suite
+        .withdraw_lp_token_as(CAROL, WithdrawOutput::PairAssets, Some(1), None)
+        .unwrap_err();
+    assert_eq!(
+        res.downcast::<ContractError>().unwrap(),
+        ContractError::MinReceiveNotSupported {}
+    );
+
+    // minimums are passed to pair
+    suite
+        .withdraw_lp_token_as(
+            CAROL,
+            WithdrawOutput::PairAssets,
+            None,
+            Some(vec![
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.eclipastro(),
+                    },
+                    amount: Uint128::new(1_000_000),
+                },
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.xastro(),
+                    },
+                    amount: Uint128::new(1_000_000),
+                },
+            ]),
+        )
+        .unwrap_err();
+
+    // pair assets are returned without swaps
+    suite
+        .withdraw_lp_token_as(CAROL, WithdrawOutput::PairAssets, None, None)
+        .unwrap();
+    assert_eq!(suite.query_lp_token_balance(CAROL).unwrap().u128(), 0);
+    assert!(
+        suite
+            .query_balance_native(CAROL, suite.eclipastro())
+            .unwrap()
+            > 0
+    );
+    assert!(suite.query_balance_native(CAROL, suite.xastro()).unwrap() > 0);
+    assert_eq!(suite.query_balance_native(CAROL, suite.astro()).unwrap(), 0);
+}
+
+#[test]
+fn reward_distribution_routes() {
+    let mut suite = instantiate();
+    suite
+        .mint_native(BOB, suite.eclipastro_xastro_lp_token(), 1_000)
+        .unwrap();
+    suite.stake_lp_token(BOB, 1_000).unwrap();
+
+    let res = suite
+        .lp_staking_update_reward_distribution(
+            &suite.admin(),
+            RewardDistribution {
+                users: 8_000,
+                treasury: 1_750,
+                funding_dao: 250,
+                routes: vec![RewardRoute {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.eclip(),
+                    },
+                    route: RouteType::StakeToXastro,
+                }],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidRewardRoute(suite.eclip()),
+        res.downcast().unwrap()
+    );
+
+    // swap route should start from the reward asset
+    let res = suite
+        .lp_staking_update_reward_distribution(
+            &suite.admin(),
+            RewardDistribution {
+                users: 8_000,
+                treasury: 1_750,
+                funding_dao: 250,
+                routes: vec![RewardRoute {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.eclip(),
+                    },
+                    route: RouteType::Swap {
+                        router: suite.lp_staking_contract(),
+                        operations: vec![SwapOperation::AstroSwap {
+                            offer_asset_info: AssetInfo::NativeToken {
+                                denom: suite.astro(),
+                            },
+                            ask_asset_info: AssetInfo::NativeToken {
+                                denom: suite.eclip(),
+                            },
+                        }],
+                        max_spread: None,
+                    },
+                }],
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidRewardRoute(suite.eclip()),
+        res.downcast().unwrap()
+    );
+
+    // send ASTRO as is instead of staking
+    suite
+        .lp_staking_update_reward_distribution(
+            &suite.admin(),
+            RewardDistribution {
+                users: 8_000,
+                treasury: 1_750,
+                funding_dao: 250,
+                routes: vec![RewardRoute {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.astro(),
+                    },
+                    route: RouteType::Send,
+                }],
+            },
+        )
+        .unwrap();
+
+    suite.update_time(86400);
+    let pending_incentives = suite
+        .query_incentive_pending_rewards(&suite.lp_staking_contract())
+        .unwrap();
+    let astro_amount = pending_incentives[0].amount.u128();
+    let protocol_amount = astro_amount - astro_amount * 8_000 / 10_000;
+    let funding_dao_amount = protocol_amount * 250 / 2_000;
+
+    suite.lp_staking_claim_rewards(BOB).unwrap();
+    assert_eq!(
+        suite
+            .query_balance_native(FUNDING_DAO, suite.astro())
+            .unwrap(),
+        funding_dao_amount
+    );
+    assert_eq!(
+        suite.query_balance_native(TREASURY, suite.astro()).unwrap(),
+        protocol_amount - funding_dao_amount
+    );
+    assert_eq!(
+        suite
+            .query_balance_native(TREASURY, suite.xastro())
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        suite.query_lp_staking_protocol_fees().unwrap(),
+        vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: suite.astro()
+            },
+            amount: Uint128::from(protocol_amount),
+        }]
+    );
+
+    // failing swap route doesn't block user claims, rewards wait for swap_rewards
+    suite
+        .lp_staking_update_reward_distribution(
+            &suite.admin(),
+            RewardDistribution {
+                users: 8_000,
+                treasury: 1_750,
+                funding_dao: 250,
+                routes: vec![RewardRoute {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.astro(),
+                    },
+                    route: RouteType::Swap {
+                        router: suite.lp_staking_contract(),
+                        operations: vec![SwapOperation::AstroSwap {
+                            offer_asset_info: AssetInfo::NativeToken {
+                                denom: suite.astro(),
+                            },
+                            ask_asset_info: AssetInfo::NativeToken {
+                                denom: suite.eclip(),
+                            },
+                        }],
+                        max_spread: None,
+                    },
+                }],
+            },
+        )
+        .unwrap();
+    suite.update_time(86400);
+    let pending_incentives = suite
+        .query_incentive_pending_rewards(&suite.lp_staking_contract())
+        .unwrap();
+    let astro_amount = pending_incentives[0].amount.u128();
+    let swap_protocol_amount = astro_amount - astro_amount * 8_000 / 10_000;
+    let swap_funding_dao_amount = swap_protocol_amount * 250 / 2_000;
+    suite.lp_staking_claim_rewards(BOB).unwrap();
+    assert_eq!(
+        suite.query_lp_staking_pending_swap_rewards().unwrap(),
+        vec![
+            PendingSwapReward {
+                receiver: Addr::unchecked(FUNDING_DAO),
+                asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.astro()
+                    },
+                    amount: Uint128::from(swap_funding_dao_amount),
+                },
+            },
+            PendingSwapReward {
+                receiver: Addr::unchecked(TREASURY),
+                asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.astro()
+                    },
+                    amount: Uint128::from(swap_protocol_amount - swap_funding_dao_amount),
+                },
+            },
+        ]
+    );
+    suite.lp_staking_swap_rewards(ALICE, None).unwrap_err();
+
+    // swapping other assets skips the failing route and keeps its rewards pending
+    let pending_swap_rewards = suite.query_lp_staking_pending_swap_rewards().unwrap();
+    suite
+        .lp_staking_swap_rewards(
+            ALICE,
+            Some(vec![AssetInfo::NativeToken {
+                denom: suite.eclip(),
+            }]),
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_lp_staking_pending_swap_rewards().unwrap(),
+        pending_swap_rewards
+    );
+
+    // pending rewards follow the current route
+    suite
+        .lp_staking_update_reward_distribution(
+            &suite.admin(),
+            RewardDistribution {
+                users: 8_000,
+                treasury: 1_750,
+                funding_dao: 250,
+                routes: vec![RewardRoute {
+                    info: AssetInfo::NativeToken {
+                        denom: suite.astro(),
+                    },
+                    route: RouteType::Send,
+                }],
+            },
+        )
+        .unwrap();
+    suite.lp_staking_swap_rewards(ALICE, None).unwrap();
+    assert_eq!(
+        suite.query_lp_staking_pending_swap_rewards().unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        suite
+            .query_balance_native(FUNDING_DAO, suite.astro())
+            .unwrap(),
+        funding_dao_amount + swap_funding_dao_amount
+    );
+    assert_eq!(
+        suite.query_balance_native(TREASURY, suite.astro()).unwrap(),
+        protocol_amount - funding_dao_amount + swap_protocol_amount - swap_funding_dao_amount
+    );
+}
+
+#[test]
+fn ownership_transfer() {
+    let mut suite = instantiate();
+    let admin = suite.admin();
+
+    let err = suite
+        .lp_staking_propose_new_owner(BOB, CAROL, 86400)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::Admin(AdminError::NotAdmin {})),
+        err.downcast().unwrap()
+    );
+    let err = suite
+        .lp_staking_propose_new_owner(&admin, &admin, 86400)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::SameOwner {}),
+        err.downcast().unwrap()
+    );
+    let err = suite
+        .lp_staking_propose_new_owner(&admin, BOB, 1209601)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::ExpiresInErr(1209600)),
+        err.downcast().unwrap()
+    );
+
+    let err = suite.lp_staking_claim_ownership(BOB).unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::ProposalNotFound {}),
+        err.downcast().unwrap()
+    );
+
+    suite
+        .lp_staking_propose_new_owner(&admin, BOB, 86400)
+        .unwrap();
+    assert_eq!(
+        suite.query_lp_staking_ownership_proposal().unwrap(),
+        Some(OwnershipProposal {
+            owner: Addr::unchecked(BOB),
+            ttl: suite.get_time() + 86400,
+        })
+    );
+
+    // only proposed owner can claim, current owner can't
+    let err = suite.lp_staking_claim_ownership(&admin).unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::NotProposedOwner {}),
+        err.downcast().unwrap()
+    );
+    let err = suite.lp_staking_claim_ownership(CAROL).unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::NotProposedOwner {}),
+        err.downcast().unwrap()
+    );
+
+    // proposal can't be claimed after expiration
+    suite.update_time(86401);
+    let err = suite.lp_staking_claim_ownership(BOB).unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::OwnershipProposalExpired {}),
+        err.downcast().unwrap()
+    );
+
+    // dropped proposal can't be claimed
+    suite
+        .lp_staking_propose_new_owner(&admin, BOB, 86400)
+        .unwrap();
+    suite.lp_staking_drop_ownership_proposal(&admin).unwrap();
+    assert_eq!(suite.query_lp_staking_ownership_proposal().unwrap(), None);
+    let err = suite.lp_staking_claim_ownership(BOB).unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::ProposalNotFound {}),
+        err.downcast().unwrap()
+    );
+
+    suite
+        .lp_staking_propose_new_owner(&admin, BOB, 86400)
+        .unwrap();
+    suite.update_time(86400);
+    suite.lp_staking_claim_ownership(BOB).unwrap();
+    assert_eq!(
+        suite.query_lp_staking_owner().unwrap(),
+        Addr::unchecked(BOB)
+    );
+    assert_eq!(suite.query_lp_staking_ownership_proposal().unwrap(), None);
+
+    // previous owner lost admin rights
+    let err = suite
+        .lp_staking_propose_new_owner(&admin, CAROL, 86400)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::Admin(AdminError::NotAdmin {})),
+        err.downcast().unwrap()
+    );
+}
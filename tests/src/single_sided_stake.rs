@@ -1,13 +1,17 @@
 use std::str::FromStr;
 
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use astroport::asset::AssetInfo;
+use cosmwasm_std::{Addr, Decimal, Uint128, Uint256};
 use cw_controllers::AdminError;
+use cw_multi_test::AppResponse;
 use eclipse_base::{converters::str_to_dec, voter::msg::AstroStakingRewardResponse};
 use equinox_msg::{
+    ownership::OwnershipError,
     single_sided_staking::{
-        TimeLockConfig, UpdateConfigMsg, UserReward, UserStaking, UserStakingByDuration,
+        AllPositionsResponse, StakingPosition, TimeLockConfig, UnbondedItem, UpdateConfigMsg,
+        UserReward, UserStaking, UserStakingByDuration,
     },
-    utils::{UNBONDING_PERIOD_0, UNBONDING_PERIOD_1},
+    utils::{default_unbonding_tiers, UnbondingTier, UNBONDING_PERIOD_0, UNBONDING_PERIOD_1},
 };
 use single_sided_staking::{config::ONE_DAY, error::ContractError};
 
@@ -50,6 +54,7 @@ fn update_config() {
         beclip: None,
         eclip_staking: None,
         init_early_unlock_penalty: Some(str_to_dec("0.8")),
+        unbonding_tiers: None,
     };
 
     // attacker
@@ -342,7 +347,8 @@ fn claim() {
         UserReward {
             beclip: Uint128::zero(),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::zero()
+            eclip: Uint128::zero(),
+            assets: vec![]
         }
     );
 
@@ -375,7 +381,8 @@ fn claim() {
         UserReward {
             beclip: Uint128::from(95555555u128),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::from(142222222u128)
+            eclip: Uint128::from(142222222u128),
+            assets: vec![]
         }
     );
     assert_eq!(
@@ -383,11 +390,27 @@ fn claim() {
         UserReward {
             beclip: Uint128::from(47777777u128),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::from(71111111u128)
+            eclip: Uint128::from(71111111u128),
+            assets: vec![]
         }
     );
 
-    suite.single_stake_claim(BOB, 0, 0, None).unwrap();
+    // pending eclipASTRO rewards are claimed from voter
+    let res = suite.single_stake_claim(BOB, 0, 0, None).unwrap();
+    assert!(res.events.iter().any(|e| e.ty == "execute"
+        && e.attributes
+            .iter()
+            .any(|a| a.key == "_contract_address" && a.value == suite.voter_contract())));
+    assert_eq!(
+        suite.query_voter_astro_staking_rewards().unwrap().users,
+        Uint128::zero()
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_staking_eclipastro_rewards()
+            .unwrap(),
+        vec![(suite.get_time(), Uint128::from(76u128))]
+    );
     assert_eq!(
         suite
             .query_balance_native(BOB.to_string(), suite.eclip())
@@ -407,7 +430,8 @@ fn claim() {
         UserReward {
             beclip: Uint128::from(0u128),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::from(0u128)
+            eclip: Uint128::from(0u128),
+            assets: vec![]
         }
     );
 
@@ -466,7 +490,8 @@ fn blacklist() {
         UserReward {
             beclip: Uint128::zero(),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::zero()
+            eclip: Uint128::zero(),
+            assets: vec![]
         }
     );
 
@@ -499,7 +524,8 @@ fn blacklist() {
         UserReward {
             beclip: Uint128::zero(),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::zero()
+            eclip: Uint128::zero(),
+            assets: vec![]
         }
     );
     assert_eq!(
@@ -509,7 +535,8 @@ fn blacklist() {
         UserReward {
             beclip: Uint128::zero(),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::zero()
+            eclip: Uint128::zero(),
+            assets: vec![]
         }
     );
 
@@ -520,7 +547,8 @@ fn blacklist() {
         UserReward {
             beclip: Uint128::from(143333332u128),
             eclipastro: Uint128::zero(),
-            eclip: Uint128::from(213333333u128)
+            eclip: Uint128::from(213333333u128),
+            assets: vec![]
         }
     );
 
@@ -873,7 +901,1104 @@ fn unbond_twice() {
     assert_eq!(ContractError::NoLockedAmount {}, res.downcast().unwrap());
 
     suite.update_time(UNBONDING_PERIOD_1);
+    let res = suite
+        .single_sided_withdraw_with_min_out(BOB, 1_000_000)
+        .unwrap_err();
+    assert!(matches!(
+        res.downcast().unwrap(),
+        ContractError::ReceivedAmountTooLow { .. }
+    ));
     suite.single_sided_withdraw(BOB, None).unwrap();
     let res = suite.single_sided_withdraw(BOB, None).unwrap_err();
     assert_eq!(ContractError::EarlyWithdraw, res.downcast().unwrap());
 }
+
+#[test]
+fn early_unlock() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    // add funds to vault
+    suite
+        .add_single_sided_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+
+    let block_time = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 400, THREE_MONTH, None)
+        .unwrap();
+    suite.update_time(ONE_DAY);
+    let second_locked_at = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 600, THREE_MONTH, None)
+        .unwrap();
+
+    // flexible staking can't be unlocked early
+    let err = suite
+        .single_sided_early_unlock(BOB, 0, 0, None)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NoLockingPeriodFound(0),
+        err.downcast().unwrap()
+    );
+
+    suite.update_time(ONE_MONTH);
+
+    // partial early unlock is only for allowed users as in unstake
+    let err = suite
+        .single_sided_early_unlock(BOB, THREE_MONTH, block_time, Some(Uint128::new(200)))
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NotAllowed(BOB.to_string()),
+        err.downcast().unwrap()
+    );
+
+    let penalty = suite
+        .calculate_penalty(400, THREE_MONTH, block_time)
+        .unwrap();
+    assert!(penalty > 0);
+    let bob_balance = suite.query_eclipastro_balance(BOB).unwrap();
+    suite
+        .single_sided_early_unlock(BOB, THREE_MONTH, block_time, None)
+        .unwrap();
+    assert_eq!(
+        suite.query_single_sided_staking(BOB).unwrap(),
+        vec![UserStaking {
+            duration: THREE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(600),
+                locked_at: second_locked_at
+            }]
+        }]
+    );
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 600);
+    assert_eq!(
+        suite.query_eclipastro_balance(BOB).unwrap(),
+        bob_balance + 400 - penalty
+    );
+    assert_eq!(suite.query_eclipastro_balance(TREASURY).unwrap(), penalty);
+
+    // penalty is not charged after lock end
+    suite.update_time(THREE_MONTH);
+    let err = suite
+        .single_sided_early_unlock(BOB, THREE_MONTH, second_locked_at, None)
+        .unwrap_err();
+    assert_eq!(ContractError::LockEnded {}, err.downcast().unwrap());
+    let bob_balance = suite.query_eclipastro_balance(BOB).unwrap();
+    suite
+        .single_sided_unstake(BOB, THREE_MONTH, second_locked_at, None, None)
+        .unwrap();
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 0);
+    assert_eq!(
+        suite.query_eclipastro_balance(BOB).unwrap(),
+        bob_balance + 600
+    );
+    assert_eq!(suite.query_eclipastro_balance(TREASURY).unwrap(), penalty);
+}
+
+#[test]
+fn transfer_position() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    // add funds to vault
+    suite
+        .add_single_sided_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+
+    let block_time = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+        .unwrap();
+
+    suite.update_time(ONE_DAY);
+
+    // blacklisted user can't receive position
+    let err = suite
+        .single_sided_transfer_position(BOB, ONE_MONTH, block_time, None, CAROL)
+        .unwrap_err();
+    assert_eq!(ContractError::Blacklisted {}, err.downcast().unwrap());
+
+    let err = suite
+        .single_sided_transfer_position(
+            BOB,
+            ONE_MONTH,
+            block_time,
+            Some(Uint128::new(1_001)),
+            ALICE,
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::ExceedAmount {}, err.downcast().unwrap());
+
+    suite
+        .single_sided_transfer_position(BOB, ONE_MONTH, block_time, Some(Uint128::new(400)), ALICE)
+        .unwrap();
+    assert_eq!(
+        suite.query_single_sided_staking(BOB).unwrap(),
+        vec![UserStaking {
+            duration: ONE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(600),
+                locked_at: block_time
+            }]
+        }]
+    );
+    assert_eq!(
+        suite.query_single_sided_staking(ALICE).unwrap(),
+        vec![UserStaking {
+            duration: ONE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(400),
+                locked_at: block_time
+            }]
+        }]
+    );
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 1_000);
+
+    // transferred position keeps original lock end time
+    let err = suite
+        .single_sided_unbond(ALICE, ONE_MONTH, block_time, UNBONDING_PERIOD_1)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::EarlyUnlockDisabled {},
+        err.downcast().unwrap()
+    );
+    suite.update_time(ONE_MONTH);
+    suite
+        .single_sided_unbond(ALICE, ONE_MONTH, block_time, UNBONDING_PERIOD_1)
+        .unwrap();
+}
+
+#[test]
+fn aggregate_positions() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    // add funds to vault
+    suite
+        .add_single_sided_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+
+    let first_locked_at = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+        .unwrap();
+    suite.update_time(ONE_DAY * 3);
+    let second_locked_at = suite.get_time();
+    suite.single_sided_stake(BOB, 500, ONE_MONTH, None).unwrap();
+    suite.update_time(ONE_DAY);
+
+    let err = suite
+        .single_sided_aggregate_positions(
+            BOB,
+            ONE_MONTH,
+            vec![first_locked_at, first_locked_at],
+            ONE_MONTH,
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::DuplicatedPositions {},
+        err.downcast().unwrap()
+    );
+
+    let err = suite
+        .single_sided_aggregate_positions(
+            BOB,
+            ONE_MONTH,
+            vec![first_locked_at, second_locked_at],
+            0,
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ExtendDurationErr(ONE_MONTH, 0),
+        err.downcast().unwrap()
+    );
+
+    suite
+        .single_sided_aggregate_positions(
+            BOB,
+            ONE_MONTH,
+            vec![first_locked_at, second_locked_at],
+            ONE_MONTH,
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_single_sided_staking(BOB).unwrap(),
+        vec![UserStaking {
+            duration: ONE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(1_500),
+                locked_at: second_locked_at
+            }]
+        }]
+    );
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 1_500);
+
+    // merged position ends with the latest position
+    suite.update_time(ONE_MONTH - ONE_DAY * 3);
+    let err = suite
+        .single_sided_unbond(BOB, ONE_MONTH, second_locked_at, UNBONDING_PERIOD_1)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::EarlyUnlockDisabled {},
+        err.downcast().unwrap()
+    );
+    suite.update_time(ONE_DAY * 4);
+    suite
+        .single_sided_unbond(BOB, ONE_MONTH, second_locked_at, UNBONDING_PERIOD_1)
+        .unwrap();
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 0);
+}
+
+#[test]
+fn auto_compound() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    // add funds to vault
+    suite
+        .add_single_sided_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+    let locked_at = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+        .unwrap();
+
+    let err = suite
+        .single_sided_set_auto_compound(BOB, 100, true)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NoLockingPeriodFound(100),
+        err.downcast().unwrap()
+    );
+    suite
+        .single_sided_set_auto_compound(BOB, ONE_MONTH, true)
+        .unwrap();
+    assert!(suite
+        .query_single_sided_auto_compound(BOB, ONE_MONTH)
+        .unwrap());
+    assert!(!suite.query_single_sided_auto_compound(BOB, 0).unwrap());
+
+    // generate eclipastro rewards
+    suite
+        .mint_native(suite.astro_staking_contract(), suite.astro(), 100_000)
+        .unwrap();
+    suite.update_time(ONE_DAY);
+
+    // anyone can compound, eclipastro rewards are added to position
+    let reward = suite
+        .query_single_sided_staking_reward(BOB, ONE_MONTH, locked_at)
+        .unwrap();
+    let eclipastro_balance = suite.query_eclipastro_balance(BOB).unwrap();
+    suite
+        .single_sided_compound(ALICE, vec![BOB.to_string()])
+        .unwrap();
+    assert_eq!(
+        suite.query_single_sided_staking(BOB).unwrap(),
+        vec![UserStaking {
+            duration: ONE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(1_000) + reward.eclipastro,
+                locked_at
+            }]
+        }]
+    );
+    // other rewards are settled with the previous amount before compounding
+    assert_eq!(
+        suite
+            .query_single_sided_staking_reward(BOB, ONE_MONTH, locked_at)
+            .unwrap(),
+        UserReward::default()
+    );
+    assert_eq!(
+        suite.query_eclipastro_balance(BOB).unwrap(),
+        eclipastro_balance
+    );
+    let eclip_balance = suite
+        .query_balance_native(BOB.to_string(), suite.eclip())
+        .unwrap();
+    assert_eq!(eclip_balance, reward.eclip.u128());
+
+    // eclip rewards after compound are calculated from compounded position only
+    suite.update_time(ONE_DAY);
+    let reward = suite
+        .query_single_sided_staking_reward(BOB, ONE_MONTH, locked_at)
+        .unwrap();
+    assert!(!reward.eclip.is_zero());
+    suite
+        .single_stake_claim(
+            BOB,
+            ONE_MONTH,
+            locked_at,
+            Some(vec![AssetInfo::NativeToken {
+                denom: suite.eclip(),
+            }]),
+        )
+        .unwrap();
+    assert_eq!(
+        suite
+            .query_balance_native(BOB.to_string(), suite.eclip())
+            .unwrap(),
+        eclip_balance + reward.eclip.u128()
+    );
+    let remaining_reward = suite
+        .query_single_sided_staking_reward(BOB, ONE_MONTH, locked_at)
+        .unwrap();
+    assert!(remaining_reward.eclip.is_zero());
+    assert_eq!(remaining_reward.beclip, reward.beclip);
+    assert_eq!(
+        suite.query_eclipastro_balance(BOB).unwrap(),
+        eclipastro_balance
+    );
+
+    // disabled auto compound sends eclipastro rewards again
+    suite
+        .single_sided_set_auto_compound(BOB, ONE_MONTH, false)
+        .unwrap();
+    assert!(!suite
+        .query_single_sided_auto_compound(BOB, ONE_MONTH)
+        .unwrap());
+}
+
+#[test]
+fn checkpoint() {
+    let mut suites = vec![SuiteBuilder::new().build(), SuiteBuilder::new().build()];
+    let mut locked_at = 0u64;
+    for suite in suites.iter_mut() {
+        suite.update_config();
+
+        // add funds to vault
+        suite
+            .add_single_sided_vault_reward(
+                &suite.admin(),
+                None,
+                None,
+                12_800_000_000u128,
+                8_600_000_000u128,
+            )
+            .unwrap();
+
+        suite
+            .mint_native(BOB.to_string(), suite.astro(), 10_000)
+            .unwrap();
+
+        // ready astro_staking_pool
+        suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+        suite.convert_astro(BOB, 10_000).unwrap();
+        locked_at = suite.get_time();
+        suite
+            .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+            .unwrap();
+        suite.single_sided_stake(BOB, 1_000, 0, None).unwrap();
+        assert_eq!(suite.query_single_sided_pending_days().unwrap(), 0);
+        suite.update_time(ONE_DAY * 100);
+        assert_eq!(suite.query_single_sided_pending_days().unwrap(), 100);
+    }
+
+    // keeper processes pending days of the first suite in chunks
+    let err = suites[0].single_sided_checkpoint(ALICE, 0).unwrap_err();
+    assert_eq!(ContractError::ZeroMaxDays {}, err.downcast().unwrap());
+    suites[0].single_sided_checkpoint(ALICE, 30).unwrap();
+    assert_eq!(suites[0].query_single_sided_pending_days().unwrap(), 70);
+    suites[0].single_sided_checkpoint(ALICE, 80).unwrap();
+    assert_eq!(suites[0].query_single_sided_pending_days().unwrap(), 0);
+
+    // rewards are same as processing all days at once
+    for (duration, locked_at) in [(ONE_MONTH, locked_at), (0, 0)] {
+        assert_eq!(
+            suites[0]
+                .query_single_sided_staking_reward(BOB, duration, locked_at)
+                .unwrap(),
+            suites[1]
+                .query_single_sided_staking_reward(BOB, duration, locked_at)
+                .unwrap()
+        );
+    }
+    suites[0].single_stake_claim_all(BOB, true, None).unwrap();
+    suites[1].single_stake_claim_all(BOB, true, None).unwrap();
+    assert_eq!(
+        suites[0]
+            .query_balance_native(BOB.to_string(), suites[0].eclip())
+            .unwrap(),
+        suites[1]
+            .query_balance_native(BOB.to_string(), suites[1].eclip())
+            .unwrap()
+    );
+}
+
+#[test]
+fn asset_rewards() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+    let partner_denom = "upartner";
+
+    suite
+        .mint_native(suite.admin(), partner_denom.to_string(), 1_000_000)
+        .unwrap();
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+    suite.single_sided_stake(BOB, 1_000, 0, None).unwrap();
+
+    // eclip has its own reward flow
+    suite
+        .mint_native(suite.admin(), suite.eclip(), 1_000)
+        .unwrap();
+    let err = suite
+        .add_single_sided_vault_asset_reward(
+            &suite.admin(),
+            None,
+            Some(ONE_DAY * 10),
+            1_000,
+            &suite.eclip(),
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidAsset {}, err.downcast().unwrap());
+
+    suite
+        .add_single_sided_vault_asset_reward(
+            &suite.admin(),
+            None,
+            Some(ONE_DAY * 10),
+            1_000_000,
+            partner_denom,
+        )
+        .unwrap();
+
+    suite.update_time(ONE_DAY * 5);
+    let reward = suite.query_single_sided_staking_reward(BOB, 0, 0).unwrap();
+    let partner_info = AssetInfo::NativeToken {
+        denom: partner_denom.to_string(),
+    };
+    assert_eq!(reward.assets.len(), 1);
+    assert_eq!(reward.assets[0].info, partner_info);
+    assert!(reward.assets[0].amount.u128() <= 500_000);
+    assert!(reward.assets[0].amount.u128() > 499_990);
+
+    // claim only eclip keeps asset rewards pending
+    suite
+        .single_stake_claim(
+            BOB,
+            0,
+            0,
+            Some(vec![AssetInfo::NativeToken {
+                denom: suite.eclip(),
+            }]),
+        )
+        .unwrap();
+    assert_eq!(
+        suite
+            .query_balance_native(BOB.to_string(), partner_denom.to_string())
+            .unwrap(),
+        0
+    );
+
+    suite
+        .single_stake_claim(BOB, 0, 0, Some(vec![partner_info.clone()]))
+        .unwrap();
+    assert_eq!(
+        suite
+            .query_balance_native(BOB.to_string(), partner_denom.to_string())
+            .unwrap(),
+        reward.assets[0].amount.u128()
+    );
+    assert_eq!(
+        suite.query_single_sided_staking_reward(BOB, 0, 0).unwrap(),
+        UserReward::default()
+    );
+
+    // rest of schedule is distributed after the end
+    suite.update_time(ONE_DAY * 10);
+    suite.single_stake_claim_all(BOB, true, None).unwrap();
+    let balance = suite
+        .query_balance_native(BOB.to_string(), partner_denom.to_string())
+        .unwrap();
+    assert!(balance <= 1_000_000);
+    assert!(balance > 999_980);
+}
+
+#[test]
+fn unbonding_tiers() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    assert_eq!(
+        suite.query_single_sided_unbonding_tiers().unwrap(),
+        default_unbonding_tiers()
+    );
+
+    let tiers = vec![
+        UnbondingTier {
+            period: ONE_DAY * 7,
+            fee_rate: Decimal::from_str("0.1").unwrap(),
+        },
+        UnbondingTier {
+            period: UNBONDING_PERIOD_1,
+            fee_rate: Decimal::zero(),
+        },
+    ];
+    let mut new_config = UpdateConfigMsg {
+        timelock_config: None,
+        voter: None,
+        lockdrop: None,
+        treasury: None,
+        eclip: None,
+        beclip: None,
+        eclip_staking: None,
+        init_early_unlock_penalty: None,
+        unbonding_tiers: Some(vec![
+            UnbondingTier {
+                period: ONE_DAY * 7,
+                fee_rate: Decimal::one(),
+            },
+            UnbondingTier {
+                period: UNBONDING_PERIOD_1,
+                fee_rate: Decimal::zero(),
+            },
+        ]),
+    };
+    suite
+        .update_single_sided_stake_config(&suite.admin(), new_config.clone())
+        .unwrap_err();
+    new_config.unbonding_tiers = Some(tiers.clone());
+    suite
+        .update_single_sided_stake_config(&suite.admin(), new_config)
+        .unwrap();
+    assert_eq!(suite.query_single_sided_unbonding_tiers().unwrap(), tiers);
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+    let block_time = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+        .unwrap();
+    suite.update_time(ONE_MONTH + ONE_DAY);
+
+    // removed period is not allowed
+    suite
+        .single_sided_unbond(BOB, ONE_MONTH, block_time, UNBONDING_PERIOD_0)
+        .unwrap_err();
+    suite
+        .single_sided_unbond(BOB, ONE_MONTH, block_time, ONE_DAY * 7)
+        .unwrap();
+    assert_eq!(
+        suite.query_single_sided_unbonded(BOB).unwrap(),
+        vec![UnbondedItem {
+            amount: Uint128::new(900),
+            fee: Uint128::new(100),
+            release_date: suite.get_time() + ONE_DAY * 7,
+            duration: ONE_MONTH,
+            locked_at: block_time,
+        }]
+    );
+}
+
+#[test]
+fn cancel_unbond() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+    let block_time = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+        .unwrap();
+    suite.update_time(ONE_MONTH + ONE_DAY);
+
+    suite
+        .single_sided_unbond(BOB, ONE_MONTH, block_time, UNBONDING_PERIOD_0)
+        .unwrap();
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 0);
+    assert_eq!(suite.query_single_sided_unbonded(BOB).unwrap().len(), 1);
+
+    let err: ContractError = suite
+        .single_sided_cancel_unbond(BOB, 1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnbondedItemNotFound(1));
+
+    // unbonding fee is refunded and the unbonded position is restored
+    suite.single_sided_cancel_unbond(BOB, 0).unwrap();
+    assert_eq!(suite.query_single_sided_unbonded(BOB).unwrap(), vec![]);
+    assert_eq!(suite.query_single_sided_total_staking().unwrap(), 1_000);
+    assert_eq!(
+        suite.query_single_sided_staking(BOB).unwrap(),
+        vec![UserStaking {
+            duration: ONE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(1_000),
+                locked_at: block_time,
+            }],
+        }]
+    );
+}
+
+#[test]
+fn paginated_queries() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    // add funds to vault
+    suite
+        .add_single_sided_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+    suite
+        .mint_native(ALICE.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+    suite.convert_astro(ALICE, 10_000).unwrap();
+
+    suite.single_sided_stake(BOB, 100, 0, None).unwrap();
+    let first_locked_at = suite.get_time();
+    suite.single_sided_stake(BOB, 200, ONE_MONTH, None).unwrap();
+    suite.update_time(ONE_DAY);
+    let second_locked_at = suite.get_time();
+    suite.single_sided_stake(BOB, 300, ONE_MONTH, None).unwrap();
+    suite
+        .single_sided_stake(ALICE, 400, THREE_MONTH, None)
+        .unwrap();
+
+    assert_eq!(
+        suite
+            .query_single_sided_staking_page(BOB, None, Some(2))
+            .unwrap(),
+        vec![
+            UserStaking {
+                duration: 0,
+                staking: vec![UserStakingByDuration {
+                    amount: Uint128::new(100),
+                    locked_at: 0,
+                }],
+            },
+            UserStaking {
+                duration: ONE_MONTH,
+                staking: vec![UserStakingByDuration {
+                    amount: Uint128::new(200),
+                    locked_at: first_locked_at,
+                }],
+            },
+        ]
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_staking_page(BOB, Some((ONE_MONTH, first_locked_at)), None)
+            .unwrap(),
+        vec![UserStaking {
+            duration: ONE_MONTH,
+            staking: vec![UserStakingByDuration {
+                amount: Uint128::new(300),
+                locked_at: second_locked_at,
+            }],
+        }]
+    );
+
+    // claim of empty position stores zero staking which is skipped by queries
+    suite
+        .single_stake_claim(CAROL, ONE_MONTH, first_locked_at, None)
+        .unwrap();
+
+    let mut stakers = vec![ALICE.to_string(), BOB.to_string()];
+    stakers.sort();
+    assert_eq!(
+        suite.query_single_sided_all_stakers(None, None).unwrap(),
+        stakers
+    );
+    assert_eq!(
+        suite.query_single_sided_all_stakers(None, Some(1)).unwrap(),
+        vec![stakers[0].clone()]
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_all_stakers(Some(stakers[0].clone()), None)
+            .unwrap(),
+        vec![stakers[1].clone()]
+    );
+
+    assert_eq!(
+        suite
+            .query_single_sided_all_positions(Some(ONE_MONTH), None, None)
+            .unwrap(),
+        AllPositionsResponse {
+            positions: vec![
+                StakingPosition {
+                    user: BOB.to_string(),
+                    duration: ONE_MONTH,
+                    locked_at: first_locked_at,
+                    amount: Uint128::new(200),
+                },
+                StakingPosition {
+                    user: BOB.to_string(),
+                    duration: ONE_MONTH,
+                    locked_at: second_locked_at,
+                    amount: Uint128::new(300),
+                },
+            ],
+            last_key: None,
+        }
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_all_positions(
+                Some(ONE_MONTH),
+                Some((BOB.to_string(), ONE_MONTH, first_locked_at)),
+                None
+            )
+            .unwrap(),
+        AllPositionsResponse {
+            positions: vec![StakingPosition {
+                user: BOB.to_string(),
+                duration: ONE_MONTH,
+                locked_at: second_locked_at,
+                amount: Uint128::new(300),
+            }],
+            last_key: None,
+        }
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_all_positions(Some(THREE_MONTH), None, Some(1))
+            .unwrap(),
+        AllPositionsResponse {
+            positions: vec![StakingPosition {
+                user: ALICE.to_string(),
+                duration: THREE_MONTH,
+                locked_at: second_locked_at,
+                amount: Uint128::new(400),
+            }],
+            last_key: Some((ALICE.to_string(), THREE_MONTH, second_locked_at)),
+        }
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_all_positions(
+                Some(THREE_MONTH),
+                Some((ALICE.to_string(), THREE_MONTH, second_locked_at)),
+                None
+            )
+            .unwrap(),
+        AllPositionsResponse {
+            positions: vec![],
+            last_key: None,
+        }
+    );
+}
+
+#[test]
+fn staking_snapshots() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+
+    // add funds to vault
+    suite
+        .add_single_sided_vault_reward(
+            &suite.admin(),
+            None,
+            None,
+            12_800_000_000u128,
+            8_600_000_000u128,
+        )
+        .unwrap();
+
+    suite
+        .mint_native(BOB.to_string(), suite.astro(), 10_000)
+        .unwrap();
+
+    // ready astro_staking_pool
+    suite.stake_astro(&suite.admin(), 1_000_000).unwrap();
+
+    suite.convert_astro(BOB, 10_000).unwrap();
+
+    let first_time = suite.get_time();
+    suite
+        .single_sided_stake(BOB, 1_000, ONE_MONTH, None)
+        .unwrap();
+    suite.update_time(ONE_DAY);
+    let second_time = suite.get_time();
+    suite.single_sided_stake(BOB, 500, 0, None).unwrap();
+    suite.update_time(ONE_DAY);
+    suite.single_sided_unstake(BOB, 0, 0, None, None).unwrap();
+
+    assert_eq!(
+        suite
+            .query_single_sided_staking_at(BOB, Some(first_time - 1))
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_staking_at(BOB, Some(first_time))
+            .unwrap(),
+        1_000
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_staking_at(BOB, Some(second_time))
+            .unwrap(),
+        1_500
+    );
+    assert_eq!(
+        suite.query_single_sided_staking_at(BOB, None).unwrap(),
+        1_000
+    );
+
+    let err = suite
+        .single_sided_backfill_snapshots(BOB, None, None)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Admin(AdminError::NotAdmin {}),
+        err.downcast().unwrap()
+    );
+
+    // users with snapshot are skipped, last user is returned while there can be more users
+    let last_user = |res: AppResponse| {
+        res.events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "last_user")
+            .map(|a| a.value.clone())
+            .unwrap()
+    };
+    let res = suite
+        .single_sided_backfill_snapshots(&suite.admin(), None, Some(1))
+        .unwrap();
+    assert_eq!(last_user(res), BOB);
+    let res = suite
+        .single_sided_backfill_snapshots(&suite.admin(), Some(BOB.to_string()), Some(1))
+        .unwrap();
+    assert_eq!(last_user(res), "");
+    assert_eq!(
+        suite
+            .query_single_sided_staking_at(BOB, Some(second_time))
+            .unwrap(),
+        1_500
+    );
+
+    // one month lock has 1.25x multiplier
+    assert_eq!(
+        suite
+            .query_single_sided_total_boosted_stake_at(Some(first_time))
+            .unwrap(),
+        Uint256::from(1_250u128)
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_total_boosted_stake_at(Some(second_time))
+            .unwrap(),
+        Uint256::from(1_750u128)
+    );
+}
+
+#[test]
+fn ownership_transfer() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+    let admin = suite.admin();
+
+    suite
+        .single_sided_propose_new_owner(&admin, BOB, 86400)
+        .unwrap();
+    let err = suite.single_sided_claim_ownership(&admin).unwrap_err();
+    assert_eq!(
+        ContractError::Ownership(OwnershipError::NotProposedOwner {}),
+        err.downcast().unwrap()
+    );
+
+    suite.single_sided_claim_ownership(BOB).unwrap();
+    assert_eq!(
+        suite.query_single_sided_owner().unwrap(),
+        Addr::unchecked(BOB)
+    );
+}
+
+#[test]
+fn update_blacklist() {
+    let mut suite = SuiteBuilder::new().build();
+    suite.update_config();
+    let admin = suite.admin();
+
+    suite
+        .add_single_sided_vault_reward(&admin, None, None, 12_800_000_000u128, 8_600_000_000u128)
+        .unwrap();
+    suite
+        .mint_native(ALICE.to_string(), suite.astro(), 1_000)
+        .unwrap();
+    suite.stake_astro(&admin, 1_000_000).unwrap();
+    suite.convert_astro(ALICE, 1_000).unwrap();
+    suite.single_sided_stake(ALICE, 1_000, 0, None).unwrap();
+    suite.update_time(43200);
+
+    let err = suite.single_sided_add_to_blacklist(ALICE, BOB).unwrap_err();
+    assert_eq!(
+        ContractError::Admin(AdminError::NotAdmin {}),
+        err.downcast().unwrap()
+    );
+    let err = suite
+        .single_sided_add_to_blacklist(&admin, CAROL)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::AlreadyBlacklisted(CAROL.to_string()),
+        err.downcast().unwrap()
+    );
+    let err = suite
+        .single_sided_remove_from_blacklist(&admin, BOB)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NotBlacklisted(BOB.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // rewards accrued before blacklisting are sent to user
+    let alice_reward = suite
+        .query_single_sided_staking_reward(ALICE, 0, 0)
+        .unwrap();
+    assert!(!alice_reward.eclip.is_zero());
+    let alice_eclip_before = suite.query_balance_native(ALICE, suite.eclip()).unwrap();
+    let blacklist_reward_before = suite.query_single_sided_blacklisted_reward().unwrap();
+    suite.single_sided_add_to_blacklist(&admin, ALICE).unwrap();
+    assert_eq!(
+        suite.query_balance_native(ALICE, suite.eclip()).unwrap(),
+        alice_eclip_before + alice_reward.eclip.u128()
+    );
+    assert_eq!(
+        suite.query_single_sided_blacklisted_reward().unwrap(),
+        blacklist_reward_before
+    );
+    assert_eq!(
+        suite.query_single_sided_blacklist(None, None).unwrap(),
+        vec![ALICE.to_string(), CAROL.to_string()]
+    );
+    assert_eq!(
+        suite.query_single_sided_blacklist(None, Some(1)).unwrap(),
+        vec![ALICE.to_string()]
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_blacklist(Some(ALICE.to_string()), None)
+            .unwrap(),
+        vec![CAROL.to_string()]
+    );
+
+    // rewards accrued while blacklisted go to blacklist rewards
+    suite.update_time(43200);
+    let err = suite.single_stake_claim(ALICE, 0, 0, None).unwrap_err();
+    assert_eq!(ContractError::Blacklisted {}, err.downcast().unwrap());
+    let blacklist_reward = suite.query_single_sided_blacklisted_reward().unwrap();
+    assert!(blacklist_reward.eclip > blacklist_reward_before.eclip);
+
+    let alice_eclip_before = suite.query_balance_native(ALICE, suite.eclip()).unwrap();
+    suite
+        .single_sided_remove_from_blacklist(&admin, ALICE)
+        .unwrap();
+    assert_eq!(
+        suite.query_balance_native(ALICE, suite.eclip()).unwrap(),
+        alice_eclip_before
+    );
+    assert_eq!(
+        suite.query_single_sided_blacklisted_reward().unwrap(),
+        blacklist_reward
+    );
+    assert_eq!(
+        suite
+            .query_single_sided_staking_reward(ALICE, 0, 0)
+            .unwrap(),
+        UserReward::default()
+    );
+    assert_eq!(
+        suite.query_single_sided_blacklist(None, None).unwrap(),
+        vec![CAROL.to_string()]
+    );
+}
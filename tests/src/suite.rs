@@ -1,9 +1,18 @@
 use anyhow::Result as AnyResult;
 use astroport::{
     asset::{Asset, AssetInfo, PairInfo},
+    astro_converter::{
+        Config as AstroConverterConfig, Cw20HookMsg as AstroConverterCw20HookMsg,
+        ExecuteMsg as AstroConverterExecuteMsg, InstantiateMsg as AstroConverterInstantiateMsg,
+        QueryMsg as AstroConverterQueryMsg,
+    },
     factory::{PairConfig, PairType, QueryMsg as FactoryQueryMsg},
     incentives::{self, ExecuteMsg as IncentivesExecuteMsg, QueryMsg as IncentivesQueryMsg},
-    pair::ExecuteMsg as PairExecuteMsg,
+    pair::{
+        ConfigResponse as PairConfigResponse, ExecuteMsg as PairExecuteMsg, PoolResponse,
+        QueryMsg as PairQueryMsg,
+    },
+    pair_concentrated::ConcentratedPoolParams,
     staking::{
         Config as AstroStakingConfig, ExecuteMsg as AstroStakingExecuteMsg,
         InstantiateMsg as AstroStakingInstantiateMsg, QueryMsg as AstroStakingQueryMsg,
@@ -11,13 +20,13 @@ use astroport::{
     vesting::{self, ExecuteMsg as VestingExecuteMsg, VestingAccount},
 };
 use cosmwasm_std::{
-    coin, coins,
+    coin, coins, from_json,
     testing::{MockApi, MockStorage},
-    Addr, Api, BlockInfo, CanonicalAddr, Decimal, DepsMut, Empty, Env, GovMsg, IbcMsg, IbcQuery,
-    MessageInfo, RecoverPubkeyError, Response, StdError, StdResult, Storage, Timestamp, Uint128,
-    VerificationError,
+    to_json_binary, to_json_vec, Addr, Api, BankMsg, Binary, BlockInfo, CanonicalAddr, Coin,
+    Decimal, Deps, DepsMut, Empty, Env, GovMsg, IbcMsg, IbcQuery, MessageInfo, RecoverPubkeyError,
+    Response, StdError, StdResult, Storage, Timestamp, Uint128, Uint256, VerificationError,
 };
-use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
 use cw_multi_test::{
     AddressGenerator, App, AppBuilder, AppResponse, BankKeeper, ContractWrapper,
     DistributionKeeper, Executor, FailingModule, StakeKeeper, WasmKeeper,
@@ -35,23 +44,34 @@ use equinox_msg::{
         BlacklistRewards, Config as LockdropConfig, Cw20HookMsg as LockdropCw20HookMsg,
         ExecuteMsg as LockdropExecuteMsg, IncentiveAmounts, IncentiveRewards,
         InstantiateMsg as LockdropInstantiateMsg, LockConfig, LpLockupInfoResponse,
-        LpLockupStateResponse, QueryMsg as LockdropQueryMsg, SingleLockupInfoResponse,
+        LpLockupStateResponse, QueryMsg as LockdropQueryMsg, ReferralInfoResponse,
+        RewardDistributionConfig as LockdropRewardDistributionConfig, SingleLockupInfoResponse,
         SingleLockupStateResponse, StakeType, UpdateConfigMsg as LockdropUpdateConfigMsg,
         UserLpLockupInfoResponse, UserSingleLockupInfoResponse,
     },
+    lp_depositor::{
+        Config as LpDepositorConfig, ExecuteMsg as LpDepositorExecuteMsg,
+        InstantiateMsg as LpDepositorInstantiateMsg, QueryMsg as LpDepositorQueryMsg,
+        SimulateResponse as LpDepositorSimulateResponse,
+        UpdateConfigMsg as LpDepositorUpdateConfigMsg,
+    },
     lp_staking::{
         Config as LpStakingConfig, ExecuteMsg as LpStakingExecuteMsg,
-        InstantiateMsg as LpStakingInstantiateMsg, QueryMsg as LpStakingQueryMsg,
-        RewardAmount as LpStakingRewardAmount, RewardDistribution,
-        RewardWeight as LpStakingRewardWeight, UpdateConfigMsg as LpStakingUpdateConfigMsg,
-        UserStaking as LpStakingUserStaking,
+        InstantiateMsg as LpStakingInstantiateMsg, LockedPosition as LpStakingLockedPosition,
+        PendingSwapReward, QueryMsg as LpStakingQueryMsg, RewardAmount as LpStakingRewardAmount,
+        RewardDistribution, RewardWeight as LpStakingRewardWeight,
+        UpdateConfigMsg as LpStakingUpdateConfigMsg, UserStaking as LpStakingUserStaking,
+        WithdrawOutput as LpStakingWithdrawOutput,
     },
+    ownership::OwnershipProposal,
     single_sided_staking::{
-        Config as SingleStakingConfig, ExecuteMsg as SingleSidedStakingExecuteMsg,
+        AllPositionsResponse as SingleStakingAllPositionsResponse, Config as SingleStakingConfig,
+        ExecuteMsg as SingleSidedStakingExecuteMsg,
         InstantiateMsg as SingleSidedStakingInstantiateMsg, QueryMsg as SingleStakingQueryMsg,
         TimeLockConfig, UnbondedItem, UpdateConfigMsg as SingleStakingUpdateConfigMsg, UserReward,
         UserStaking as SingleSidedUserStaking,
     },
+    utils::UnbondingTier,
 };
 
 use crate::common::stargate::MockStargate;
@@ -94,12 +114,51 @@ fn store_astroport_token(app: &mut TestApp) -> u64 {
     app.store_code(contract)
 }
 
+/// xyk pair returns concentrated pool params in config for lp depositor,
+/// price_scale follows pool balances
+fn astroport_pair_query(deps: Deps, env: Env, msg: PairQueryMsg) -> StdResult<Binary> {
+    match msg {
+        PairQueryMsg::Config {} => {
+            let mut config: PairConfigResponse = from_json(astroport_pair::contract::query(
+                deps,
+                env.clone(),
+                PairQueryMsg::Config {},
+            )?)?;
+            let pool: PoolResponse = from_json(astroport_pair::contract::query(
+                deps,
+                env,
+                PairQueryMsg::Pool {},
+            )?)?;
+            let price_scale = if pool.assets[1].amount.is_zero() {
+                Decimal::one()
+            } else {
+                Decimal::from_ratio(pool.assets[0].amount, pool.assets[1].amount)
+            };
+            config.params = Some(to_json_binary(&ConcentratedPoolParams {
+                amp: Decimal::from_ratio(10u128, 1u128),
+                gamma: Decimal::from_ratio(145u128, 1_000_000u128),
+                mid_fee: Decimal::from_ratio(26u128, 10_000u128),
+                out_fee: Decimal::from_ratio(45u128, 10_000u128),
+                fee_gamma: Decimal::from_ratio(23u128, 100_000u128),
+                repeg_profit_threshold: Decimal::from_ratio(2u128, 1_000_000u128),
+                min_price_scale_delta: Decimal::from_ratio(146u128, 1_000_000u128),
+                price_scale,
+                ma_half_time: 600,
+                track_asset_balances: None,
+                fee_share: None,
+            })?);
+            to_json_binary(&config)
+        }
+        msg => astroport_pair::contract::query(deps, env, msg),
+    }
+}
+
 fn store_astroport_pair(app: &mut TestApp) -> u64 {
     let contract = Box::new(
         ContractWrapper::new_with_empty(
             astroport_pair::contract::execute,
             astroport_pair::contract::instantiate,
-            astroport_pair::contract::query,
+            astroport_pair_query,
         )
         .with_reply_empty(astroport_pair::contract::reply),
     );
@@ -107,6 +166,72 @@ fn store_astroport_pair(app: &mut TestApp) -> u64 {
     app.store_code(contract)
 }
 
+// ASTRO converter mock, releases prefunded native ASTRO 1:1 for old ASTRO
+fn astro_converter_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: AstroConverterInstantiateMsg,
+) -> StdResult<Response> {
+    let config = AstroConverterConfig {
+        old_astro_asset_info: msg.old_astro_asset_info,
+        new_astro_denom: msg.new_astro_denom,
+        outpost_burn_params: msg.outpost_burn_params,
+    };
+    deps.storage.set(b"config", &to_json_vec(&config)?);
+    Ok(Response::new())
+}
+
+fn astro_converter_config(storage: &dyn Storage) -> StdResult<AstroConverterConfig> {
+    from_json(storage.get(b"config").unwrap())
+}
+
+fn astro_converter_execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: AstroConverterExecuteMsg,
+) -> StdResult<Response> {
+    let config = astro_converter_config(deps.storage)?;
+    let (receiver, amount) = match msg {
+        AstroConverterExecuteMsg::Convert { receiver } => {
+            let amount = info
+                .funds
+                .iter()
+                .find(|c| AssetInfo::native(&c.denom) == config.old_astro_asset_info)
+                .ok_or(StdError::generic_err("Invalid denom"))?
+                .amount;
+            (receiver.unwrap_or(info.sender.to_string()), amount)
+        }
+        AstroConverterExecuteMsg::Receive(cw20_msg) => {
+            if AssetInfo::cw20(info.sender) != config.old_astro_asset_info {
+                return Err(StdError::generic_err("Unsupported cw20 token"));
+            }
+            let hook: AstroConverterCw20HookMsg = from_json(&cw20_msg.msg)?;
+            (hook.receiver.unwrap_or(cw20_msg.sender), cw20_msg.amount)
+        }
+        _ => return Err(StdError::generic_err("Not supported")),
+    };
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: receiver,
+        amount: coins(amount.u128(), config.new_astro_denom),
+    }))
+}
+
+fn astro_converter_query(deps: Deps, _env: Env, msg: AstroConverterQueryMsg) -> StdResult<Binary> {
+    match msg {
+        AstroConverterQueryMsg::Config {} => to_json_binary(&astro_converter_config(deps.storage)?),
+    }
+}
+
+fn store_astro_converter(app: &mut TestApp) -> u64 {
+    app.store_code(Box::new(ContractWrapper::new_with_empty(
+        astro_converter_execute,
+        astro_converter_instantiate,
+        astro_converter_query,
+    )))
+}
+
 fn store_astroport_factory(app: &mut TestApp) -> u64 {
     let contract = Box::new(
         ContractWrapper::new_with_empty(
@@ -342,6 +467,16 @@ fn store_single_staking(app: &mut TestApp) -> u64 {
     app.store_code(contract)
 }
 
+fn store_lp_depositor(app: &mut TestApp) -> u64 {
+    let contract = Box::new(ContractWrapper::new_with_empty(
+        lp_depositor::contract::execute,
+        lp_depositor::contract::instantiate,
+        lp_depositor::contract::query,
+    ));
+
+    app.store_code(contract)
+}
+
 fn store_lockdrop(app: &mut TestApp) -> u64 {
     let contract = Box::new(ContractWrapper::new_with_empty(
         lockdrop::contract::execute,
@@ -933,6 +1068,28 @@ impl SuiteBuilder {
             )
             .unwrap();
 
+        let lp_depositor_code_id = store_lp_depositor(&mut app);
+        let lp_depositor_contract = app
+            .instantiate_contract(
+                lp_depositor_code_id,
+                admin.clone(),
+                &LpDepositorInstantiateMsg {
+                    astro: ASTRO_DENOM.to_string(),
+                    xastro: xastro.clone(),
+                    eclipastro: eclipastro.clone(),
+                    staking_contract: astro_staking_contract.to_string(),
+                    voter: voter_contract.to_string(),
+                    lp_contract: eclipastro_xastro_lp_contract.to_string(),
+                    lp_token: eclipastro_xastro_lp_token.clone(),
+                    lp_staking: Some(lp_staking_contract.to_string()),
+                    owner: None,
+                },
+                &[],
+                "Eclipsefi lp depositor",
+                None,
+            )
+            .unwrap();
+
         let lockdrop_code_id = store_lockdrop(&mut app);
         let init_timestamp = app.block_info().time.seconds() + 86400;
         let lockdrop_contract = app
@@ -1022,6 +1179,7 @@ impl SuiteBuilder {
             eclip: eclip.clone(),
             single_staking_contract,
             lp_staking_contract,
+            lp_depositor_contract,
             lockdrop_contract,
             minter_contract,
             voter_contract,
@@ -1046,6 +1204,7 @@ pub struct Suite {
     eclip: String,
     single_staking_contract: Addr,
     lp_staking_contract: Addr,
+    lp_depositor_contract: Addr,
     lockdrop_contract: Addr,
     minter_contract: Addr,
     voter_contract: Addr,
@@ -1085,6 +1244,9 @@ impl Suite {
     pub fn lp_staking_contract(&self) -> String {
         self.lp_staking_contract.to_string()
     }
+    pub fn lp_depositor_contract(&self) -> String {
+        self.lp_depositor_contract.to_string()
+    }
     pub fn lockdrop_contract(&self) -> String {
         self.lockdrop_contract.to_string()
     }
@@ -1174,6 +1336,27 @@ impl Suite {
                 &[],
             )
             .unwrap();
+        self.app
+            .execute_contract(
+                self.admin.clone(),
+                self.lp_staking_contract.clone(),
+                &LpStakingExecuteMsg::UpdateConfig {
+                    config: LpStakingUpdateConfigMsg {
+                        lp_token: None,
+                        lp_contract: None,
+                        lockdrop: Some(self.lockdrop_contract.to_string()),
+                        astroport_incentives: None,
+                        treasury: None,
+                        funding_dao: None,
+                        eclip: None,
+                        beclip: None,
+                        unbonding_tiers: None,
+                        timelock_config: None,
+                    },
+                },
+                &[],
+            )
+            .unwrap();
     }
 
     pub fn mint_native(
@@ -1204,6 +1387,62 @@ impl Suite {
         Ok(balance.u128())
     }
 
+    pub fn instantiate_cw20_token(
+        &mut self,
+        symbol: &str,
+        initial_balances: Vec<(&str, u128)>,
+    ) -> AnyResult<Addr> {
+        let code_id = store_astroport_token(&mut self.app);
+        self.app.instantiate_contract(
+            code_id,
+            self.admin.clone(),
+            &cw20_base::msg::InstantiateMsg {
+                name: symbol.to_string(),
+                symbol: symbol.to_string(),
+                decimals: 6,
+                initial_balances: initial_balances
+                    .into_iter()
+                    .map(|(address, amount)| Cw20Coin {
+                        address: address.to_string(),
+                        amount: Uint128::from(amount),
+                    })
+                    .collect(),
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            symbol,
+            None,
+        )
+    }
+
+    /// mock ASTRO converter prefunded with native ASTRO
+    pub fn instantiate_astro_converter(
+        &mut self,
+        old_astro_asset_info: AssetInfo,
+        astro_amount: u128,
+    ) -> AnyResult<Addr> {
+        let code_id = store_astro_converter(&mut self.app);
+        let astro_converter = self.app.instantiate_contract(
+            code_id,
+            self.admin.clone(),
+            &AstroConverterInstantiateMsg {
+                old_astro_asset_info,
+                new_astro_denom: self.astro.clone(),
+                outpost_burn_params: None,
+            },
+            &[],
+            "ASTRO converter",
+            None,
+        )?;
+        self.mint_native(
+            astro_converter.to_string(),
+            self.astro.clone(),
+            astro_amount,
+        )?;
+        Ok(astro_converter)
+    }
+
     pub fn stake_astro(&mut self, sender: &str, amount: u128) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(sender),
@@ -1430,6 +1669,42 @@ impl Suite {
         )?;
         Ok(config)
     }
+    pub fn query_lp_staking_protocol_fees(&self) -> StdResult<Vec<Asset>> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_staking_contract.clone(),
+            &LpStakingQueryMsg::ProtocolFees {},
+        )
+    }
+    pub fn query_lp_staking_pending_swap_rewards(&self) -> StdResult<Vec<PendingSwapReward>> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_staking_contract.clone(),
+            &LpStakingQueryMsg::PendingSwapRewards {},
+        )
+    }
+    pub fn lp_staking_swap_rewards(
+        &mut self,
+        sender: &str,
+        assets: Option<Vec<AssetInfo>>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::SwapRewards { assets },
+            &[],
+        )
+    }
+    pub fn lp_staking_update_reward_distribution(
+        &mut self,
+        sender: &str,
+        distribution: RewardDistribution,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::UpdateRewardDistribution { distribution },
+            &[],
+        )
+    }
     pub fn lp_staking_update_config(
         &mut self,
         sender: &str,
@@ -1442,6 +1717,50 @@ impl Suite {
             &[],
         )
     }
+    pub fn lp_staking_propose_new_owner(
+        &mut self,
+        sender: &str,
+        owner: &str,
+        expires_in: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::ProposeNewOwner {
+                owner: owner.to_string(),
+                expires_in,
+            },
+            &[],
+        )
+    }
+    pub fn lp_staking_drop_ownership_proposal(&mut self, sender: &str) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::DropOwnershipProposal {},
+            &[],
+        )
+    }
+    pub fn lp_staking_claim_ownership(&mut self, sender: &str) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::ClaimOwnership {},
+            &[],
+        )
+    }
+    pub fn query_lp_staking_owner(&self) -> StdResult<Addr> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_staking_contract.clone(),
+            &LpStakingQueryMsg::Owner {},
+        )
+    }
+    pub fn query_lp_staking_ownership_proposal(&self) -> StdResult<Option<OwnershipProposal>> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_staking_contract.clone(),
+            &LpStakingQueryMsg::OwnershipProposal {},
+        )
+    }
     pub fn query_lp_token_balance(&self, address: &str) -> StdResult<Uint128> {
         let res = self
             .app
@@ -1470,6 +1789,16 @@ impl Suite {
         )?;
         Ok(res)
     }
+    pub fn query_cw20_balance(&self, token: &Addr, address: &str) -> StdResult<u128> {
+        let balance: BalanceResponse = self.app.wrap().query_wasm_smart(
+            token.clone(),
+            &Cw20QueryMsg::Balance {
+                address: address.to_owned(),
+            },
+        )?;
+        Ok(balance.balance.u128())
+    }
+
     pub fn query_beclip_balance(&self, address: &str) -> StdResult<u128> {
         let balance: BalanceResponse = self.app.wrap().query_wasm_smart(
             self.beclip.clone(),
@@ -1493,6 +1822,36 @@ impl Suite {
             &[],
         )
     }
+    pub fn single_sided_propose_new_owner(
+        &mut self,
+        sender: &str,
+        owner: &str,
+        expires_in: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::ProposeNewOwner {
+                owner: owner.to_string(),
+                expires_in,
+            },
+            &[],
+        )
+    }
+    pub fn single_sided_claim_ownership(&mut self, sender: &str) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::ClaimOwnership {},
+            &[],
+        )
+    }
+    pub fn query_single_sided_owner(&self) -> StdResult<Addr> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::Owner {},
+        )
+    }
     pub fn query_single_sided_stake_config(&self) -> StdResult<SingleStakingConfig> {
         let config: SingleStakingConfig = self.app.wrap().query_wasm_smart(
             self.single_staking_contract.clone(),
@@ -1505,10 +1864,107 @@ impl Suite {
             self.single_staking_contract.clone(),
             &SingleStakingQueryMsg::Staking {
                 user: user.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+        Ok(user_staking)
+    }
+    pub fn query_single_sided_staking_page(
+        &self,
+        user: &str,
+        start_after: Option<(u64, u64)>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<SingleSidedUserStaking>> {
+        let user_staking: Vec<SingleSidedUserStaking> = self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::Staking {
+                user: user.to_string(),
+                start_after,
+                limit,
             },
         )?;
         Ok(user_staking)
     }
+    pub fn query_single_sided_staking_at(
+        &self,
+        user: &str,
+        timestamp: Option<u64>,
+    ) -> StdResult<u128> {
+        let staking: Uint128 = self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::StakingAt {
+                user: user.to_string(),
+                timestamp,
+            },
+        )?;
+        Ok(staking.u128())
+    }
+    pub fn query_single_sided_unbonded(&self, user: &str) -> StdResult<Vec<UnbondedItem>> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::Unbonded {
+                user: user.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+    }
+    pub fn query_single_sided_unbonding_tiers(&self) -> StdResult<Vec<UnbondingTier>> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::UnbondingTiers {},
+        )
+    }
+    pub fn query_single_sided_pending_days(&self) -> StdResult<u64> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::PendingDays {},
+        )
+    }
+    pub fn query_single_sided_auto_compound(&self, user: &str, duration: u64) -> StdResult<bool> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::AutoCompound {
+                user: user.to_string(),
+                duration,
+            },
+        )
+    }
+    pub fn query_single_sided_total_boosted_stake_at(
+        &self,
+        timestamp: Option<u64>,
+    ) -> StdResult<Uint256> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::TotalBoostedStakeAt { timestamp },
+        )
+    }
+    pub fn query_single_sided_all_stakers(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<String>> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::AllStakers { start_after, limit },
+        )
+    }
+    pub fn query_single_sided_all_positions(
+        &self,
+        duration: Option<u64>,
+        start_after: Option<(String, u64, u64)>,
+        limit: Option<u32>,
+    ) -> StdResult<SingleStakingAllPositionsResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::AllPositions {
+                duration,
+                start_after,
+                limit,
+            },
+        )
+    }
     pub fn query_single_sided_total_staking(&self) -> StdResult<u128> {
         let total_staking: Uint128 = self.app.wrap().query_wasm_smart(
             self.single_staking_contract.clone(),
@@ -1536,6 +1992,21 @@ impl Suite {
             &coins(eclip + beclip, self.eclip.clone()),
         )
     }
+    pub fn add_single_sided_vault_asset_reward(
+        &mut self,
+        sender: &str,
+        from: Option<u64>,
+        duration: Option<u64>,
+        amount: u128,
+        denom: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::AddAssetRewards { from, duration },
+            &coins(amount, denom),
+        )
+    }
     pub fn single_sided_stake(
         &mut self,
         sender: &str,
@@ -1593,57 +2064,188 @@ impl Suite {
         )
     }
 
-    pub fn single_sided_withdraw(
+    pub fn single_sided_cancel_unbond(
         &mut self,
         sender: &str,
-        recipient: Option<String>,
+        index: u32,
     ) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(sender),
             self.single_staking_contract.clone(),
-            &SingleSidedStakingExecuteMsg::Withdraw { recipient },
+            &SingleSidedStakingExecuteMsg::CancelUnbond { index },
             &[],
         )
     }
 
-    pub fn single_sided_restake(
+    pub fn single_sided_early_unlock(
         &mut self,
         sender: &str,
-        from_duration: u64,
+        duration: u64,
         locked_at: u64,
-        to_duration: u64,
-        recipient: Option<String>,
         amount: Option<Uint128>,
     ) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(sender),
             self.single_staking_contract.clone(),
-            &SingleSidedStakingExecuteMsg::Restake {
-                from_duration,
-                locked_at: Some(locked_at),
+            &SingleSidedStakingExecuteMsg::EarlyUnlock {
+                duration,
+                locked_at,
                 amount,
-                to_duration,
-                recipient,
             },
             &[],
         )
     }
-    pub fn query_single_sided_staking_reward(
-        &self,
-        user: &str,
+
+    pub fn single_sided_transfer_position(
+        &mut self,
+        sender: &str,
         duration: u64,
         locked_at: u64,
-    ) -> StdResult<UserReward> {
-        let reward: UserReward = self.app.wrap().query_wasm_smart(
+        amount: Option<Uint128>,
+        recipient: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
             self.single_staking_contract.clone(),
-            &SingleStakingQueryMsg::Reward {
-                user: user.to_string(),
+            &SingleSidedStakingExecuteMsg::TransferPosition {
                 duration,
                 locked_at,
+                amount,
+                recipient: recipient.to_string(),
             },
-        )?;
-        Ok(reward)
-    }
+            &[],
+        )
+    }
+
+    pub fn single_sided_aggregate_positions(
+        &mut self,
+        sender: &str,
+        duration: u64,
+        locked_at_list: Vec<u64>,
+        target_duration: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::AggregatePositions {
+                duration,
+                locked_at_list,
+                target_duration,
+            },
+            &[],
+        )
+    }
+
+    pub fn single_sided_checkpoint(
+        &mut self,
+        sender: &str,
+        max_days: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::Checkpoint { max_days },
+            &[],
+        )
+    }
+
+    pub fn single_sided_set_auto_compound(
+        &mut self,
+        sender: &str,
+        duration: u64,
+        enabled: bool,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::SetAutoCompound { duration, enabled },
+            &[],
+        )
+    }
+
+    pub fn single_sided_compound(
+        &mut self,
+        sender: &str,
+        users: Vec<String>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::Compound { users },
+            &[],
+        )
+    }
+
+    pub fn single_sided_withdraw(
+        &mut self,
+        sender: &str,
+        recipient: Option<String>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::Withdraw {
+                recipient,
+                min_astro_out: None,
+            },
+            &[],
+        )
+    }
+
+    pub fn single_sided_withdraw_with_min_out(
+        &mut self,
+        sender: &str,
+        min_astro_out: u128,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::Withdraw {
+                recipient: None,
+                min_astro_out: Some(Uint128::from(min_astro_out)),
+            },
+            &[],
+        )
+    }
+
+    pub fn single_sided_restake(
+        &mut self,
+        sender: &str,
+        from_duration: u64,
+        locked_at: u64,
+        to_duration: u64,
+        recipient: Option<String>,
+        amount: Option<Uint128>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::Restake {
+                from_duration,
+                locked_at: Some(locked_at),
+                amount,
+                to_duration,
+                recipient,
+            },
+            &[],
+        )
+    }
+    pub fn query_single_sided_staking_reward(
+        &self,
+        user: &str,
+        duration: u64,
+        locked_at: u64,
+    ) -> StdResult<UserReward> {
+        let reward: UserReward = self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::Reward {
+                user: user.to_string(),
+                duration,
+                locked_at,
+            },
+        )?;
+        Ok(reward)
+    }
     pub fn query_single_sided_blacklisted_reward(&self) -> StdResult<UserReward> {
         let reward: UserReward = self.app.wrap().query_wasm_smart(
             self.single_staking_contract.clone(),
@@ -1651,6 +2253,57 @@ impl Suite {
         )?;
         Ok(reward)
     }
+    pub fn query_single_sided_blacklist(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<String>> {
+        self.app.wrap().query_wasm_smart(
+            self.single_staking_contract.clone(),
+            &SingleStakingQueryMsg::Blacklist { start_after, limit },
+        )
+    }
+    pub fn single_sided_add_to_blacklist(
+        &mut self,
+        sender: &str,
+        user: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::AddToBlacklist {
+                user: user.to_string(),
+            },
+            &[],
+        )
+    }
+    pub fn single_sided_remove_from_blacklist(
+        &mut self,
+        sender: &str,
+        user: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::RemoveFromBlacklist {
+                user: user.to_string(),
+            },
+            &[],
+        )
+    }
+    pub fn single_sided_backfill_snapshots(
+        &mut self,
+        sender: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.single_staking_contract.clone(),
+            &SingleSidedStakingExecuteMsg::BackfillSnapshots { start_after, limit },
+            &[],
+        )
+    }
     pub fn query_single_sided_staking_eclipastro_rewards(&self) -> StdResult<Vec<(u64, Uint128)>> {
         let reward: Vec<(u64, Uint128)> = self.app.wrap().query_wasm_smart(
             self.single_staking_contract.clone(),
@@ -1729,6 +2382,24 @@ impl Suite {
             &[],
         )
     }
+    pub fn update_lockdrop_reward_distribution_config(
+        &mut self,
+        sender: &str,
+        instant: u64,
+        vesting_period: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lockdrop_contract.clone(),
+            &LockdropExecuteMsg::UpdateRewardDistributionConfig {
+                new_config: LockdropRewardDistributionConfig {
+                    instant,
+                    vesting_period,
+                },
+            },
+            &[],
+        )
+    }
     pub fn query_lockdrop_config(&self) -> StdResult<LockdropConfig> {
         let res: LockdropConfig = self
             .app
@@ -1756,10 +2427,62 @@ impl Suite {
             &LockdropExecuteMsg::IncreaseLockup {
                 stake_type: StakeType::SingleStaking,
                 duration,
+                recipient: None,
+                referrer: None,
             },
             &[coin(amount, token)],
         )
     }
+    pub fn lockdrop_increase_lockup(
+        &mut self,
+        sender: &str,
+        stake_type: StakeType,
+        duration: u64,
+        recipient: Option<&str>,
+        referrer: Option<&str>,
+        funds: Coin,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lockdrop_contract.clone(),
+            &LockdropExecuteMsg::IncreaseLockup {
+                stake_type,
+                duration,
+                recipient: recipient.map(|r| r.to_string()),
+                referrer: referrer.map(|r| r.to_string()),
+            },
+            &[funds],
+        )
+    }
+    pub fn lockdrop_claim_referral_rewards(&mut self, sender: &str) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lockdrop_contract.clone(),
+            &LockdropExecuteMsg::ClaimReferralRewards {},
+            &[],
+        )
+    }
+    pub fn query_lockdrop_referrer(&self, user: &str) -> StdResult<Option<String>> {
+        let res: Option<String> = self.app.wrap().query_wasm_smart(
+            self.lockdrop_contract.clone(),
+            &LockdropQueryMsg::Referrer {
+                user: user.to_string(),
+            },
+        )?;
+        Ok(res)
+    }
+    pub fn query_lockdrop_referral_info(
+        &self,
+        referrer: &str,
+    ) -> StdResult<Vec<ReferralInfoResponse>> {
+        let res: Vec<ReferralInfoResponse> = self.app.wrap().query_wasm_smart(
+            self.lockdrop_contract.clone(),
+            &LockdropQueryMsg::ReferralInfo {
+                referrer: referrer.to_string(),
+            },
+        )?;
+        Ok(res)
+    }
     pub fn query_single_lockup_info(&self) -> StdResult<SingleLockupInfoResponse> {
         let res: SingleLockupInfoResponse = self.app.wrap().query_wasm_smart(
             self.lockdrop_contract.clone(),
@@ -1774,6 +2497,30 @@ impl Suite {
         )?;
         Ok(res)
     }
+    pub fn increase_lockdrop_cw20(
+        &mut self,
+        sender: &str,
+        token: Addr,
+        amount: u128,
+        stake_type: StakeType,
+        duration: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            token,
+            &Cw20ExecuteMsg::Send {
+                contract: self.lockdrop_contract.to_string(),
+                amount: Uint128::from(amount),
+                msg: to_json_binary(&LockdropCw20HookMsg::IncreaseLockup {
+                    stake_type,
+                    duration,
+                    recipient: None,
+                    referrer: None,
+                })?,
+            },
+            &[],
+        )
+    }
     pub fn query_user_single_lockup_info(
         &self,
         user: &str,
@@ -1819,6 +2566,8 @@ impl Suite {
             &LockdropExecuteMsg::IncreaseLockup {
                 stake_type: StakeType::LpStaking,
                 duration,
+                recipient: None,
+                referrer: None,
             },
             &[coin(amount, token)],
         )
@@ -1859,6 +2608,34 @@ impl Suite {
             &[],
         )
     }
+    pub fn lockdrop_add_to_blacklist(
+        &mut self,
+        sender: &str,
+        user: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lockdrop_contract.clone(),
+            &LockdropExecuteMsg::AddToBlacklist {
+                user: user.to_string(),
+            },
+            &[],
+        )
+    }
+    pub fn lockdrop_remove_from_blacklist(
+        &mut self,
+        sender: &str,
+        user: &str,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lockdrop_contract.clone(),
+            &LockdropExecuteMsg::RemoveFromBlacklist {
+                user: user.to_string(),
+            },
+            &[],
+        )
+    }
     pub fn lockdrop_blacklist_rewards_claim(&mut self) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(self.admin()),
@@ -1971,6 +2748,22 @@ impl Suite {
             &[coin(amount, self.eclip.clone())],
         )
     }
+    pub fn lockdrop_migrate_to_vault(
+        &mut self,
+        sender: &str,
+        stake_type: StakeType,
+        duration: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lockdrop_contract.clone(),
+            &LockdropExecuteMsg::MigrateToVault {
+                stake_type,
+                duration,
+            },
+            &[],
+        )
+    }
     pub fn lockdrop_stake_to_vaults(&mut self, sender: &str) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(sender),
@@ -2143,15 +2936,135 @@ impl Suite {
             &[],
         )
     }
+    pub fn lp_depositor_convert(
+        &mut self,
+        sender: &str,
+        funds: &[Coin],
+        stake: bool,
+        min_lp_out: Option<u128>,
+        max_spread: Option<Decimal>,
+    ) -> AnyResult<AppResponse> {
+        let min_lp_out = min_lp_out.map(Uint128::new);
+        let msg = if stake {
+            LpDepositorExecuteMsg::ConvertAndStake {
+                recipient: None,
+                min_lp_out,
+                max_spread,
+            }
+        } else {
+            LpDepositorExecuteMsg::Convert {
+                recipient: None,
+                min_lp_out,
+                max_spread,
+            }
+        };
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_depositor_contract.clone(),
+            &msg,
+            funds,
+        )
+    }
+
+    pub fn lp_depositor_update_config(
+        &mut self,
+        sender: &str,
+        config: LpDepositorUpdateConfigMsg,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_depositor_contract.clone(),
+            &LpDepositorExecuteMsg::UpdateConfig { config },
+            &[],
+        )
+    }
+
+    pub fn query_lp_depositor_config(&self) -> StdResult<LpDepositorConfig> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_depositor_contract.clone(),
+            &LpDepositorQueryMsg::Config {},
+        )
+    }
+
+    pub fn query_lp_depositor_simulate(
+        &self,
+        assets: Vec<Asset>,
+    ) -> StdResult<LpDepositorSimulateResponse> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_depositor_contract.clone(),
+            &LpDepositorQueryMsg::Simulate { assets },
+        )
+    }
+
     pub fn stake_lp_token(&mut self, sender: &str, amount: u128) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(sender),
             self.lp_staking_contract.clone(),
-            &LpStakingExecuteMsg::Stake { recipient: None },
+            &LpStakingExecuteMsg::Stake {
+                duration: None,
+                recipient: None,
+            },
             &[coin(amount, self.eclipastro_xastro_lp_token.clone())],
         )
     }
 
+    pub fn lock_lp_token(
+        &mut self,
+        sender: &str,
+        amount: u128,
+        duration: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::Stake {
+                duration: Some(duration),
+                recipient: None,
+            },
+            &[coin(amount, self.eclipastro_xastro_lp_token.clone())],
+        )
+    }
+
+    pub fn restake_lp_token(
+        &mut self,
+        sender: &str,
+        from_duration: u64,
+        locked_at: Option<u64>,
+        amount: Option<u128>,
+        to_duration: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::Restake {
+                from_duration,
+                locked_at,
+                amount: amount.map(Uint128::new),
+                to_duration,
+            },
+            &[],
+        )
+    }
+
+    pub fn unlock_lp_token(
+        &mut self,
+        sender: &str,
+        duration: u64,
+        locked_at: u64,
+        amount: Option<u128>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::Unlock {
+                duration,
+                locked_at,
+                amount: amount.map(Uint128::new),
+            },
+            &[],
+        )
+    }
+
     pub fn unbond_lp_token(
         &mut self,
         sender: &str,
@@ -2169,6 +3082,15 @@ impl Suite {
         )
     }
 
+    pub fn cancel_unbond_lp_token(&mut self, sender: &str, index: u32) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::CancelUnbond { index },
+            &[],
+        )
+    }
+
     pub fn withdraw_lp_token(
         &mut self,
         sender: &str,
@@ -2177,7 +3099,30 @@ impl Suite {
         self.app.execute_contract(
             Addr::unchecked(sender),
             self.lp_staking_contract.clone(),
-            &LpStakingExecuteMsg::Withdraw { recipient },
+            &LpStakingExecuteMsg::Withdraw {
+                recipient,
+                min_astro_out: None,
+            },
+            &[],
+        )
+    }
+
+    pub fn withdraw_lp_token_as(
+        &mut self,
+        sender: &str,
+        output: LpStakingWithdrawOutput,
+        min_receive: Option<u128>,
+        min_assets_to_receive: Option<Vec<Asset>>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::WithdrawAs {
+                recipient: None,
+                output,
+                min_receive: min_receive.map(Uint128::new),
+                min_assets_to_receive,
+            },
             &[],
         )
     }
@@ -2192,6 +3137,18 @@ impl Suite {
         Ok(res)
     }
 
+    pub fn query_user_lp_token_locked_staking(
+        &self,
+        user: &str,
+    ) -> StdResult<Vec<LpStakingLockedPosition>> {
+        self.app.wrap().query_wasm_smart(
+            self.lp_staking_contract.clone(),
+            &LpStakingQueryMsg::LockedStaking {
+                user: user.to_string(),
+            },
+        )
+    }
+
     pub fn query_user_lp_token_unbonded(&self, user: &str) -> StdResult<Vec<UnbondedItem>> {
         self.app.wrap().query_wasm_smart(
             self.lp_staking_contract.clone(),
@@ -2268,6 +3225,17 @@ impl Suite {
         )
     }
 
+    pub fn lp_add_to_blacklist(&mut self, user: impl ToString) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(self.admin()),
+            self.lp_staking_contract.clone(),
+            &LpStakingExecuteMsg::AddToBlacklist {
+                user: user.to_string(),
+            },
+            &[],
+        )
+    }
+
     pub fn lp_remove_from_blacklist(&mut self, user: impl ToString) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             Addr::unchecked(self.admin()),